@@ -4,18 +4,27 @@ import {
   ArmorModel,
   Constants,
   CharacterBuilder,
+  CombatantBuilder,
+  MonsterModel,
   WeaponModel,
   CombatSummary,
 } from "./utility/types";
 import { MakeCharacter } from "./components/MakeCharacter";
 import { CombatSummaryComponent } from "./components/CombatSummary";
-import { getArmor, getConstants, getWeapons, postAttack } from "./api";
+import {
+  getArmor,
+  getConstants,
+  getMonsters,
+  getWeapons,
+  postAttack,
+} from "./api";
 import Ibraham from "./assets/ibraham.png";
 
 export const AppWithData = () => {
   const [weapons, setWeapons] = useState<WeaponModel[]>();
   const [armor, setArmor] = useState<ArmorModel[]>();
   const [constants, setConstants] = useState<Constants>();
+  const [monsters, setMonsters] = useState<MonsterModel[]>();
 
   useEffect(() => {
     getWeapons().then((w) => {
@@ -27,21 +36,54 @@ export const AppWithData = () => {
     getConstants().then((c) => {
       setConstants(c);
     });
+    getMonsters().then((m) => {
+      setMonsters(m);
+    });
   }, []);
 
-  if (!weapons || !armor || !constants) {
+  if (!weapons || !armor || !constants || !monsters) {
     return <p>Loading...</p>;
   }
 
-  return <App weapons={weapons} armor={armor} constants={constants} />;
+  return (
+    <App
+      weapons={weapons}
+      armor={armor}
+      constants={constants}
+      monsters={monsters}
+    />
+  );
 };
 
 interface IApp {
   constants: Constants;
   weapons: WeaponModel[];
   armor: ArmorModel[];
+  monsters: MonsterModel[];
 }
 
+// A chosen monster fights from its stat block, keeping the unit's name and
+// circumstances
+const combatantBuilder = (
+  unit: CharacterBuilder,
+  monster: string | undefined
+): CombatantBuilder => {
+  if (monster === undefined) {
+    return { t: "character", c: unit };
+  }
+
+  return {
+    t: "monster",
+    c: {
+      monster,
+      name: unit.name,
+      proneState: unit.proneState,
+      coverState: unit.coverState,
+      position: unit.position,
+    },
+  };
+};
+
 const defaultUnit = (
   constants: Constants,
   defaultName: string
@@ -66,7 +108,7 @@ const defaultUnit = (
   };
 };
 
-const App: React.FC<IApp> = ({ constants, weapons, armor }) => {
+const App: React.FC<IApp> = ({ constants, weapons, armor, monsters }) => {
   const [log, setLog] = useState<CombatSummary>();
 
   const [attacker, setAttacker] = useState<CharacterBuilder>(
@@ -76,9 +118,17 @@ const App: React.FC<IApp> = ({ constants, weapons, armor }) => {
     defaultUnit(constants, "Combatant 2")
   );
 
+  // SRD monster indexes, a character is built when left out
+  const [attackerMonster, setAttackerMonster] = useState<string>();
+  const [targetMonster, setTargetMonster] = useState<string>();
+
+  const [distance, setDistance] = useState(5);
+
   const [currentUnit, setCurrentUnit] = useState<"attacker" | "defender">(
     "attacker"
   );
+  const currentMonster =
+    currentUnit === "attacker" ? attackerMonster : targetMonster;
 
   return (
     <div
@@ -121,7 +171,28 @@ const App: React.FC<IApp> = ({ constants, weapons, armor }) => {
           Combatant 2
         </button>
       </div>
-      {currentUnit === "attacker" ? (
+      <label style={{ alignSelf: "center", marginBottom: "24px" }}>
+        Fights as:{" "}
+        <select
+          value={currentMonster ?? ""}
+          onChange={(e) => {
+            const monster = e.target.value || undefined;
+            if (currentUnit === "attacker") {
+              setAttackerMonster(monster);
+            } else {
+              setTargetMonster(monster);
+            }
+          }}
+        >
+          <option value="">Character</option>
+          {monsters.map((monster) => (
+            <option key={monster.index} value={monster.index}>
+              {monster.name} (AC {monster.armorClass}, {monster.hitPoints} HP)
+            </option>
+          ))}
+        </select>
+      </label>
+      {currentMonster !== undefined ? null : currentUnit === "attacker" ? (
         <MakeCharacter
           unit={attacker}
           setUnit={setAttacker}
@@ -142,13 +213,24 @@ const App: React.FC<IApp> = ({ constants, weapons, armor }) => {
       )}
 
       <hr />
+      <label>
+        Distance (feet)
+        <input
+          type="number"
+          min={0}
+          step={5}
+          value={distance}
+          onChange={(e) => setDistance(Math.max(0, Number(e.target.value)))}
+        />
+      </label>
       <label>
         <button
           style={{ maxWidth: "fit-content" }}
           onClick={() => {
             postAttack({
-              attacker,
-              target,
+              attacker: combatantBuilder(attacker, attackerMonster),
+              target: combatantBuilder(target, targetMonster),
+              distance,
             }).then((p) => {
               setLog(p);
             });
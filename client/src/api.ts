@@ -2,7 +2,10 @@ import {
   ArmorModel,
   AttackRequest,
   CombatSummary,
+  ClassType,
   Constants,
+  MonsterModel,
+  SpellChoices,
   WeaponModel,
 } from "./utility/types";
 
@@ -74,3 +77,40 @@ export const getConstants = async (): Promise<Constants> => {
     })
   );
 };
+
+export const getSpells = async (
+  classType: ClassType,
+  level: number
+): Promise<SpellChoices> => {
+  const query = new URLSearchParams({ class: classType, level: `${level}` });
+
+  return fetch(`/get-spells?${query}`, {
+    method: "GET",
+    mode: "cors",
+    cache: "no-cache",
+    headers: {
+      Accept: "application/json",
+    },
+  }).then((response) =>
+    response.json().then((spellsResponse): SpellChoices => {
+      const spellChoices: SpellChoices = JSON.parse(spellsResponse);
+      return spellChoices;
+    })
+  );
+};
+
+export const getMonsters = async (): Promise<MonsterModel[]> => {
+  return fetch("/get-monsters", {
+    method: "GET",
+    mode: "cors",
+    cache: "no-cache",
+    headers: {
+      Accept: "application/json",
+    },
+  }).then((response) =>
+    response.json().then((monstersResponse): MonsterModel[] => {
+      const monsters: MonsterModel[] = JSON.parse(monstersResponse);
+      return monsters;
+    })
+  );
+};
@@ -1,4 +1,7 @@
-import { CombatSummary } from "../utility/types";
+import {
+  CombatSummary,
+  PhysicalAttackAttemptSummaryWithPhrases,
+} from "../utility/types";
 
 export interface ICombatSummaryComponent extends CombatSummary {
   combatant1Name: string;
@@ -9,9 +12,10 @@ export const CombatSummaryComponent: React.FC<ICombatSummaryComponent> = (
   summary
 ) => {
   const {
+    initiative,
     combatant1Summary,
     combatant2Summary,
-    attacks,
+    turns,
     outcome,
     combatant1Name,
     combatant2Name,
@@ -28,7 +32,27 @@ export const CombatSummaryComponent: React.FC<ICombatSummaryComponent> = (
             <li>{combatant1Summary.attackerArmorProficiencyPenalty}</li>
             <li>{combatant1Summary.attackerProneContextBonus}</li>
             <li>{combatant1Summary.defenderBonusFomCoverState}</li>
+            <li>{combatant1Summary.attackerFightingStyleBonus}</li>
+            <li>{combatant1Summary.defenderFightingStyleBonus}</li>
+            <li>{combatant1Summary.defenderProtectingAlly}</li>
             <li>{combatant1Summary.attackerCombinedAdvantageType}</li>
+            <li>{combatant1Summary.attackerAttacksPerTurn}</li>
+            <li>{combatant1Summary.attackerRage}</li>
+            <li>{combatant1Summary.attackerRecklessAttack}</li>
+            <li>{combatant1Summary.defenderRecklessAttack}</li>
+            <li>{combatant1Summary.defenderParalyzed}</li>
+            <li>{combatant1Summary.attackerSneakAttack}</li>
+            <li>{combatant1Summary.attackerDivineSmite}</li>
+            <li>{combatant1Summary.attackerMartialArts}</li>
+            <li>{combatant1Summary.attackerOffHandWeapon}</li>
+            <li>{combatant1Summary.attackerCriticalRange}</li>
+            <li>{combatant1Summary.attackerSacredWeapon}</li>
+            <li>{combatant1Summary.attackerColossusSlayer}</li>
+            <li>{combatant1Summary.attackerDivineStrike}</li>
+            <li>{combatant1Summary.attackerDarkOnesBlessing}</li>
+            <li>{combatant1Summary.attackerSpellcasting}</li>
+            <li>{combatant1Summary.defenderUnarmoredDefense}</li>
+            <li>{combatant1Summary.defenderDamageResistances}</li>
           </ul>
         </div>
       )}
@@ -42,36 +66,56 @@ export const CombatSummaryComponent: React.FC<ICombatSummaryComponent> = (
             <li>{combatant2Summary.attackerArmorProficiencyPenalty}</li>
             <li>{combatant2Summary.attackerProneContextBonus}</li>
             <li>{combatant2Summary.defenderBonusFomCoverState}</li>
+            <li>{combatant2Summary.attackerFightingStyleBonus}</li>
+            <li>{combatant2Summary.defenderFightingStyleBonus}</li>
+            <li>{combatant2Summary.defenderProtectingAlly}</li>
             <li>{combatant2Summary.attackerCombinedAdvantageType}</li>
+            <li>{combatant2Summary.attackerAttacksPerTurn}</li>
+            <li>{combatant2Summary.attackerRage}</li>
+            <li>{combatant2Summary.attackerRecklessAttack}</li>
+            <li>{combatant2Summary.defenderRecklessAttack}</li>
+            <li>{combatant2Summary.defenderParalyzed}</li>
+            <li>{combatant2Summary.attackerSneakAttack}</li>
+            <li>{combatant2Summary.attackerDivineSmite}</li>
+            <li>{combatant2Summary.attackerMartialArts}</li>
+            <li>{combatant2Summary.attackerOffHandWeapon}</li>
+            <li>{combatant2Summary.attackerCriticalRange}</li>
+            <li>{combatant2Summary.attackerSacredWeapon}</li>
+            <li>{combatant2Summary.attackerColossusSlayer}</li>
+            <li>{combatant2Summary.attackerDivineStrike}</li>
+            <li>{combatant2Summary.attackerDarkOnesBlessing}</li>
+            <li>{combatant2Summary.attackerSpellcasting}</li>
+            <li>{combatant2Summary.defenderUnarmoredDefense}</li>
+            <li>{combatant2Summary.defenderDamageResistances}</li>
           </ul>
         </div>
       )}
 
       <hr />
 
-      {attacks.map((a, idx) => {
+      {initiative.length > 0 && (
+        <div>
+          <h4>Initiative:</h4>
+          <ol>
+            {initiative.map((entry, idx) => (
+              <li key={idx}>{entry.roll}</li>
+            ))}
+          </ol>
+        </div>
+      )}
+
+      {turns.map((turn, turnIdx) => {
         return (
-          <div>
-            <h3>{a.beforePhrase}</h3>
-            <>
-              {a.summary.t == "failure" ? (
-                <p key={idx}>{a.summary.c}</p>
-              ) : (
-                <ul>
-                  <li>{a.summary.c.after.attack}</li>
-                  <li>{a.summary.c.after.attackRoll}</li>
-                  <li>{a.summary.c.after.targetAc}</li>
-                  {a.summary.c.after.damageRoll && (
-                    <li>{a.summary.c.after.damageRoll}</li>
-                  )}
-                  <li>{a.summary.c.after.hit}</li>
-                  {a.summary.c.after.hpChange && (
-                    <li>{a.summary.c.after.hpChange}</li>
-                  )}
-                </ul>
-              )}
-            </>
-            <h5>{a.afterPhrase}</h5>
+          <div key={turnIdx}>
+            <h3>{turn.beforePhrase}</h3>
+            {turn.bonusActionPhrase && <p>{turn.bonusActionPhrase}</p>}
+            {turn.attacks.map((a, idx) => (
+              <AttackAttempt key={idx} {...a} />
+            ))}
+            <h5>{turn.afterPhrase}</h5>
+            {turn.legendaryActions.map((a, idx) => (
+              <AttackAttempt key={idx} {...a} />
+            ))}
           </div>
         );
       })}
@@ -80,3 +124,49 @@ export const CombatSummaryComponent: React.FC<ICombatSummaryComponent> = (
     </div>
   );
 };
+
+const AttackAttempt: React.FC<PhysicalAttackAttemptSummaryWithPhrases> = (
+  a
+) => {
+  return (
+    <div>
+      <h4>{a.beforePhrase}</h4>
+      <>
+        {a.summary.t == "failure" ? (
+          <p>{a.summary.c}</p>
+        ) : a.summary.t == "savingThrow" ? (
+          <ul>
+            <li>{a.summary.c.after.cast}</li>
+            {a.summary.c.after.damageRoll && (
+              <li>{a.summary.c.after.damageRoll}</li>
+            )}
+            {a.summary.c.after.targets.map((target, targetIdx) => (
+              <li key={targetIdx}>
+                {target.savingThrow} {target.effect} {target.hpChange}
+              </li>
+            ))}
+          </ul>
+        ) : (
+          <ul>
+            <li>{a.summary.c.after.attack}</li>
+            <li>{a.summary.c.after.attackRoll}</li>
+            <li>{a.summary.c.after.targetAc}</li>
+            {a.summary.c.after.damageRoll && (
+              <li>{a.summary.c.after.damageRoll}</li>
+            )}
+            {a.summary.c.after.bonusDamageRolls.map(
+              (bonusDamageRoll, bonusIdx) => (
+                <li key={bonusIdx}>{bonusDamageRoll}</li>
+              )
+            )}
+            <li>{a.summary.c.after.hit}</li>
+            {a.summary.c.after.hpChange && (
+              <li>{a.summary.c.after.hpChange}</li>
+            )}
+          </ul>
+        )}
+      </>
+      <h5>{a.afterPhrase}</h5>
+    </div>
+  );
+};
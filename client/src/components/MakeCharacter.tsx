@@ -1,18 +1,60 @@
+import { useEffect, useState } from "react";
 import { AbilityScoresComponent } from "./AbilityScores";
 import { Counter } from "./Counter";
 import { camelToSentenceCase, rollHp } from "../util";
+import { getSpells } from "../api";
 import {
   ArmorModel,
   ArmorType,
+  Material,
   ClassType,
   Constants,
   CoverState,
+  FightingStyle,
   ProneState,
   CharacterBuilder,
+  SmitePolicy,
+  SpellChoices,
+  SubclassType,
   WeaponModel,
+  WeaponProperty,
   WeaponType,
 } from "../utility/types";
 
+// The SRD has one subclass per class
+const SUBCLASS_BY_CLASS: Record<ClassType, SubclassType> = {
+  [ClassType.Barbarian]: SubclassType.Berserker,
+  [ClassType.Bard]: SubclassType.Lore,
+  [ClassType.Cleric]: SubclassType.Life,
+  [ClassType.Druid]: SubclassType.Land,
+  [ClassType.Fighter]: SubclassType.Champion,
+  [ClassType.Monk]: SubclassType.OpenHand,
+  [ClassType.Paladin]: SubclassType.Devotion,
+  [ClassType.Ranger]: SubclassType.Hunter,
+  [ClassType.Rogue]: SubclassType.Thief,
+  [ClassType.Sorcerer]: SubclassType.Draconic,
+  [ClassType.Warlock]: SubclassType.Fiend,
+  [ClassType.Wizard]: SubclassType.Evocation,
+};
+
+// Spells cast in place of weapon attacks, by SRD index
+const ATTACK_SPELLS: Record<string, string> = {
+  "fire-bolt": "Fire Bolt",
+  "ray-of-frost": "Ray of Frost",
+  "guiding-bolt": "Guiding Bolt",
+  "inflict-wounds": "Inflict Wounds",
+  "scorching-ray": "Scorching Ray",
+  "sacred-flame": "Sacred Flame",
+  "burning-hands": "Burning Hands",
+  "thunderwave": "Thunderwave",
+  "hold-person": "Hold Person",
+  "fireball": "Fireball",
+  "lightning-bolt": "Lightning Bolt",
+};
+
+// Cantrips are cast without a slot and are rejected with one
+const SPELL_SLOT_LEVELS = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
 export interface IMakeCharacter {
   unit: CharacterBuilder;
   setUnit: React.Dispatch<React.SetStateAction<CharacterBuilder>>;
@@ -30,6 +72,23 @@ export const MakeCharacter: React.FC<IMakeCharacter> = ({
   armor,
   characterType,
 }) => {
+  const [spellChoices, setSpellChoices] = useState<SpellChoices>();
+
+  useEffect(() => {
+    getSpells(unit.class, unit.level).then((choices) => {
+      setSpellChoices(choices);
+      // Drop spells the new class or level can no longer choose
+      setUnit(
+        (u): CharacterBuilder => ({
+          ...u,
+          spells: u.spells?.filter((index) =>
+            choices.spells.some((spell) => spell.index === index)
+          ),
+        })
+      );
+    });
+  }, [unit.class, unit.level, setUnit]);
+
   return (
     <div key={characterType} className="make-unit">
       <div className="make-unit-column-1 flex-column">
@@ -146,6 +205,12 @@ export const MakeCharacter: React.FC<IMakeCharacter> = ({
                   (u): CharacterBuilder => ({
                     ...u,
                     class: ClassType[e.target.value as keyof typeof ClassType],
+                    // Class features do not carry over to another class
+                    subclass: undefined,
+                    rage: undefined,
+                    frenzy: undefined,
+                    recklessAttack: undefined,
+                    smitePolicy: undefined,
                   })
                 );
               }}
@@ -159,6 +224,26 @@ export const MakeCharacter: React.FC<IMakeCharacter> = ({
               })}
             </select>
           </label>
+          <label className="button-group-container">
+            Subclass:&nbsp;
+            <select
+              value={unit.subclass ?? ""}
+              onChange={(e) => {
+                setUnit(
+                  (u): CharacterBuilder => ({
+                    ...u,
+                    subclass: (e.target.value as SubclassType) || undefined,
+                    frenzy: undefined,
+                  })
+                );
+              }}
+            >
+              <option value="">None</option>
+              <option value={SUBCLASS_BY_CLASS[unit.class]}>
+                {camelToSentenceCase(SUBCLASS_BY_CLASS[unit.class])}
+              </option>
+            </select>
+          </label>
         </div>
 
         <div>
@@ -274,16 +359,50 @@ export const MakeCharacter: React.FC<IMakeCharacter> = ({
           </select>
         </div>
 
+        <div>
+          <label htmlFor="dnd-off-hand-weapon">Off-hand weapon</label>
+          <select
+            name="dnd-off-hand-weapon"
+            value={unit.offHandWeaponType}
+            onChange={(e) => {
+              setUnit(
+                (u): CharacterBuilder => ({
+                  ...u,
+                  offHandWeaponType:
+                    (e.target.value as WeaponType) || undefined,
+                })
+              );
+            }}
+          >
+            <option value="">None</option>
+            {weapons
+              .filter((wp) => wp.properties.includes(WeaponProperty.Light))
+              .map((wp, idx) => {
+                return (
+                  <option key={idx} value={wp.weapon_type}>
+                    {camelToSentenceCase(wp.weapon_type)}
+                  </option>
+                );
+              })}
+          </select>
+        </div>
+
         <div>
           <label htmlFor="dnd-armor">Armor</label>
           <select
             name="dnd-armor"
-            value={unit.armorType}
+            value={
+              unit.armorType
+                ? `${unit.armorType}:${unit.armorMaterial ?? ""}`
+                : ""
+            }
             onChange={(e) => {
+              const [armorType, armorMaterial] = e.target.value.split(":");
               setUnit(
                 (u): CharacterBuilder => ({
                   ...u,
-                  armorType: (e.target.value as ArmorType) || undefined,
+                  armorType: (armorType as ArmorType) || undefined,
+                  armorMaterial: (armorMaterial as Material) || undefined,
                 })
               );
             }}
@@ -291,15 +410,211 @@ export const MakeCharacter: React.FC<IMakeCharacter> = ({
             <option value="">None</option>
             {armor.map((ar, idx) => {
               return (
-                <option key={idx} value={ar.armor_type}>
+                <option
+                  key={idx}
+                  value={`${ar.armor_type}:${ar.primary_material}`}
+                >
                   {`${camelToSentenceCase(ar.armor_type)} (${
-                    ar.armor_category
-                  }) (AC ${ar.armor_class})`}
+                    ar.primary_material
+                  }) (${ar.armor_category}) (AC ${ar.armor_class})`}
+                </option>
+              );
+            })}
+          </select>
+        </div>
+
+        <div>
+          <label htmlFor="dnd-fighting-style">Fighting Style</label>
+          <select
+            name="dnd-fighting-style"
+            value={unit.fightingStyle ?? ""}
+            onChange={(e) => {
+              setUnit(
+                (u): CharacterBuilder => ({
+                  ...u,
+                  fightingStyle:
+                    (e.target.value as FightingStyle) || undefined,
+                })
+              );
+            }}
+          >
+            <option value="">None</option>
+            {Object.values(FightingStyle).map((style, idx) => {
+              return (
+                <option key={idx} value={style}>
+                  {camelToSentenceCase(style)}
+                </option>
+              );
+            })}
+          </select>
+        </div>
+
+        {unit.class === ClassType.Barbarian && (
+          <div>
+            <label htmlFor="dnd-rage">Rage</label>
+            <input
+              type="checkbox"
+              name="dnd-rage"
+              checked={unit.rage ?? false}
+              onChange={(e) => {
+                setUnit(
+                  (u): CharacterBuilder => ({ ...u, rage: e.target.checked })
+                );
+              }}
+            />
+            <label htmlFor="dnd-reckless-attack">Reckless Attack</label>
+            <input
+              type="checkbox"
+              name="dnd-reckless-attack"
+              checked={unit.recklessAttack ?? false}
+              onChange={(e) => {
+                setUnit(
+                  (u): CharacterBuilder => ({
+                    ...u,
+                    recklessAttack: e.target.checked,
+                  })
+                );
+              }}
+            />
+            {unit.subclass === SubclassType.Berserker && (
+              <>
+                <label htmlFor="dnd-frenzy">Frenzy</label>
+                <input
+                  type="checkbox"
+                  name="dnd-frenzy"
+                  checked={unit.frenzy ?? false}
+                  onChange={(e) => {
+                    setUnit(
+                      (u): CharacterBuilder => ({
+                        ...u,
+                        frenzy: e.target.checked,
+                      })
+                    );
+                  }}
+                />
+              </>
+            )}
+          </div>
+        )}
+
+        {unit.class === ClassType.Paladin && (
+          <div>
+            <label htmlFor="dnd-smite-policy">Divine Smite</label>
+            <select
+              name="dnd-smite-policy"
+              value={unit.smitePolicy ?? ""}
+              onChange={(e) => {
+                setUnit(
+                  (u): CharacterBuilder => ({
+                    ...u,
+                    smitePolicy: (e.target.value as SmitePolicy) || undefined,
+                  })
+                );
+              }}
+            >
+              <option value="">None</option>
+              {Object.values(SmitePolicy).map((policy, idx) => {
+                return (
+                  <option key={idx} value={policy}>
+                    {camelToSentenceCase(policy)}
+                  </option>
+                );
+              })}
+            </select>
+          </div>
+        )}
+
+        <div>
+          <label htmlFor="dnd-attack-spell">Attack spell</label>
+          <select
+            name="dnd-attack-spell"
+            value={unit.attackSpell ?? ""}
+            onChange={(e) => {
+              setUnit(
+                (u): CharacterBuilder => ({
+                  ...u,
+                  attackSpell: e.target.value || undefined,
+                  attackSpellSlotLevel: undefined,
+                })
+              );
+            }}
+          >
+            <option value="">None</option>
+            {Object.entries(ATTACK_SPELLS).map(([index, name]) => {
+              return (
+                <option key={index} value={index}>
+                  {name}
                 </option>
               );
             })}
           </select>
         </div>
+
+        {unit.attackSpell && (
+          <div>
+            <label htmlFor="dnd-attack-spell-slot-level">Spell slot level</label>
+            <select
+              name="dnd-attack-spell-slot-level"
+              value={unit.attackSpellSlotLevel ?? ""}
+              onChange={(e) => {
+                setUnit(
+                  (u): CharacterBuilder => ({
+                    ...u,
+                    attackSpellSlotLevel: e.target.value
+                      ? Number(e.target.value)
+                      : undefined,
+                  })
+                );
+              }}
+            >
+              <option value="">Lowest available</option>
+              {SPELL_SLOT_LEVELS.map((slotLevel) => {
+                return (
+                  <option key={slotLevel} value={slotLevel}>
+                    {slotLevel}
+                  </option>
+                );
+              })}
+            </select>
+          </div>
+        )}
+
+        {spellChoices && spellChoices.spells.length > 0 && (
+          <div>
+            <label htmlFor="dnd-spells">
+              Spells ({spellChoices.cantripsKnown} cantrips
+              {spellChoices.spellsKnown != null &&
+                `, ${spellChoices.spellsKnown} known`}
+              )
+            </label>
+            <select
+              name="dnd-spells"
+              multiple
+              value={unit.spells ?? []}
+              onChange={(e) => {
+                setUnit(
+                  (u): CharacterBuilder => ({
+                    ...u,
+                    spells: Array.from(
+                      e.target.selectedOptions,
+                      (option) => option.value
+                    ),
+                  })
+                );
+              }}
+            >
+              {spellChoices.spells.map((spell) => {
+                return (
+                  <option key={spell.index} value={spell.index}>
+                    {spell.level === 0
+                      ? `${spell.name} (cantrip)`
+                      : `${spell.name} (level ${spell.level})`}
+                  </option>
+                );
+              })}
+            </select>
+          </div>
+        )}
       </div>
 
       <div className="make-unit-column-2 flex-column">
@@ -40,7 +40,9 @@ export type ProficiencyType =
 	| { t: "weaponCategory", c: WeaponCategory }
 	| { t: "weaponType", c: WeaponType }
 	| { t: "armorCategory", c: ArmorCategory }
-	| { t: "armorMaterial", c: Material };
+	| { t: "armorMaterialRestriction", c: MaterialRestriction }
+	| { t: "savingThrow", c: AbilityType }
+	| { t: "tool", c: ToolType };
 
 export type ProficiencyTypeByClassMap = Record<ClassType, ProficiencyType[]>;
 
@@ -83,8 +85,46 @@ export enum ArmorCategory {
 }
 
 export enum Material {
+	Cloth = "cloth",
+	Leather = "leather",
+	Hide = "hide",
+	Wood = "wood",
 	Metal = "metal",
-	Unknown = "unknown",
+	/** Mithral armor doesn't impose disadvantage on Dexterity (Stealth) checks */
+	Mithral = "mithral",
+	/** Any critical hit against the wearer of adamantine armor becomes a normal hit */
+	Adamantine = "adamantine",
+}
+
+export enum MaterialRestriction {
+	/** Druids will not wear armor or use shields made of metal */
+	Nonmetal = "nonmetal",
+}
+
+export enum ToolType {
+	AlchemistsSupplies = "alchemistsSupplies",
+	BrewersSupplies = "brewersSupplies",
+	CalligraphersSupplies = "calligraphersSupplies",
+	CarpentersTools = "carpentersTools",
+	CartographersTools = "cartographersTools",
+	CobblersTools = "cobblersTools",
+	CooksUtensils = "cooksUtensils",
+	DisguiseKit = "disguiseKit",
+	ForgeryKit = "forgeryKit",
+	GlassblowersTools = "glassblowersTools",
+	HerbalismKit = "herbalismKit",
+	JewelersTools = "jewelersTools",
+	LeatherworkersTools = "leatherworkersTools",
+	MasonsTools = "masonsTools",
+	NavigatorsTools = "navigatorsTools",
+	PaintersSupplies = "paintersSupplies",
+	PoisonersKit = "poisonersKit",
+	PottersTools = "pottersTools",
+	SmithsTools = "smithsTools",
+	ThievesTools = "thievesTools",
+	TinkersTools = "tinkersTools",
+	WeaversTools = "weaversTools",
+	WoodcarversTools = "woodcarversTools",
 }
 
 export interface ArmorModel {
@@ -92,6 +132,7 @@ export interface ArmorModel {
 	armor_class: ArmorClass;
 	armor_category: ArmorCategory;
 	primary_material: Material;
+	stealth_disadvantage: boolean;
 }
 
 export interface CannotBeTargeted {
@@ -202,6 +243,7 @@ export interface WeaponModel {
 	weapon_category: WeaponCategory;
 	damage_type: DamageType;
 	damage_dice: Dice;
+	properties: WeaponProperty[];
 }
 
 export interface PhysicalAttackSummary {
@@ -210,7 +252,51 @@ export interface PhysicalAttackSummary {
 	attackerWeaponProficiencyBonus: string;
 	attackerArmorProficiencyPenalty: string;
 	attackerWeaponAbilityModifier: string;
+	attackerFightingStyleBonus: string;
+	defenderFightingStyleBonus: string;
+	defenderProtectingAlly: string;
 	attackerCombinedAdvantageType: string;
+	attackerAttacksPerTurn: string;
+	attackerRage: string;
+	attackerRecklessAttack: string;
+	defenderRecklessAttack: string;
+	defenderParalyzed: string;
+	attackerSpecialAbilities: string;
+	attackerPackTactics: string;
+	attackerSunlightSensitivity: string;
+	attackerHidden: string;
+	attackerSneakAttack: string;
+	attackerDivineSmite: string;
+	attackerMartialArts: string;
+	attackerOffHandWeapon: string;
+	attackerCriticalRange: string;
+	attackerSacredWeapon: string;
+	attackerColossusSlayer: string;
+	attackerDivineStrike: string;
+	attackerDarkOnesBlessing: string;
+	attackerSpellcasting: string;
+	defenderUnarmoredDefense: string;
+	defenderDamageResistances: string;
+}
+
+/** One entry per combatant, in turn order */
+export interface InitiativeSummary {
+	name: string;
+	total: number;
+	roll: string;
+}
+
+export interface SpellSaveTargetSummary {
+	savingThrow: string;
+	effect: string;
+	hpChange?: string;
+}
+
+export interface SpellSaveOutcomeSummary {
+	cast: string;
+	damageRoll?: string;
+	/** One entry per creature caught by the spell */
+	targets: SpellSaveTargetSummary[];
 }
 
 export interface PhysicalAttackOutcomeSummary {
@@ -218,6 +304,8 @@ export interface PhysicalAttackOutcomeSummary {
 	attackRoll: string;
 	targetAc: string;
 	damageRoll?: string;
+	/** One line per source of extra dice, such as Sneak Attack */
+	bonusDamageRolls: string[];
 	hit: string;
 	hpChange?: string;
 }
@@ -248,16 +336,61 @@ export enum CoverState {
 	Total = "Total",
 }
 
+/** A point on the battlefield, in feet */
+export interface Position {
+	x: number;
+	y: number;
+}
+
 export interface CharacterBuilder {
 	name: string;
 	level: number;
 	hp: number;
 	class: ClassType;
+	/** Must belong to the class and be available at the level */
+	subclass?: SubclassType;
 	abilityScores: AbilityScores;
 	weaponType?: WeaponType;
+	/**
+	 * A light weapon held alongside a light main-hand weapon, attacking as a
+	 * bonus action
+	 */
+	offHandWeaponType?: WeaponType;
 	armorType?: ArmorType;
+	/** Defaults to the primary material of the armor type */
+	armorMaterial?: Material;
+	fightingStyle?: FightingStyle;
+	/** Barbarians enter a rage on their first turn */
+	rage?: boolean;
+	/** Berserkers rage into a frenzy, attacking as a bonus action */
+	frenzy?: boolean;
+	/** Barbarians of 2nd level or higher attack recklessly on every turn */
+	recklessAttack?: boolean;
+	/**
+	 * Paladins of 2nd level or higher decide when to spend a spell slot on
+	 * Divine Smite
+	 */
+	smitePolicy?: SmitePolicy;
+	/**
+	 * SRD index of a spell cast instead of attacking with a weapon, e.g.
+	 * "fire-bolt"
+	 */
+	attackSpell?: string;
+	/**
+	 * Slot level to cast the attack spell with, moving up to the next
+	 * higher slot left once those run out. The lowest left that fits the
+	 * spell if left out
+	 */
+	attackSpellSlotLevel?: number;
+	/**
+	 * SRD indexes of the known or prepared spells, cantrips included. The
+	 * attack spell is always among them
+	 */
+	spells?: string[];
 	proneState: ProneState;
 	coverState: CoverState;
+	/** Placed from the distance between the attacker and the target if left out */
+	position?: Position;
 }
 
 export interface Constants {
@@ -280,6 +413,10 @@ export type PhysicalAttackAttemptSummary =
 	| { t: "failure", c: string }
 	| { t: "success", c: {
 	after: PhysicalAttackOutcomeSummary;
+}}
+	/** A spell resisted with saving throws instead of an attack roll */
+	| { t: "savingThrow", c: {
+	after: SpellSaveOutcomeSummary;
 }};
 
 export interface PhysicalAttackAttemptSummaryWithPhrases {
@@ -289,15 +426,82 @@ export interface PhysicalAttackAttemptSummaryWithPhrases {
 }
 
 export interface CombatSummary {
+	/** The turn order held for the whole fight */
+	initiative: InitiativeSummary[];
 	combatant1Summary?: PhysicalAttackSummary;
 	combatant2Summary?: PhysicalAttackSummary;
-	attacks: PhysicalAttackAttemptSummaryWithPhrases[];
+	turns: TurnSummary[];
 	outcome: string;
 }
 
+/** Every attack a combatant makes on their turn */
+export interface TurnSummary {
+	beforePhrase: string;
+	bonusActionPhrase?: string;
+	attacks: PhysicalAttackAttemptSummaryWithPhrases[];
+	afterPhrase: string;
+	/** Taken by the other combatant once the turn has ended */
+	legendaryActions: PhysicalAttackAttemptSummaryWithPhrases[];
+}
+
 export interface AttackRequest {
-	attacker: CharacterBuilder;
-	target: CharacterBuilder;
+	attacker: CombatantBuilder;
+	target: CombatantBuilder;
+	/** Stands beside the target and may protect them, but does not fight */
+	targetAlly?: CombatantBuilder;
+	/** Stands beside the target so the attacker can Sneak Attack, but does not fight */
+	attackerAlly?: CombatantBuilder;
+	/**
+	 * Feet between the attacker and the target, checked against spell range.
+	 * Worked out from their positions when both are placed
+	 */
+	distance?: number;
+	/** The fight takes place in sunlight, e.g. for Sunlight Sensitivity */
+	sunlight?: boolean;
+}
+
+/**
+ * The adjusted XP each difficulty starts at, for one character or summed
+ * for the whole party
+ */
+export interface XpThresholds {
+	easy: number;
+	medium: number;
+	hard: number;
+	deadly: number;
+}
+
+export enum EncounterDifficulty {
+	/** Below the easy threshold, hardly worth any resources */
+	Trivial = "Trivial",
+	Easy = "Easy",
+	Medium = "Medium",
+	Hard = "Hard",
+	Deadly = "Deadly",
+}
+
+/** How hard a fight is for a party, worked out before simulating it */
+export interface Encounter {
+	/** The thresholds of every character in the party summed */
+	partyThresholds: XpThresholds;
+	/** The XP the party earns for defeating the monsters */
+	totalXp: number;
+	/** More monsters make a fight harder than their XP alone suggests */
+	multiplier: number;
+	/** Compared to the party's thresholds to find the difficulty */
+	adjustedXp: number;
+	difficulty: EncounterDifficulty;
+}
+
+export interface EncounterSummary {
+	encounter?: Encounter;
+	outcome: string;
+}
+
+export interface EncounterRequest {
+	party: CharacterBuilder[];
+	/** SRD indexes of the monsters, once per monster, e.g. "goblin" */
+	monsters: string[];
 }
 
 export enum AbilityType {
@@ -337,3 +541,395 @@ export type PhysicalAttackType =
 	| { t: "armed", c: WeaponModel }
 	| { t: "unarmed", c?: undefined };
 
+export enum WeaponProperty {
+	Ammunition = "ammunition",
+	Finesse = "finesse",
+	Heavy = "heavy",
+	Light = "light",
+	Loading = "loading",
+	Monk = "monk",
+	Reach = "reach",
+	Special = "special",
+	Thrown = "thrown",
+	TwoHanded = "twoHanded",
+	Versatile = "versatile",
+}
+
+export enum FightingStyle {
+	/** +2 bonus to attack rolls you make with ranged weapons */
+	Archery = "Archery",
+	/** +1 bonus to AC while you are wearing armor */
+	Defense = "Defense",
+	/**
+	 * +2 bonus to damage rolls when wielding a melee weapon in one hand
+	 * and no other weapons
+	 */
+	Dueling = "Dueling",
+	/**
+	 * Reroll a 1 or 2 on a damage die for an attack with a melee weapon
+	 * wielded with two hands
+	 */
+	GreatWeaponFighting = "GreatWeaponFighting",
+	/**
+	 * Use your reaction to impose disadvantage on an attack against an ally
+	 * within 5 feet of you, requires a shield
+	 */
+	Protection = "Protection",
+	/** Add your ability modifier to the damage of the off-hand attack */
+	TwoWeaponFighting = "TwoWeaponFighting",
+}
+
+export enum CreatureType {
+	Aberration = "Aberration",
+	Beast = "Beast",
+	Celestial = "Celestial",
+	Construct = "Construct",
+	Dragon = "Dragon",
+	Elemental = "Elemental",
+	Fey = "Fey",
+	Fiend = "Fiend",
+	Giant = "Giant",
+	Humanoid = "Humanoid",
+	Monstrosity = "Monstrosity",
+	Ooze = "Ooze",
+	Plant = "Plant",
+	Undead = "Undead",
+}
+
+/** When a paladin spends a spell slot on a melee hit */
+export enum SmitePolicy {
+	/** Spend the highest slot left, only on critical hits */
+	CriticalsOnly = "CriticalsOnly",
+	/** Spend the lowest slot left on every hit */
+	Always = "Always",
+	Never = "Never",
+}
+
+/** The subclasses in the SRD, one per class */
+export enum SubclassType {
+	Berserker = "Berserker",
+	Lore = "Lore",
+	Life = "Life",
+	Land = "Land",
+	Champion = "Champion",
+	OpenHand = "OpenHand",
+	Devotion = "Devotion",
+	Hunter = "Hunter",
+	Thief = "Thief",
+	Draconic = "Draconic",
+	Fiend = "Fiend",
+	Evocation = "Evocation",
+}
+
+export enum SpellAttackType {
+	Melee = "Melee",
+	Ranged = "Ranged",
+}
+
+export enum AreaOfEffectType {
+	Cone = "Cone",
+	Cube = "Cube",
+	Cylinder = "Cylinder",
+	Line = "Line",
+	Sphere = "Sphere",
+}
+
+export interface AreaOfEffect {
+	areaType: AreaOfEffectType;
+	/** Feet, e.g. the radius of a sphere or the length of a cone */
+	size: number;
+}
+
+/** What a successful saving throw does to the spell's effect */
+export enum SpellDcSuccess {
+	/** Half damage, rounded down */
+	Half = "Half",
+	/** No damage and no effect */
+	None = "None",
+	/** Spell specific, treated as no effect */
+	Other = "Other",
+}
+
+export interface SpellDc {
+	dcType: AbilityType;
+	dcSuccess: SpellDcSuccess;
+}
+
+export enum Condition {
+	/** Incapacitated and unable to move or speak */
+	Paralyzed = "Paralyzed",
+}
+
+export type SpellRange = 
+	/** The caster is the point of origin or the only target */
+	| { t: "selfOnly", c?: undefined }
+	| { t: "touch", c?: undefined }
+	| { t: "feet", c: number }
+	| { t: "miles", c: number }
+	| { t: "sight", c?: undefined }
+	| { t: "unlimited", c?: undefined }
+	| { t: "special", c?: undefined };
+
+export interface SpellDamage {
+	/** A few spells, such as chaos bolt, pick the type as they are cast */
+	damageType?: DamageType;
+	/** Keyed by the slot the spell is cast with */
+	damageAtSlotLevel: Record<number, Dice>;
+	/** Keyed by the character level the damage starts at, for cantrips */
+	damageAtCharacterLevel: Record<number, Dice>;
+}
+
+export interface SpellModel {
+	/** The SRD index, e.g. "fire-bolt" */
+	index: string;
+	name: string;
+	/** 0 for cantrips */
+	level: number;
+	range: SpellRange;
+	attackType?: SpellAttackType;
+	/** Spells which force a saving throw instead of an attack roll */
+	dc?: SpellDc;
+	damage?: SpellDamage;
+	/** Imposed on a failed saving throw, e.g. paralysis from hold person */
+	condition?: Condition;
+	/** The only creatures the spell affects, e.g. humanoids for hold person */
+	targetCreatureType?: CreatureType;
+	/**
+	 * The target gains no benefit from cover against the saving throw,
+	 * e.g. sacred flame
+	 */
+	ignoresCover: boolean;
+	areaOfEffect?: AreaOfEffect;
+	/** Separate attack rolls per cast, e.g. the three rays of Scorching Ray */
+	attacks: number;
+	concentration: boolean;
+	classes: ClassType[];
+}
+
+/** Everything a character of the class and level may know or prepare */
+export interface SpellChoices {
+	cantripsKnown: number;
+	/**
+	 * None for classes that prepare their spells, up to their spellcasting
+	 * ability modifier plus their level, or half their level for paladins
+	 */
+	spellsKnown?: number;
+	/** Cantrips first, then by spell level and name */
+	spells: SpellModel[];
+}
+
+export type ChallengeRating = 
+	| { t: "eighth", c?: undefined }
+	| { t: "quarter", c?: undefined }
+	| { t: "half", c?: undefined }
+	/** 0 for creatures that pose no threat, up to 30 */
+	| { t: "whole", c: number };
+
+/** Special senses in feet, `None` when the creature lacks them */
+export interface Senses {
+	blindsight?: number;
+	darkvision?: number;
+	tremorsense?: number;
+	truesight?: number;
+	/** 10 plus the Wisdom (Perception) modifier */
+	passivePerception: number;
+}
+
+/**
+ * Damage of one type dealt by an attack, e.g. 2d10 + 8 piercing. Flat
+ * damage such as a bat's 1 piercing is rolled as 1d1
+ */
+export interface MonsterDamage {
+	damageType: DamageType;
+	damageDice: Dice;
+	damageBonus: number;
+}
+
+/**
+ * An attack from a stat block. The to-hit bonus already includes the
+ * monster's ability modifier and proficiency bonus
+ */
+export interface MonsterAttack {
+	name: string;
+	attackRange: WeaponRange;
+	attackBonus: number;
+	/**
+	 * The first damage is the attack's own, any others are dealt on top of
+	 * it, e.g. the fire damage of a red dragon's bite
+	 */
+	damage: MonsterDamage[];
+}
+
+/** Attacks made as part of Multiattack, e.g. two with claws */
+export interface MultiattackStep {
+	/** Names one of the monster's attacks */
+	attackName: string;
+	count: number;
+}
+
+/**
+ * Several attacks taken with one action. Where the stat block offers a
+ * choice, the first option is taken
+ */
+export interface Multiattack {
+	steps: MultiattackStep[];
+}
+
+/** Hit points regained at the start of each of the monster's turns */
+export interface Regeneration {
+	hitPoints: number;
+	/**
+	 * Taking damage of these types stops it at the start of the next turn,
+	 * e.g. acid or fire for a troll
+	 */
+	suppressedBy: DamageType[];
+	/** Vampires do not regenerate in sunlight */
+	suppressedInSunlight: boolean;
+}
+
+/** A trait from a monster's stat block that changes how it fights */
+export type SpecialAbility = 
+	/**
+	 * Advantage on attack rolls against a creature with one of the
+	 * monster's allies within 5 feet of it
+	 */
+	| { t: "packTactics", c?: undefined }
+	/** Advantage on saving throws against spells */
+	| { t: "magicResistance", c?: undefined }
+	| { t: "regeneration", c: Regeneration }
+	/** Disadvantage on attack rolls while in sunlight */
+	| { t: "sunlightSensitivity", c?: undefined }
+	/** May drop to 1 hit point instead of 0 with a Constitution saving throw */
+	| { t: "undeadFortitude", c?: undefined }
+	/** Disengage or Hide as a bonus action */
+	| { t: "nimbleEscape", c?: undefined }
+	/**
+	 * Advantage on Wisdom (Perception) checks relying on some senses, e.g.
+	 * Keen Smell or Keen Hearing and Sight
+	 */
+	| { t: "keenSenses", c?: undefined }
+	/** A melee weapon deals one extra die, already included in the attacks */
+	| { t: "brute", c?: undefined }
+	/** Turns a failed saving throw into a success a number of times a day */
+	| { t: "legendaryResistance", c: number }
+	/** Listed in the stat block but without any effect in combat here */
+	| { t: "notImplemented", c: string };
+
+export interface Recharge {
+	dice: Dice;
+	minValue: number;
+}
+
+/** How often a monster can take one of its actions */
+export type Usage = 
+	/**
+	 * Regained at the start of the monster's turn on a high enough roll,
+	 * e.g. "Recharge 5–6"
+	 */
+	| { t: "rechargeOnRoll", c: Recharge }
+	/** A number of uses, e.g. "3/Day" */
+	| { t: "perDay", c: number }
+	/** Used once until the monster rests, which never happens mid-combat */
+	| { t: "rechargeAfterRest", c?: undefined };
+
+/**
+ * An action that forces a saving throw instead of making an attack roll,
+ * e.g. a dragon's breath weapon
+ */
+export interface MonsterSaveAction {
+	name: string;
+	dc: SpellDc;
+	dcValue: number;
+	/** Rolled once, before the saving throw */
+	damage: MonsterDamage[];
+	/** `None` for actions the monster can take every turn */
+	usage?: Usage;
+}
+
+/** What a legendary action does */
+export type LegendaryActionEffect = 
+	/** Makes one of the monster's attacks, e.g. a dragon's tail attack */
+	| { t: "attack", c: MonsterAttack }
+	/** Forces a saving throw, e.g. a dragon's wing attack */
+	| { t: "saveAction", c: MonsterSaveAction }
+	/**
+	 * Moving, making a check or anything else without an effect in combat
+	 * here
+	 */
+	| { t: "notImplemented", c?: undefined };
+
+/** Taken at the end of another creature's turn */
+export interface LegendaryAction {
+	name: string;
+	/** Most cost 1, others are listed as e.g. "Costs 2 Actions" */
+	cost: number;
+	effect: LegendaryActionEffect;
+}
+
+/**
+ * When a monster with Legendary Resistance turns a failed saving throw
+ * into a success
+ */
+export enum LegendaryResistancePolicy {
+	/** On every failed saving throw while uses are left */
+	Always = "Always",
+	/**
+	 * Only when failing would impose a condition, e.g. paralysis, keeping
+	 * the uses for the worst effects
+	 */
+	AgainstConditions = "AgainstConditions",
+	Never = "Never",
+}
+
+/** A monster's stat block */
+export interface MonsterModel {
+	/** The SRD index, e.g. "goblin" */
+	index: string;
+	name: string;
+	creatureType: CreatureType;
+	/** Already includes any armor or shield the monster is described with */
+	armorClass: ArmorClass;
+	/** The average of the monster's hit dice */
+	hitPoints: number;
+	abilityScores: AbilityScores;
+	/** The saving throws the stat block lists */
+	proficiencies: ProficiencyType[];
+	challengeRating: ChallengeRating;
+	/** Earned for defeating the monster */
+	xp: number;
+	senses: Senses;
+	/** The Dexterity (Stealth) total when the stat block lists it */
+	stealth?: number;
+	specialAbilities: SpecialAbility[];
+	/** The actions that make an attack roll */
+	attacks: MonsterAttack[];
+	multiattack?: Multiattack;
+	/** The actions that force a saving throw and deal damage */
+	saveActions: MonsterSaveAction[];
+	/** Taken at the end of other creatures' turns */
+	legendaryActions: LegendaryAction[];
+}
+
+export interface MonsterBuilder {
+	/** SRD index of the monster's stat block, e.g. "goblin" */
+	monster: string;
+	/** Defaults to the stat block's name */
+	name?: string;
+	/**
+	 * When Legendary Resistance turns a failed saving throw into a success,
+	 * for monsters that have it
+	 */
+	legendaryResistancePolicy?: LegendaryResistancePolicy;
+	proneState: ProneState;
+	coverState: CoverState;
+	/** Placed from the distance between the attacker and the target if left out */
+	position?: Position;
+}
+
+/**
+ * A combatant is either built up as a character or taken from a monster's
+ * stat block
+ */
+export type CombatantBuilder = 
+	| { t: "character", c: CharacterBuilder }
+	| { t: "monster", c: MonsterBuilder };
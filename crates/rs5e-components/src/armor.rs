@@ -12,6 +12,8 @@ pub trait Armor: Identifiable + Defender {
     fn armor_class(&self) -> &ArmorClass;
 
     fn primary_material(&self) -> &Material;
+
+    fn stealth_disadvantage(&self) -> bool;
 }
 
 #[cfg(any(test, feature = "test"))]
@@ -48,6 +50,10 @@ pub mod mocks {
         fn armor_class(&self) -> &ArmorClass {
             &self.armor_class
         }
+
+        fn stealth_disadvantage(&self) -> bool {
+            false
+        }
     }
 
     impl MockArmor {
@@ -57,7 +63,7 @@ pub mod mocks {
             Self {
                 id: Id::new_incremental(),
                 armor_category: ArmorCategory::Light,
-                primary_material: Material::Unknown,
+                primary_material: Material::Metal,
                 armor_type: ArmorType::Breastplate,
                 armor_class: ArmorClass::default(),
             }
@@ -1,14 +1,38 @@
 use super::{damages::Damages, identifiable::Identifiable, weapon::Weapon};
 use crate::{
     combatant::Combatant, has_abilities::HasAbilities, has_armor::HasArmor, has_class::HasClass,
-    has_level::HasLevel,
+    has_divine_smite::HasDivineSmite, has_fighting_style::HasFightingStyle, has_ki::HasKi,
+    has_level::HasLevel, has_proficiencies::HasProficiencies, has_rage::HasRage,
+    has_reckless_attack::HasRecklessAttack, has_sacred_weapon::HasSacredWeapon,
+    has_sneak_attack::HasSneakAttack, has_special_abilities::HasSpecialAbilities,
+    has_spell_slots::HasSpellSlots, has_spellcasting::HasSpellcasting, has_subclass::HasSubclass,
+    has_temporary_hp::HasTemporaryHp,
 };
 use rs5e_concepts::{
     ability_modifier::AbilityModifier, attack_roll::AttackRoll, proficiency_bonus::ProficiencyBonus,
 };
 
 pub trait Attacker:
-    Identifiable + Damages + Combatant + HasArmor + HasLevel + HasClass + HasAbilities
+    Identifiable
+    + Damages
+    + Combatant
+    + HasArmor
+    + HasLevel
+    + HasClass
+    + HasAbilities
+    + HasProficiencies
+    + HasFightingStyle
+    + HasRage
+    + HasRecklessAttack
+    + HasSacredWeapon
+    + HasSneakAttack
+    + HasSpellSlots
+    + HasSpellcasting
+    + HasDivineSmite
+    + HasKi
+    + HasSubclass
+    + HasTemporaryHp
+    + HasSpecialAbilities
 {
     type Weapon: Weapon;
 
@@ -16,15 +40,22 @@ pub trait Attacker:
 
     fn equipped_weapon(&self) -> Option<&Self::Weapon>;
 
+    /// A light weapon held alongside a light main-hand weapon
+    fn equipped_off_hand_weapon(&self) -> Option<&Self::Weapon> {
+        None
+    }
+
     fn roll_attack(
         &self,
         ability_modifier: AbilityModifier,
         proficiency_bonus: Option<ProficiencyBonus>,
+        attack_bonus: i32,
     ) -> AttackRoll {
         AttackRoll::new(
             self.unmodified_attack_roll(),
             ability_modifier,
             proficiency_bonus,
+            attack_bonus,
         )
     }
 }
@@ -37,13 +68,25 @@ pub mod mocks {
     use super::*;
     use rs5e_concepts::{
         ability_scores::AbilityScores,
+        ability_type::AbilityType,
         class_type::ClassType,
         cover_state::{CoverState, DEFAULT_COVER_STATE},
         damage_source::DamageSource,
         damage_type::DamageType,
+        divine_smite::SmitePolicy,
+        fighting_style::FightingStyle,
         id::Id,
+        ki::Ki,
         level::Level,
+        position::{Position, DEFAULT_POSITION},
+        proficiency_bonus::ProficiencyBonus,
+        proficiency_type::ProficiencyType,
         prone_state::{ProneState, DEFAULT_PRONE_STATE},
+        rage::Rage,
+        sacred_weapon::SacredWeapon,
+        special_ability::SpecialAbility,
+        spell_slots::SpellSlots,
+        subclass_type::SubclassType,
     };
     use rs5e_dice::{Dice, Die, DieLoading, DieType};
 
@@ -54,6 +97,7 @@ pub mod mocks {
         level: Level,
         class: ClassType,
         ability_scores: AbilityScores,
+        proficiencies: Vec<ProficiencyType>,
     }
 
     impl<W: Weapon> Identifiable for MockAttacker<W> {
@@ -88,6 +132,10 @@ pub mod mocks {
         fn cover_state(&self) -> &CoverState {
             &DEFAULT_COVER_STATE
         }
+
+        fn position(&self) -> &Position {
+            &DEFAULT_POSITION
+        }
     }
 
     impl<W: Weapon> HasArmor for MockAttacker<W> {
@@ -105,8 +153,8 @@ pub mod mocks {
     }
 
     impl<W: Weapon> HasClass for MockAttacker<W> {
-        fn class(&self) -> &ClassType {
-            &self.class
+        fn class(&self) -> Option<&ClassType> {
+            Some(&self.class)
         }
     }
 
@@ -120,6 +168,126 @@ pub mod mocks {
         }
     }
 
+    impl<W: Weapon> HasProficiencies for MockAttacker<W> {
+        fn proficiencies(&self) -> &[ProficiencyType] {
+            &self.proficiencies
+        }
+
+        fn proficiency_bonus(&self) -> ProficiencyBonus {
+            ProficiencyBonus::from(&self.level)
+        }
+    }
+
+    impl<W: Weapon> HasFightingStyle for MockAttacker<W> {
+        fn fighting_style(&self) -> Option<&FightingStyle> {
+            None
+        }
+    }
+
+    impl<W: Weapon> HasRage for MockAttacker<W> {
+        fn rage(&self) -> Option<&Rage> {
+            None
+        }
+
+        fn rage_mut(&mut self) -> Option<&mut Rage> {
+            None
+        }
+    }
+
+    impl<W: Weapon> HasRecklessAttack for MockAttacker<W> {
+        fn reckless_attack(&self) -> bool {
+            false
+        }
+
+        fn attacked_recklessly(&self) -> bool {
+            false
+        }
+
+        fn set_attacked_recklessly(&mut self, _attacked_recklessly: bool) {}
+    }
+
+    impl<W: Weapon> HasSacredWeapon for MockAttacker<W> {
+        fn sacred_weapon(&self) -> Option<&SacredWeapon> {
+            None
+        }
+
+        fn sacred_weapon_mut(&mut self) -> Option<&mut SacredWeapon> {
+            None
+        }
+    }
+
+    impl<W: Weapon> HasSneakAttack for MockAttacker<W> {
+        fn sneak_attack_used(&self) -> bool {
+            false
+        }
+
+        fn set_sneak_attack_used(&mut self, _sneak_attack_used: bool) {}
+    }
+
+    impl<W: Weapon> HasSpellSlots for MockAttacker<W> {
+        fn spell_slots(&self) -> Option<&SpellSlots> {
+            None
+        }
+
+        fn spell_slots_mut(&mut self) -> Option<&mut SpellSlots> {
+            None
+        }
+    }
+
+    impl<W: Weapon> HasSpellcasting for MockAttacker<W> {
+        fn spellcasting_ability(&self) -> Option<&AbilityType> {
+            None
+        }
+    }
+
+    impl<W: Weapon> HasKi for MockAttacker<W> {
+        fn ki(&self) -> Option<&Ki> {
+            None
+        }
+
+        fn ki_mut(&mut self) -> Option<&mut Ki> {
+            None
+        }
+    }
+
+    impl<W: Weapon> HasDivineSmite for MockAttacker<W> {
+        fn smite_policy(&self) -> Option<&SmitePolicy> {
+            None
+        }
+    }
+
+    impl<W: Weapon> HasSubclass for MockAttacker<W> {
+        fn subclass(&self) -> Option<&SubclassType> {
+            None
+        }
+
+        fn subclass_damage_used(&self) -> bool {
+            false
+        }
+
+        fn set_subclass_damage_used(&mut self, _subclass_damage_used: bool) {}
+    }
+
+    impl<W: Weapon> HasTemporaryHp for MockAttacker<W> {
+        fn temporary_hp(&self) -> u32 {
+            0
+        }
+
+        fn gain_temporary_hp(&mut self, _temporary_hp: u32) {}
+    }
+
+    impl<W: Weapon> HasSpecialAbilities for MockAttacker<W> {
+        fn special_abilities(&self) -> &[SpecialAbility] {
+            &[]
+        }
+
+        fn regeneration_suppressed_by(&self) -> Option<&DamageType> {
+            None
+        }
+
+        fn set_regeneration_suppressed_by(&mut self, _damage_type: Option<DamageType>) {}
+    }
+
     impl<W: Weapon> Attacker for MockAttacker<W> {
         type Weapon = W;
 
@@ -140,6 +308,7 @@ pub mod mocks {
                 level: Level::default(),
                 class: ClassType::Barbarian,
                 ability_scores: AbilityScores::default(),
+                proficiencies: Vec::new(),
             }
         }
     }
@@ -1,7 +1,9 @@
-use rs5e_concepts::{cover_state::CoverState, prone_state::ProneState};
+use rs5e_concepts::{cover_state::CoverState, position::Position, prone_state::ProneState};
 
 pub trait Combatant {
     fn prone_state(&self) -> &ProneState;
 
     fn cover_state(&self) -> &CoverState;
+
+    fn position(&self) -> &Position;
 }
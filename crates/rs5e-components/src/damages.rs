@@ -1,5 +1,7 @@
 use rs5e_concepts::{
-    attack_roll::AttackRoll, damage_roll::DamageRoll, damage_source::DamageSource,
+    attack_roll::AttackRoll,
+    damage_roll::{DamageRoll, DamageRollContext},
+    damage_source::DamageSource,
     damage_type::DamageType,
 };
 use rs5e_dice::Dice;
@@ -24,6 +26,36 @@ pub trait Damages {
             _ => todo!("see comment"),
         }
     }
+
+    fn roll_damage_with_context(&self, context: &DamageRollContext) -> DamageRoll {
+        let damage_dice = match self.damage_source() {
+            DamageSource::Unarmed => context.damage_dice,
+            DamageSource::Weapon => Some(context.damage_dice.unwrap_or_else(|| self.damage_dice())),
+        };
+
+        let damage_roll = damage_dice.map_or_else(
+            || {
+                DamageRoll::from_critical_state_unarmed(
+                    context.ability_modifier.clone(),
+                    context.critical_state,
+                )
+            },
+            |damage_dice| {
+                DamageRoll::from_critical_state(
+                    context.ability_modifier.clone(),
+                    context.critical_state,
+                    &|| {
+                        context.reroll_at_or_below.map_or_else(
+                            || damage_dice.roll(),
+                            |value| damage_dice.roll_rerolling_at_or_below(value),
+                        )
+                    },
+                )
+            },
+        );
+
+        damage_roll.with_damage_bonus(context.damage_bonus)
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
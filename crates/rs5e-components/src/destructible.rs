@@ -40,6 +40,9 @@ pub trait Destructible: Identifiable + Defender + Sized {
 
     fn take_damage(&mut self, damage: u32) -> HpChange;
 
+    /// Healing never raises hit points above the maximum
+    fn regain_hp(&mut self, hp: u32) -> HpChange;
+
     fn destroy(self) -> Self::Destroyed;
 
     fn should_destroy(&self) -> bool {
@@ -66,6 +69,7 @@ pub mod mocks {
     use rs5e_concepts::{
         cover_state::{CoverState, DEFAULT_COVER_STATE},
         id::Id,
+        position::{Position, DEFAULT_POSITION},
         prone_state::{ProneState, DEFAULT_PRONE_STATE},
     };
     use rs5e_macro_derive::Identifiable;
@@ -88,6 +92,10 @@ pub mod mocks {
         fn cover_state(&self) -> &CoverState {
             &DEFAULT_COVER_STATE
         }
+
+        fn position(&self) -> &Position {
+            &DEFAULT_POSITION
+        }
     }
 
     impl HasArmor for MockDestructible {
@@ -116,6 +124,18 @@ pub mod mocks {
                 before,
                 max: self.max_hp(),
                 after: self.hp(),
+                temporary_absorbed: 0,
+            }
+        }
+
+        fn regain_hp(&mut self, hp: u32) -> HpChange {
+            let before = self.hp();
+            self.hp = (self.hp + hp).min(self.max_hp);
+            HpChange {
+                before,
+                max: self.max_hp(),
+                after: self.hp(),
+                temporary_absorbed: 0,
             }
         }
 
@@ -1,5 +1,6 @@
 use rs5e_concepts::class_type::ClassType;
 
 pub trait HasClass {
-    fn class(&self) -> &ClassType;
+    /// `None` for creatures without a class, such as monsters
+    fn class(&self) -> Option<&ClassType>;
 }
@@ -0,0 +1,7 @@
+use rs5e_concepts::concentration::Concentration;
+
+pub trait HasConcentration {
+    fn concentration(&self) -> Option<&Concentration>;
+
+    fn concentration_mut(&mut self) -> &mut Option<Concentration>;
+}
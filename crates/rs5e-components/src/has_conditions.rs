@@ -0,0 +1,20 @@
+use rs5e_concepts::condition::{Condition, SpellCondition};
+
+pub trait HasConditions {
+    fn conditions(&self) -> &[SpellCondition];
+
+    fn conditions_mut(&mut self) -> &mut Vec<SpellCondition>;
+
+    fn has_condition(&self, condition: &Condition) -> bool {
+        self.conditions()
+            .iter()
+            .any(|spell_condition| &spell_condition.condition == condition)
+    }
+
+    /// Incapacitated creatures skip their turn
+    fn is_incapacitated(&self) -> bool {
+        self.conditions()
+            .iter()
+            .any(|spell_condition| spell_condition.condition.incapacitates())
+    }
+}
@@ -0,0 +1,5 @@
+use rs5e_concepts::creature_type::CreatureType;
+
+pub trait HasCreatureType {
+    fn creature_type(&self) -> CreatureType;
+}
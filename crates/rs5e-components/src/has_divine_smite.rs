@@ -0,0 +1,6 @@
+use rs5e_concepts::divine_smite::SmitePolicy;
+
+pub trait HasDivineSmite {
+    /// When they spend a spell slot on a melee hit
+    fn smite_policy(&self) -> Option<&SmitePolicy>;
+}
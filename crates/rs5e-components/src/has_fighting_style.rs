@@ -0,0 +1,5 @@
+use rs5e_concepts::fighting_style::FightingStyle;
+
+pub trait HasFightingStyle {
+    fn fighting_style(&self) -> Option<&FightingStyle>;
+}
@@ -0,0 +1,7 @@
+use rs5e_concepts::ki::Ki;
+
+pub trait HasKi {
+    fn ki(&self) -> Option<&Ki>;
+
+    fn ki_mut(&mut self) -> Option<&mut Ki>;
+}
@@ -0,0 +1,11 @@
+use crate::has_limited_uses::HasLimitedUses;
+use rs5e_concepts::legendary::{LegendaryActionPoints, LegendaryResistancePolicy};
+
+pub trait HasLegendaryActions: HasLimitedUses {
+    /// `None` for creatures without legendary actions
+    fn legendary_action_points(&self) -> Option<&LegendaryActionPoints>;
+
+    fn legendary_action_points_mut(&mut self) -> Option<&mut LegendaryActionPoints>;
+
+    fn legendary_resistance_policy(&self) -> &LegendaryResistancePolicy;
+}
@@ -0,0 +1,19 @@
+use rs5e_concepts::usage::LimitedUse;
+
+pub trait HasLimitedUses {
+    fn limited_uses(&self) -> &[LimitedUse];
+
+    fn limited_uses_mut(&mut self) -> &mut [LimitedUse];
+
+    fn limited_use(&self, name: &str) -> Option<&LimitedUse> {
+        self.limited_uses()
+            .iter()
+            .find(|limited_use| limited_use.name() == name)
+    }
+
+    fn limited_use_mut(&mut self, name: &str) -> Option<&mut LimitedUse> {
+        self.limited_uses_mut()
+            .iter_mut()
+            .find(|limited_use| limited_use.name() == name)
+    }
+}
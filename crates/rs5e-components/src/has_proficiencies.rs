@@ -0,0 +1,12 @@
+use rs5e_concepts::{proficiency_bonus::ProficiencyBonus, proficiency_type::ProficiencyType};
+
+pub trait HasProficiencies {
+    fn proficiencies(&self) -> &[ProficiencyType];
+
+    /// Grows with a character's level or a monster's challenge rating
+    fn proficiency_bonus(&self) -> ProficiencyBonus;
+
+    fn is_proficient(&self, proficiency: &ProficiencyType) -> bool {
+        self.proficiencies().contains(proficiency)
+    }
+}
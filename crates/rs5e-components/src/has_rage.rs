@@ -0,0 +1,12 @@
+use rs5e_concepts::rage::Rage;
+
+pub trait HasRage {
+    /// `None` for anyone who cannot rage
+    fn rage(&self) -> Option<&Rage>;
+
+    fn rage_mut(&mut self) -> Option<&mut Rage>;
+
+    fn is_raging(&self) -> bool {
+        self.rage().is_some_and(Rage::is_raging)
+    }
+}
@@ -0,0 +1,9 @@
+pub trait HasRecklessAttack {
+    /// Whether they choose to attack recklessly on each of their turns
+    fn reckless_attack(&self) -> bool;
+
+    /// Attack rolls against them have advantage until their next turn
+    fn attacked_recklessly(&self) -> bool;
+
+    fn set_attacked_recklessly(&mut self, attacked_recklessly: bool);
+}
@@ -0,0 +1,12 @@
+use rs5e_concepts::sacred_weapon::SacredWeapon;
+
+pub trait HasSacredWeapon {
+    /// `None` for anyone without the Oath of Devotion's Channel Divinity
+    fn sacred_weapon(&self) -> Option<&SacredWeapon>;
+
+    fn sacred_weapon_mut(&mut self) -> Option<&mut SacredWeapon>;
+
+    fn is_sacred_weapon_active(&self) -> bool {
+        self.sacred_weapon().is_some_and(SacredWeapon::is_active)
+    }
+}
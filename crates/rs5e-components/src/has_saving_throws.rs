@@ -0,0 +1,65 @@
+use crate::{
+    combatant::Combatant, has_abilities::HasAbilities, has_conditions::HasConditions,
+    has_level::HasLevel, has_proficiencies::HasProficiencies,
+    has_special_abilities::HasSpecialAbilities,
+};
+use rs5e_concepts::{
+    ability_type::AbilityType,
+    saving_throw::{SavingThrow, SavingThrowBonus, SavingThrowContext},
+    special_ability::{MagicResistanceBonus, MagicResistanceContext},
+};
+
+pub trait HasSavingThrows:
+    HasAbilities + HasProficiencies + HasLevel + HasConditions + HasSpecialAbilities + Combatant
+{
+    fn unmodified_saving_throw_roll(&self) -> u32;
+
+    fn roll_saving_throw(&self, ability: &AbilityType, dc: u32) -> SavingThrow {
+        self.roll_saving_throw_ignoring_cover(ability, dc, false)
+    }
+
+    /// Some spells leave the target no benefit from cover, e.g. sacred flame
+    fn roll_saving_throw_ignoring_cover(
+        &self,
+        ability: &AbilityType,
+        dc: u32,
+        ignores_cover: bool,
+    ) -> SavingThrow {
+        SavingThrow {
+            ability: ability.clone(),
+            raw_value: self.unmodified_saving_throw_roll(),
+            discarded_raw_value: None,
+            bonus: SavingThrowBonus::from(SavingThrowContext {
+                ability,
+                ability_scores: self.ability_scores(),
+                proficiencies: self.proficiencies(),
+                proficiency_bonus: &self.proficiency_bonus(),
+                cover_state: self.cover_state(),
+                ignores_cover,
+            }),
+            dc,
+            automatic_failure: self
+                .conditions()
+                .iter()
+                .any(|spell_condition| spell_condition.condition.fails_saving_throw(ability)),
+            legendary_resistance: false,
+        }
+    }
+
+    /// Magic Resistance rolls saving throws against spells with advantage
+    fn roll_saving_throw_against_spell(
+        &self,
+        ability: &AbilityType,
+        dc: u32,
+        ignores_cover: bool,
+    ) -> SavingThrow {
+        let saving_throw = self.roll_saving_throw_ignoring_cover(ability, dc, ignores_cover);
+
+        match *MagicResistanceBonus::from(MagicResistanceContext {
+            special_abilities: self.special_abilities(),
+        }) {
+            Some(_) => saving_throw.with_advantage(self.unmodified_saving_throw_roll()),
+            None => saving_throw,
+        }
+    }
+}
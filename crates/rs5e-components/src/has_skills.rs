@@ -0,0 +1,45 @@
+use crate::{has_abilities::HasAbilities, has_class::HasClass, has_level::HasLevel};
+use rs5e_concepts::{
+    ability_type::AbilityType,
+    initiative::{FeralInstinctBonus, FeralInstinctContext, Initiative},
+};
+
+pub trait HasSkills: HasAbilities + HasClass + HasLevel {
+    fn unmodified_ability_check_roll(&self) -> u32;
+
+    /// The Dexterity (Stealth) modifier
+    fn stealth_bonus(&self) -> i32 {
+        self.ability_score(&AbilityType::Dexterity)
+            .modifier()
+            .value()
+    }
+
+    /// 10 plus the Wisdom (Perception) modifier
+    fn passive_perception(&self) -> u32 {
+        10_u32.saturating_add_signed(self.ability_score(&AbilityType::Wisdom).modifier().value())
+    }
+
+    fn roll_stealth_check(&self) -> i32 {
+        i32::try_from(self.unmodified_ability_check_roll()).unwrap_or(0) + self.stealth_bonus()
+    }
+
+    /// Feral Instinct rolls initiative with advantage
+    fn roll_initiative(&self) -> Initiative {
+        let dexterity_score = self.ability_score(&AbilityType::Dexterity);
+        let initiative = Initiative {
+            raw_value: self.unmodified_ability_check_roll(),
+            discarded_raw_value: None,
+            dexterity_modifier: dexterity_score.modifier(),
+            dexterity_score: **dexterity_score,
+            tiebreaker: None,
+        };
+
+        match *FeralInstinctBonus::from(FeralInstinctContext {
+            class: self.class(),
+            level: self.level(),
+        }) {
+            Some(_) => initiative.with_advantage(self.unmodified_ability_check_roll()),
+            None => initiative,
+        }
+    }
+}
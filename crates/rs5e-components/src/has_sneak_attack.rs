@@ -0,0 +1,6 @@
+pub trait HasSneakAttack {
+    /// Sneak Attack can only be dealt once per turn
+    fn sneak_attack_used(&self) -> bool;
+
+    fn set_sneak_attack_used(&mut self, sneak_attack_used: bool);
+}
@@ -0,0 +1,12 @@
+use rs5e_concepts::{damage_type::DamageType, special_ability::SpecialAbility};
+
+pub trait HasSpecialAbilities {
+    /// Traits from a monster's stat block, characters have none
+    fn special_abilities(&self) -> &[SpecialAbility];
+
+    /// Damage taken since the creature's last turn that stops its
+    /// Regeneration, e.g. fire for a troll
+    fn regeneration_suppressed_by(&self) -> Option<&DamageType>;
+
+    fn set_regeneration_suppressed_by(&mut self, damage_type: Option<DamageType>);
+}
@@ -0,0 +1,7 @@
+use rs5e_concepts::spell_slots::SpellSlots;
+
+pub trait HasSpellSlots {
+    fn spell_slots(&self) -> Option<&SpellSlots>;
+
+    fn spell_slots_mut(&mut self) -> Option<&mut SpellSlots>;
+}
@@ -0,0 +1,6 @@
+use rs5e_concepts::ability_type::AbilityType;
+
+pub trait HasSpellcasting {
+    /// None for characters who cannot cast spells at their class and level
+    fn spellcasting_ability(&self) -> Option<&AbilityType>;
+}
@@ -0,0 +1,11 @@
+use rs5e_concepts::subclass_type::SubclassType;
+
+pub trait HasSubclass {
+    fn subclass(&self) -> Option<&SubclassType>;
+
+    /// Subclass damage like Colossus Slayer or Divine Strike can only be
+    /// dealt once per turn
+    fn subclass_damage_used(&self) -> bool;
+
+    fn set_subclass_damage_used(&mut self, subclass_damage_used: bool);
+}
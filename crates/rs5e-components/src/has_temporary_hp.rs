@@ -0,0 +1,6 @@
+pub trait HasTemporaryHp {
+    fn temporary_hp(&self) -> u32;
+
+    /// Temporary hit points do not stack, the higher amount is kept
+    fn gain_temporary_hp(&mut self, temporary_hp: u32);
+}
@@ -13,7 +13,27 @@ pub mod destructible;
 pub mod has_abilities;
 pub mod has_armor;
 pub mod has_class;
+pub mod has_concentration;
+pub mod has_conditions;
+pub mod has_creature_type;
+pub mod has_divine_smite;
+pub mod has_fighting_style;
+pub mod has_ki;
+pub mod has_legendary_actions;
 pub mod has_level;
+pub mod has_limited_uses;
+pub mod has_proficiencies;
+pub mod has_rage;
+pub mod has_reckless_attack;
+pub mod has_sacred_weapon;
+pub mod has_saving_throws;
+pub mod has_skills;
+pub mod has_sneak_attack;
+pub mod has_special_abilities;
+pub mod has_spell_slots;
+pub mod has_spellcasting;
+pub mod has_subclass;
+pub mod has_temporary_hp;
 pub mod identifiable;
 pub mod named;
 pub mod weapon;
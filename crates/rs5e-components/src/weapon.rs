@@ -1,6 +1,7 @@
 use super::{damages::Damages, identifiable::Identifiable};
 use rs5e_concepts::{
-    weapon_category::WeaponCategory, weapon_range::WeaponRange, weapon_type::WeaponType,
+    weapon_category::WeaponCategory, weapon_property::WeaponProperty, weapon_range::WeaponRange,
+    weapon_type::WeaponType,
 };
 
 pub trait Weapon: Identifiable + Damages {
@@ -9,6 +10,12 @@ pub trait Weapon: Identifiable + Damages {
     fn weapon_category(&self) -> &WeaponCategory;
 
     fn weapon_type(&self) -> &WeaponType;
+
+    fn properties(&self) -> &[WeaponProperty];
+
+    fn has_property(&self, property: &WeaponProperty) -> bool {
+        self.properties().contains(property)
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
@@ -27,6 +34,7 @@ pub mod mocks {
         weapon_range: WeaponRange,
         weapon_category: WeaponCategory,
         weapon_type: WeaponType,
+        properties: Vec<WeaponProperty>,
     }
 
     impl Damages for MockWeapon {
@@ -55,6 +63,10 @@ pub mod mocks {
         fn weapon_type(&self) -> &WeaponType {
             &self.weapon_type
         }
+
+        fn properties(&self) -> &[WeaponProperty] {
+            &self.properties
+        }
     }
 
     impl MockWeapon {
@@ -67,6 +79,11 @@ pub mod mocks {
                 weapon_range: WeaponRange::Melee,
                 weapon_category: WeaponCategory::Simple,
                 weapon_type: WeaponType::Dagger,
+                properties: vec![
+                    WeaponProperty::Finesse,
+                    WeaponProperty::Light,
+                    WeaponProperty::Thrown,
+                ],
             }
         }
     }
@@ -1,4 +1,4 @@
-use crate::weapon_range::WeaponRange;
+use crate::{util::macros::implement_from_str, weapon_range::WeaponRange};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
@@ -29,3 +29,16 @@ impl From<&WeaponRange> for AbilityType {
         }
     }
 }
+
+// Abbreviations as used by the SRD ability score data
+implement_from_str!(
+    AbilityType,
+    [
+        ["str", Strength],
+        ["dex", Dexterity],
+        ["con", Constitution],
+        ["int", Intelligence],
+        ["wis", Wisdom],
+        ["cha", Charisma]
+    ]
+);
@@ -0,0 +1,178 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/spellcasting#AreasofEffect>
+
+use crate::{
+    position::Position,
+    spell::{AreaOfEffect, AreaOfEffectType, SpellRange},
+};
+
+/// Lines are 5 feet wide unless the spell says otherwise
+pub const LINE_WIDTH: u32 = 5;
+
+#[derive(Debug)]
+pub struct AreaOfEffectContext<'a> {
+    pub area_of_effect: &'a AreaOfEffect,
+    pub range: &'a SpellRange,
+    pub caster: Position,
+    /// The point the caster aims at, the position of their target
+    pub target: Position,
+}
+
+/// An area of effect placed on the battlefield
+#[derive(Debug, PartialEq, Eq)]
+pub struct AreaOfEffectTemplate {
+    pub area_type: AreaOfEffectType,
+    /// Feet, e.g. the radius of a sphere or the length of a cone
+    pub size: u32,
+    /// The center of a sphere or cylinder, or the point a cone, cube or line
+    /// extends from. A cone, cube or line does not include its point of origin
+    pub origin: Position,
+    /// The direction a cone, cube or line extends in
+    pub toward: Position,
+}
+
+impl From<AreaOfEffectContext<'_>> for AreaOfEffectTemplate {
+    fn from(
+        AreaOfEffectContext {
+            area_of_effect,
+            range,
+            caster,
+            target,
+        }: AreaOfEffectContext,
+    ) -> Self {
+        let AreaOfEffect { area_type, size } = *area_of_effect;
+
+        let (origin, toward) = match (range, area_type) {
+            (SpellRange::SelfOnly, _) | (_, AreaOfEffectType::Cone | AreaOfEffectType::Line) => {
+                (caster, target)
+            }
+            // Spheres and cylinders with a range are centered on the target
+            (_, AreaOfEffectType::Sphere | AreaOfEffectType::Cylinder) => (target, target),
+            // Cubes with a range are placed with the target at their center
+            (_, AreaOfEffectType::Cube) => (
+                caster.toward(
+                    &target,
+                    caster.distance_to(&target).saturating_sub(size / 2),
+                ),
+                target,
+            ),
+        };
+
+        Self {
+            area_type,
+            size,
+            origin,
+            toward,
+        }
+    }
+}
+
+impl AreaOfEffectTemplate {
+    /// Creatures are treated as a single point at their position
+    #[must_use]
+    pub fn contains(&self, position: &Position) -> bool {
+        let size = i128::from(self.size);
+        let width = i128::from(LINE_WIDTH);
+        let (px, py) = self.origin.offset_to(position);
+        let squared_distance = px * px + py * py;
+
+        if matches!(
+            self.area_type,
+            AreaOfEffectType::Sphere | AreaOfEffectType::Cylinder
+        ) {
+            return squared_distance <= size * size;
+        }
+
+        // Further than a cube's far corners or a line's far edge is outside
+        // every shape, which also keeps the products below from overflowing
+        if squared_distance > 2 * size * size + width * width {
+            return false;
+        }
+
+        let (dx, dy) = self.origin.offset_to(&self.toward);
+        let length_squared = dx * dx + dy * dy;
+        if length_squared == 0 {
+            return false;
+        }
+
+        // Both are scaled by the length of the direction to stay in integers
+        let along = px * dx + py * dy;
+        let across = (px * dy - py * dx).abs();
+        let scaled = |feet: i128| feet.saturating_mul(feet).saturating_mul(length_squared);
+
+        along > 0
+            && along.saturating_mul(along) <= scaled(size)
+            && match self.area_type {
+                // A cone's width at a point equals the distance from its origin
+                AreaOfEffectType::Cone => 2 * across <= along,
+                AreaOfEffectType::Cube => 4 * across.saturating_mul(across) <= scaled(size),
+                AreaOfEffectType::Line => 4 * across.saturating_mul(across) <= scaled(width),
+                AreaOfEffectType::Sphere | AreaOfEffectType::Cylinder => true,
+            }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(area_type: AreaOfEffectType, size: u32, range: SpellRange) -> AreaOfEffectTemplate {
+        AreaOfEffectTemplate::from(AreaOfEffectContext {
+            area_of_effect: &AreaOfEffect { area_type, size },
+            range: &range,
+            caster: Position::new(0, 0),
+            target: Position::new(10, 0),
+        })
+    }
+
+    #[test]
+    fn areas_catch_creatures_by_shape() {
+        let fireball = template(AreaOfEffectType::Sphere, 20, SpellRange::Feet(150));
+        assert!(fireball.contains(&Position::new(10, 0)));
+        assert!(fireball.contains(&Position::new(0, 0)));
+        assert!(!fireball.contains(&Position::new(-15, 0)));
+
+        let burning_hands = template(AreaOfEffectType::Cone, 15, SpellRange::SelfOnly);
+        assert!(burning_hands.contains(&Position::new(10, 5)));
+        assert!(!burning_hands.contains(&Position::new(10, 10)));
+        assert!(!burning_hands.contains(&Position::new(20, 0)));
+        assert!(!burning_hands.contains(&Position::new(0, 0)));
+
+        let lightning_bolt = template(AreaOfEffectType::Line, 100, SpellRange::SelfOnly);
+        assert!(lightning_bolt.contains(&Position::new(90, 0)));
+        assert!(!lightning_bolt.contains(&Position::new(50, 5)));
+        assert!(!lightning_bolt.contains(&Position::new(-10, 0)));
+
+        let thunderwave = template(AreaOfEffectType::Cube, 15, SpellRange::SelfOnly);
+        assert!(thunderwave.contains(&Position::new(15, 5)));
+        assert!(!thunderwave.contains(&Position::new(5, 10)));
+    }
+
+    #[test]
+    fn opposite_corners_do_not_overflow() {
+        let (min, max) = (
+            Position::new(i32::MIN, i32::MIN),
+            Position::new(i32::MAX, i32::MAX),
+        );
+        let template = |area_type, size, range| {
+            AreaOfEffectTemplate::from(AreaOfEffectContext {
+                area_of_effect: &AreaOfEffect { area_type, size },
+                range: &range,
+                caster: min,
+                target: max,
+            })
+        };
+
+        let fireball = template(AreaOfEffectType::Sphere, 20, SpellRange::Feet(150));
+        assert!(fireball.contains(&max));
+        assert!(!fireball.contains(&min));
+
+        let burning_hands = template(AreaOfEffectType::Cone, 15, SpellRange::SelfOnly);
+        assert!(burning_hands.contains(&Position::new(i32::MIN + 5, i32::MIN + 5)));
+        assert!(!burning_hands.contains(&max));
+
+        let lightning_bolt = template(AreaOfEffectType::Line, 100, SpellRange::SelfOnly);
+        assert!(lightning_bolt.contains(&Position::new(i32::MIN + 50, i32::MIN + 50)));
+        assert!(!lightning_bolt.contains(&Position::new(i32::MIN + 50, i32::MAX)));
+        assert!(!lightning_bolt.contains(&max));
+    }
+}
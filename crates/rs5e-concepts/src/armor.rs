@@ -14,4 +14,5 @@ pub struct ArmorModel {
     pub armor_class: ArmorClass,
     pub armor_category: ArmorCategory,
     pub primary_material: Material,
+    pub stealth_disadvantage: bool,
 }
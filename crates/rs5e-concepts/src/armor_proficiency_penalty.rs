@@ -1,15 +1,14 @@
 use crate::{
     advantage_type::{AdvantageType, Disadvantage},
     armor_category::ArmorCategory,
-    class_type::ClassType,
     material::Material,
-    proficiency_type::{proficiency_type_by_class_map, ProficiencyType},
+    proficiency_type::ProficiencyType,
 };
 use std::ops::{Deref, Not};
 
 #[derive(Debug)]
 pub struct ArmorProficiencyContext<'a> {
-    pub class: &'a ClassType,
+    pub proficiencies: &'a [ProficiencyType],
     pub armor_category: Option<&'a ArmorCategory>,
     pub armor_primary_material: Option<&'a Material>,
 }
@@ -30,30 +29,25 @@ impl From<ArmorProficiencyContext<'_>> for ArmorProficiencyPenalty {
         ArmorProficiencyContext {
             armor_category,
             armor_primary_material,
-            class,
+            proficiencies,
         }: ArmorProficiencyContext,
     ) -> Self {
         let mut has_proficiency = armor_category
             // Unarmored targets have proficiency
             .map_or(true, |cat| {
-                proficiency_type_by_class_map()
-                    .get(class)
-                    .is_some_and(|class_proficiency_list| {
-                        class_proficiency_list
-                            .contains(&ProficiencyType::ArmorCategory(cat.clone()))
-                    })
+                proficiencies.contains(&ProficiencyType::ArmorCategory(cat.clone()))
             });
 
-        if has_proficiency && class == &ClassType::Druid {
+        if has_proficiency {
             has_proficiency = armor_primary_material
                 // Unarmored targets have proficiency
                 .map_or(true, |mat| {
-                    proficiency_type_by_class_map().get(class).is_some_and(
-                        |class_proficiency_list| {
-                            class_proficiency_list
-                                .contains(&ProficiencyType::ArmorMaterial(mat.clone()))
-                        },
-                    )
+                    proficiencies.iter().all(|p| match p {
+                        ProficiencyType::ArmorMaterialRestriction(restriction) => {
+                            restriction.allows(mat)
+                        }
+                        _ => true,
+                    })
                 });
         }
 
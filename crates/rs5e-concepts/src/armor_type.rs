@@ -45,27 +45,51 @@ impl  ArmorType {
 }
 
 impl ArmorType {
-    #[must_use]
+    /// The material the armor is made of unless a variant is chosen
+    ///
     /// Resources:
     /// <https://rpg.stackexchange.com/questions/105486/what-is-metal-armor>
     /// <https://roll20.net/compendium/dnd5e/Armor#content>
+    #[must_use]
     pub const fn primary_material(&self) -> Material {
         match self {
-            | Self::HideArmor
-            | Self::LeatherArmor
-            | Self::PaddedArmor
+            Self::PaddedArmor => Material::Cloth,
+            // Studded leather is reinforced with rivets but is made from leather
+            Self::LeatherArmor | Self::StuddedLeatherArmor => Material::Leather,
+            Self::HideArmor => Material::Hide,
+            // Rules say shields can come in metal or wood variants,
+            // we are defaulting to wood for the basic one
+            Self::Shield => Material::Wood,
+            Self::Breastplate
+            | Self::ChainMail
+            | Self::ChainShirt
+            | Self::HalfPlateArmor
+            | Self::PlateArmor
+            | Self::RingMail
+            | Self::ScaleMail
+            | Self::SplintArmor => Material::Metal,
+        }
+    }
+
+    /// Every material this armor can be made of, starting with the primary material
+    ///
+    /// Mithral and adamantine variants exist for medium and heavy armor that
+    /// isn't hide: <https://roll20.net/compendium/dnd5e/Adamantine%20Armor>
+    #[must_use]
+    pub const fn material_variants(&self) -> &'static [Material] {
+        match self {
+            Self::PaddedArmor => &[Material::Cloth],
+            Self::LeatherArmor | Self::StuddedLeatherArmor => &[Material::Leather],
+            Self::HideArmor => &[Material::Hide],
+            Self::Shield => &[Material::Wood, Material::Metal],
+            Self::Breastplate
+            | Self::ChainMail
+            | Self::ChainShirt
+            | Self::HalfPlateArmor
+            | Self::PlateArmor
             | Self::RingMail
-            // Rules say shields can technically come in metal or wood variants
-            // we are defaulting to wood for this basic one
-            | Self::Shield => Material::Unknown,
-            Self::Breastplate 
-            | Self::ChainMail 
-            | Self::ChainShirt 
-            | Self::HalfPlateArmor  
-            | Self::PlateArmor 
-            | Self::ScaleMail  
-            | Self::SplintArmor 
-            | Self::StuddedLeatherArmor => Material::Metal,
+            | Self::ScaleMail
+            | Self::SplintArmor => &[Material::Metal, Material::Mithral, Material::Adamantine],
         }
     }
 }
@@ -1,11 +1,16 @@
 use super::critical_state::CriticalState;
-use crate::{ability_modifier::AbilityModifier, proficiency_bonus::ProficiencyBonus, roll::Roll};
+use crate::{
+    ability_modifier::AbilityModifier, improved_critical::DEFAULT_CRITICAL_RANGE,
+    proficiency_bonus::ProficiencyBonus, roll::Roll,
+};
 
 #[derive(Debug)]
 pub struct AttackRoll {
     pub(crate) roll_value: u32,
     pub(crate) ability_modifier: AbilityModifier,
     pub(crate) proficiency_bonus: Option<ProficiencyBonus>,
+    /// Flat bonuses from features like the Archery fighting style
+    pub(crate) attack_bonus: i32,
 }
 
 impl AttackRoll {
@@ -14,19 +19,27 @@ impl AttackRoll {
         roll_value: u32,
         ability_modifier: AbilityModifier,
         proficiency_bonus: Option<ProficiencyBonus>,
+        attack_bonus: i32,
     ) -> Self {
         Self {
             roll_value,
             ability_modifier,
             proficiency_bonus,
+            attack_bonus,
         }
     }
 
     #[must_use]
     pub const fn critical_state(&self) -> CriticalState {
+        self.critical_state_in_range(DEFAULT_CRITICAL_RANGE)
+    }
+
+    /// Features like Improved Critical score a critical hit below a 20
+    #[must_use]
+    pub const fn critical_state_in_range(&self, critical_range: u32) -> CriticalState {
         match self.roll_value {
-            20 => CriticalState::Critical,
             1 => CriticalState::CriticalFail,
+            roll_value if roll_value >= critical_range => CriticalState::Critical,
             _ => CriticalState::Normal,
         }
     }
@@ -45,6 +58,11 @@ impl AttackRoll {
     pub const fn proficiency_bonus(&self) -> Option<&ProficiencyBonus> {
         self.proficiency_bonus.as_ref()
     }
+
+    #[must_use]
+    pub const fn attack_bonus(&self) -> i32 {
+        self.attack_bonus
+    }
 }
 
 impl Roll for AttackRoll {
@@ -55,6 +73,7 @@ impl Roll for AttackRoll {
     fn bonus_value(&self) -> i32 {
         self.ability_modifier().value()
             + (self.proficiency_bonus().map(|p| p.value()).unwrap_or(0) as i32)
+            + self.attack_bonus
     }
 }
 
@@ -69,6 +88,7 @@ pub mod mocks {
                 roll_value: 1,
                 ability_modifier: AbilityModifier::new(1),
                 proficiency_bonus: Some(ProficiencyBonus::new(0)),
+                attack_bonus: 0,
             }
         }
 
@@ -78,6 +98,7 @@ pub mod mocks {
                 roll_value: 10,
                 ability_modifier: AbilityModifier::new(2),
                 proficiency_bonus: Some(ProficiencyBonus::new(1)),
+                attack_bonus: 0,
             }
         }
 
@@ -87,6 +108,7 @@ pub mod mocks {
                 roll_value: 20,
                 ability_modifier: AbilityModifier::new(0),
                 proficiency_bonus: Some(ProficiencyBonus::new(1)),
+                attack_bonus: 0,
             }
         }
     }
@@ -114,6 +136,10 @@ pub mod tests {
 
         assert_eq!(normal_attack_roll.total_value(), 13);
         assert_eq!(normal_attack_roll.critical_state(), CriticalState::Normal);
+        assert_eq!(
+            normal_attack_roll.critical_state_in_range(10),
+            CriticalState::Critical
+        );
     }
 
     #[test]
@@ -0,0 +1,58 @@
+use crate::{critical_state::CriticalState, damage_type::DamageType};
+use rs5e_dice::Dice;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BonusDamageSource {
+    SneakAttack,
+    DivineSmite {
+        slot_level: u32,
+    },
+    ColossusSlayer,
+    DivineStrike,
+    /// Listed in a monster's attack on top of its own damage
+    AdditionalDamage,
+}
+
+/// Extra dice rolled on a hit on top of the weapon's damage roll
+#[derive(Debug)]
+pub struct BonusDamageRoll {
+    pub source: BonusDamageSource,
+    pub dice: Dice,
+    pub damage_type: DamageType,
+    pub roll_value: u32,
+    /// The dice are rolled again on a critical hit
+    pub critical_roll_value: Option<u32>,
+    /// Added once, e.g. the +6 of a behir's 2d10 + 6 slashing damage
+    pub damage_bonus: i32,
+}
+
+impl BonusDamageRoll {
+    #[must_use]
+    pub fn roll(
+        source: BonusDamageSource,
+        dice: Dice,
+        damage_type: DamageType,
+        critical_state: CriticalState,
+    ) -> Self {
+        Self {
+            source,
+            dice,
+            damage_type,
+            roll_value: dice.roll(),
+            critical_roll_value: critical_state.is_critical().then(|| dice.roll()),
+            damage_bonus: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_damage_bonus(mut self, damage_bonus: i32) -> Self {
+        self.damage_bonus = damage_bonus;
+        self
+    }
+
+    #[must_use]
+    pub fn total_value(&self) -> u32 {
+        (self.roll_value + self.critical_roll_value.unwrap_or(0))
+            .saturating_add_signed(self.damage_bonus)
+    }
+}
@@ -0,0 +1,92 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/monsters#ChallengeRating>
+
+use crate::proficiency_bonus::ProficiencyBonus;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+pub const MAX_CHALLENGE_RATING: u32 = 30;
+
+/// Monsters below challenge rating 5 have a +2 proficiency bonus, which
+/// grows by 1 every 4 challenge ratings after that
+const BASE_PROFICIENCY_BONUS: u32 = 2;
+const CHALLENGE_RATINGS_PER_PROFICIENCY_BONUS: u32 = 4;
+
+#[derive(Debug)]
+pub struct InvalidChallengeRating;
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "camelCase", tag = "t", content = "c")]
+pub enum ChallengeRating {
+    Eighth,
+    Quarter,
+    Half,
+    /// 0 for creatures that pose no threat, up to 30
+    Whole(u32),
+}
+
+/// The SRD data writes challenge ratings as numbers, e.g. 0.25
+impl TryFrom<f64> for ChallengeRating {
+    type Error = InvalidChallengeRating;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        let matches = |rating: f64| (rating - value).abs() < f64::EPSILON;
+
+        [
+            (0.125, Self::Eighth),
+            (0.25, Self::Quarter),
+            (0.5, Self::Half),
+        ]
+        .into_iter()
+        .find(|(rating, _)| matches(*rating))
+        .map(|(_, challenge_rating)| challenge_rating)
+        .or_else(|| {
+            (0..=MAX_CHALLENGE_RATING)
+                .find(|rating| matches(f64::from(*rating)))
+                .map(Self::Whole)
+        })
+        .ok_or(InvalidChallengeRating)
+    }
+}
+
+impl ChallengeRating {
+    #[must_use]
+    pub const fn proficiency_bonus(&self) -> ProficiencyBonus {
+        ProficiencyBonus::new(match self {
+            Self::Whole(rating) if *rating > 0 => {
+                BASE_PROFICIENCY_BONUS + (*rating - 1) / CHALLENGE_RATINGS_PER_PROFICIENCY_BONUS
+            }
+            _ => BASE_PROFICIENCY_BONUS,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proficiency_bonus_grows_every_four_challenge_ratings() {
+        let proficiency_bonus = |rating| {
+            ChallengeRating::try_from(rating)
+                .unwrap()
+                .proficiency_bonus()
+                .value()
+        };
+
+        assert_eq!(
+            ChallengeRating::try_from(0.25).ok(),
+            Some(ChallengeRating::Quarter)
+        );
+        assert!(ChallengeRating::try_from(0.3).is_err());
+        assert_eq!(proficiency_bonus(0.0), 2);
+        assert_eq!(proficiency_bonus(0.125), 2);
+        assert_eq!(proficiency_bonus(4.0), 2);
+        assert_eq!(proficiency_bonus(5.0), 3);
+        assert_eq!(proficiency_bonus(17.0), 6);
+        assert_eq!(proficiency_bonus(21.0), 7);
+        assert_eq!(proficiency_bonus(30.0), 9);
+    }
+}
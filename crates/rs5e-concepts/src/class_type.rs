@@ -1,3 +1,4 @@
+use crate::util::macros::implement_from_str;
 use std::fmt::Display;
 
 #[cfg(feature = "serde")]
@@ -29,3 +30,21 @@ impl Display for ClassType {
         write!(f, "{self:?}")
     }
 }
+
+implement_from_str!(
+    ClassType,
+    [
+        ["barbarian", Barbarian],
+        ["bard", Bard],
+        ["cleric", Cleric],
+        ["druid", Druid],
+        ["fighter", Fighter],
+        ["monk", Monk],
+        ["paladin", Paladin],
+        ["ranger", Ranger],
+        ["rogue", Rogue],
+        ["sorcerer", Sorcerer],
+        ["warlock", Warlock],
+        ["wizard", Wizard]
+    ]
+);
@@ -0,0 +1,45 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#Hunter>
+
+use crate::{level::Level, subclass_type::SubclassType, weapon::WeaponModel};
+use rs5e_dice::{Dice, Die, DieType};
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct ColossusSlayerContext<'a> {
+    pub subclass: Option<&'a SubclassType>,
+    pub level: &'a Level,
+    pub weapon: Option<&'a WeaponModel>,
+    pub target_below_hp_max: bool,
+}
+
+/// The Hunter's Prey chosen by every hunter here. Dealt once per turn on a
+/// weapon hit against a creature below its hit point maximum.
+#[derive(Debug)]
+pub struct ColossusSlayerDice(Option<Dice>);
+
+impl Deref for ColossusSlayerDice {
+    type Target = Option<Dice>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<ColossusSlayerContext<'_>> for ColossusSlayerDice {
+    fn from(
+        ColossusSlayerContext {
+            subclass,
+            level,
+            weapon,
+            target_below_hp_max,
+        }: ColossusSlayerContext,
+    ) -> Self {
+        Self(
+            (subclass == Some(&SubclassType::Hunter)
+                && **level >= SubclassType::Hunter.level()
+                && weapon.is_some()
+                && target_below_hp_max)
+                .then_some(Dice::new(1, Die::new(DieType::D8))),
+        )
+    }
+}
@@ -0,0 +1,53 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/spellcasting#Concentration>
+
+use crate::id::Id;
+use std::ops::Deref;
+
+/// The lowest DC of the Constitution saving throw to keep concentrating
+pub const CONCENTRATION_MINIMUM_DC: u32 = 10;
+
+/// A spell kept going by its caster, who can only concentrate on one at a time
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Concentration {
+    pub caster: Id,
+    pub spell_name: String,
+}
+
+#[derive(Debug)]
+pub struct ConcentrationDcContext {
+    /// Damage taken from a single source
+    pub damage: u32,
+}
+
+/// 10 or half the damage taken, whichever is higher
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConcentrationDc(u32);
+
+impl Deref for ConcentrationDc {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<ConcentrationDcContext> for ConcentrationDc {
+    fn from(ConcentrationDcContext { damage }: ConcentrationDcContext) -> Self {
+        Self(CONCENTRATION_MINIMUM_DC.max(damage / 2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concentration_dc_is_half_the_damage_but_at_least_10() {
+        let dc = |damage| *ConcentrationDc::from(ConcentrationDcContext { damage });
+
+        assert_eq!(dc(1), 10);
+        assert_eq!(dc(21), 10);
+        assert_eq!(dc(22), 11);
+        assert_eq!(dc(45), 22);
+    }
+}
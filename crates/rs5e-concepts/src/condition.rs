@@ -0,0 +1,88 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/appendix-a-conditions>
+
+use crate::{
+    ability_type::AbilityType,
+    advantage_type::{Advantage, AdvantageType},
+    id::Id,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+use typeshare::typeshare;
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Condition {
+    /// Incapacitated and unable to move or speak
+    Paralyzed,
+}
+
+impl Condition {
+    /// Incapacitated creatures cannot take actions or reactions
+    #[must_use]
+    pub const fn incapacitates(&self) -> bool {
+        match self {
+            Self::Paralyzed => true,
+        }
+    }
+
+    #[must_use]
+    pub const fn fails_saving_throw(&self, ability: &AbilityType) -> bool {
+        match self {
+            Self::Paralyzed => matches!(ability, AbilityType::Strength | AbilityType::Dexterity),
+        }
+    }
+}
+
+/// A condition imposed by a spell, which the creature tries to shake off
+/// with a saving throw at the end of each of its turns
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SpellCondition {
+    pub condition: Condition,
+    /// Ends early if the caster stops concentrating on the spell
+    pub caster: Id,
+    pub spell_name: String,
+    pub dc_type: AbilityType,
+    pub save_dc: u32,
+}
+
+#[derive(Debug)]
+pub struct ParalyzedTargetContext {
+    pub paralyzed: bool,
+}
+
+/// Attack rolls against a paralyzed creature have advantage, and any hit
+/// from within 5 feet is a critical hit
+#[derive(Debug)]
+pub struct ParalyzedTargetBonus(Option<Advantage>);
+
+impl Deref for ParalyzedTargetBonus {
+    type Target = Option<Advantage>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl ParalyzedTargetBonus {
+    #[must_use]
+    pub const fn melee_hits_critical(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+impl From<ParalyzedTargetContext> for ParalyzedTargetBonus {
+    fn from(ParalyzedTargetContext { paralyzed }: ParalyzedTargetContext) -> Self {
+        Self(paralyzed.then_some(Advantage))
+    }
+}
+
+impl From<&ParalyzedTargetBonus> for AdvantageType {
+    fn from(value: &ParalyzedTargetBonus) -> Self {
+        value
+            .deref()
+            .as_ref()
+            .map_or(Self::Normal, |advantage| Self::Advantage(advantage.clone()))
+    }
+}
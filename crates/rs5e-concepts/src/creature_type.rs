@@ -0,0 +1,44 @@
+use crate::util::macros::implement_from_str;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CreatureType {
+    Aberration,
+    Beast,
+    Celestial,
+    Construct,
+    Dragon,
+    Elemental,
+    Fey,
+    Fiend,
+    Giant,
+    Humanoid,
+    Monstrosity,
+    Ooze,
+    Plant,
+    Undead,
+}
+
+implement_from_str!(
+    CreatureType,
+    [
+        ["aberration", Aberration],
+        ["beast", Beast],
+        ["celestial", Celestial],
+        ["construct", Construct],
+        ["dragon", Dragon],
+        ["elemental", Elemental],
+        ["fey", Fey],
+        ["fiend", Fiend],
+        ["giant", Giant],
+        ["humanoid", Humanoid],
+        ["monstrosity", Monstrosity],
+        ["ooze", Ooze],
+        ["plant", Plant],
+        ["undead", Undead]
+    ]
+);
@@ -0,0 +1,65 @@
+use crate::damage_type::DamageType;
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct DamageResistanceContext {
+    /// Raging barbarians resist bludgeoning, piercing and slashing damage
+    pub raging: bool,
+}
+
+/// Damage of a resisted type is halved, rounded down
+#[derive(Debug, Default)]
+pub struct DamageResistances(Vec<DamageType>);
+
+impl Deref for DamageResistances {
+    type Target = [DamageType];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DamageResistances {
+    #[must_use]
+    pub fn resists(&self, damage_type: &DamageType) -> bool {
+        self.0.contains(damage_type)
+    }
+
+    #[must_use]
+    pub fn apply(&self, damage_type: &DamageType, damage: u32) -> u32 {
+        if self.resists(damage_type) {
+            damage / 2
+        } else {
+            damage
+        }
+    }
+}
+
+impl From<DamageResistanceContext> for DamageResistances {
+    fn from(DamageResistanceContext { raging }: DamageResistanceContext) -> Self {
+        let mut damage_resistances = Vec::new();
+
+        if raging {
+            damage_resistances.extend([
+                DamageType::Bludgeoning,
+                DamageType::Piercing,
+                DamageType::Slashing,
+            ]);
+        }
+
+        Self(damage_resistances)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rage_halves_physical_damage() {
+        let damage_resistances = DamageResistances::from(DamageResistanceContext { raging: true });
+
+        assert_eq!(damage_resistances.apply(&DamageType::Slashing, 9), 4);
+        assert_eq!(damage_resistances.apply(&DamageType::Fire, 9), 9);
+    }
+}
@@ -1,5 +1,6 @@
 use super::{attack_roll::AttackRoll, critical_state::CriticalState};
 use crate::{ability_modifier::AbilityModifier, roll::Roll};
+use rs5e_dice::Dice;
 
 /// This type is meant to remain in context of the roll itself, and factors that
 /// affect the value of the roll. Other concepts like the actual damage should
@@ -10,6 +11,24 @@ pub struct DamageRoll {
     pub(crate) ability_modifier: AbilityModifier,
     // pub(crate) damage_type: DamageType,
     pub(crate) damage_roll_type: DamageRollType,
+    /// Flat bonuses from features like the Dueling fighting style
+    pub(crate) damage_bonus: i32,
+}
+
+/// Everything beyond the attack roll itself that shapes a damage roll
+#[derive(Debug, Clone)]
+pub struct DamageRollContext {
+    /// May differ from the attack roll, e.g. a critical hit against
+    /// adamantine armor
+    pub critical_state: CriticalState,
+    /// Damage dice that land at or below this value are rerolled once
+    pub reroll_at_or_below: Option<u32>,
+    pub damage_bonus: i32,
+    /// May differ from the attack roll's, e.g. an off-hand attack
+    pub ability_modifier: AbilityModifier,
+    /// Replaces the weapon's or unarmed strike's damage dice, e.g. a monk's
+    /// martial arts die
+    pub damage_dice: Option<Dice>,
 }
 
 #[derive(Debug)]
@@ -36,9 +55,21 @@ impl DamageRoll {
             roll_value,
             ability_modifier,
             damage_roll_type,
+            damage_bonus: 0,
         }
     }
 
+    #[must_use]
+    pub const fn with_damage_bonus(mut self, damage_bonus: i32) -> Self {
+        self.damage_bonus = damage_bonus;
+        self
+    }
+
+    #[must_use]
+    pub const fn damage_bonus(&self) -> i32 {
+        self.damage_bonus
+    }
+
     #[must_use]
     pub const fn is_critical(&self) -> bool {
         self.damage_roll_type.is_critical()
@@ -55,28 +86,54 @@ impl DamageRoll {
     }
 
     pub fn from_attack_roll(attack_roll: &AttackRoll, damage_roll: &impl Fn() -> u32) -> Self {
+        Self::from_critical_state(
+            attack_roll.ability_modifier().clone(),
+            attack_roll.critical_state(),
+            damage_roll,
+        )
+    }
+
+    /// For when the critical state of the hit differs from the attack roll
+    /// itself, e.g. a critical hit against adamantine armor
+    pub fn from_critical_state(
+        ability_modifier: AbilityModifier,
+        critical_state: CriticalState,
+        damage_roll: &impl Fn() -> u32,
+    ) -> Self {
         Self {
             roll_value: damage_roll(),
-            ability_modifier: attack_roll.ability_modifier().clone(),
+            ability_modifier,
             // damage_type,
-            damage_roll_type: match attack_roll.critical_state() {
+            damage_roll_type: match critical_state {
                 CriticalState::Critical => DamageRollType::Critical {
                     bonus_roll_value: damage_roll(),
                 },
                 CriticalState::Normal | CriticalState::CriticalFail => DamageRollType::Normal,
             },
+            damage_bonus: 0,
         }
     }
 
     #[must_use]
     pub fn from_attack_roll_unarmed(attack_roll: &AttackRoll) -> Self {
+        Self::from_critical_state_unarmed(
+            attack_roll.ability_modifier().clone(),
+            attack_roll.critical_state(),
+        )
+    }
+
+    #[must_use]
+    pub const fn from_critical_state_unarmed(
+        ability_modifier: AbilityModifier,
+        critical_state: CriticalState,
+    ) -> Self {
         Self {
             // Rule for unarmed (default) is 1 damage + STR
             // Since stats are not yet implemented it's just 1 + 0
             roll_value: 1,
-            ability_modifier: attack_roll.ability_modifier().clone(),
+            ability_modifier,
             // damage_type: DamageType::Bludgeoning,
-            damage_roll_type: match attack_roll.critical_state() {
+            damage_roll_type: match critical_state {
                 CriticalState::Critical => DamageRollType::Critical {
                     // Rule for unarmed damage is 1 damage + STR
                     // Since stats are not yet implemented it's just 1 + 0
@@ -85,6 +142,7 @@ impl DamageRoll {
                 },
                 CriticalState::Normal | CriticalState::CriticalFail => DamageRollType::Normal,
             },
+            damage_bonus: 0,
         }
     }
 }
@@ -95,12 +153,12 @@ impl Roll for DamageRoll {
     }
 
     fn bonus_value(&self) -> i32 {
-        match self.damage_roll_type {
-            DamageRollType::Normal => self.ability_modifier().value(),
-            DamageRollType::Critical { bonus_roll_value } => {
-                bonus_roll_value as i32 + self.ability_modifier().value()
-            }
-        }
+        let bonus_roll_value = match self.damage_roll_type {
+            DamageRollType::Normal => 0,
+            DamageRollType::Critical { bonus_roll_value } => bonus_roll_value as i32,
+        };
+
+        bonus_roll_value + self.ability_modifier().value() + self.damage_bonus
     }
 }
 
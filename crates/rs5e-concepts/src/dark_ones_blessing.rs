@@ -0,0 +1,71 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#TheFiend>
+
+use crate::{
+    ability_scores::AbilityScores, ability_type::AbilityType, level::Level,
+    subclass_type::SubclassType,
+};
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct DarkOnesBlessingContext<'a> {
+    pub subclass: Option<&'a SubclassType>,
+    pub level: &'a Level,
+    pub ability_scores: &'a AbilityScores,
+}
+
+/// Temporary hit points gained on reducing a hostile creature to 0 hit
+/// points, the Charisma modifier plus the warlock level, minimum 1
+#[derive(Debug)]
+pub struct DarkOnesBlessing(Option<u32>);
+
+impl Deref for DarkOnesBlessing {
+    type Target = Option<u32>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<DarkOnesBlessingContext<'_>> for DarkOnesBlessing {
+    fn from(
+        DarkOnesBlessingContext {
+            subclass,
+            level,
+            ability_scores,
+        }: DarkOnesBlessingContext,
+    ) -> Self {
+        let charisma_modifier = ability_scores
+            .score(&AbilityType::Charisma)
+            .modifier()
+            .value();
+
+        Self((subclass == Some(&SubclassType::Fiend)).then(|| {
+            u32::try_from(charisma_modifier + i32::try_from(**level).unwrap_or(0))
+                .unwrap_or(0)
+                .max(1)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ability_scores::AbilityScore;
+
+    #[test]
+    fn fiend_warlocks_add_charisma_to_their_level() {
+        let mut ability_scores = AbilityScores::default();
+        *ability_scores.score_mut(&AbilityType::Charisma) = AbilityScore::try_from(16).unwrap();
+
+        let dark_ones_blessing = |subclass| {
+            *DarkOnesBlessing::from(DarkOnesBlessingContext {
+                subclass,
+                level: &Level::try_from(5).unwrap(),
+                ability_scores: &ability_scores,
+            })
+        };
+
+        assert_eq!(dark_ones_blessing(Some(&SubclassType::Fiend)), Some(8));
+        assert_eq!(dark_ones_blessing(None), None);
+    }
+}
@@ -0,0 +1,177 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#DivineSmite>
+
+use crate::{
+    class_type::ClassType, creature_type::CreatureType, critical_state::CriticalState,
+    level::Level, spell_slots::SpellSlots, weapon_range::WeaponRange,
+};
+use rs5e_dice::{Dice, Die, DieType};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+use typeshare::typeshare;
+
+/// A 4th-level slot already deals the maximum of 5d8
+const MAX_DIVINE_SMITE_DICE: u32 = 5;
+
+/// When a paladin spends a spell slot on a melee hit
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SmitePolicy {
+    /// Spend the highest slot left, only on critical hits
+    CriticalsOnly,
+    /// Spend the lowest slot left on every hit
+    Always,
+    Never,
+}
+
+impl SmitePolicy {
+    /// The slot level to spend on a hit, if any
+    #[must_use]
+    pub fn choose_slot(
+        &self,
+        critical_state: CriticalState,
+        spell_slots: &SpellSlots,
+    ) -> Option<u32> {
+        match self {
+            Self::CriticalsOnly if critical_state.is_critical() => spell_slots.highest_available(),
+            Self::Always => spell_slots.lowest_available(),
+            Self::CriticalsOnly | Self::Never => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DivineSmiteContext<'a> {
+    /// `None` for creatures without a class, such as monsters
+    pub class: Option<&'a ClassType>,
+    pub level: &'a Level,
+    pub smite_policy: Option<&'a SmitePolicy>,
+    /// `None` for unarmed strikes, which are melee weapon attacks
+    pub weapon_range: Option<&'a WeaponRange>,
+}
+
+/// The policy to smite with, `None` when the attack cannot smite at all
+#[derive(Debug)]
+pub struct DivineSmite(Option<SmitePolicy>);
+
+impl Deref for DivineSmite {
+    type Target = Option<SmitePolicy>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<DivineSmiteContext<'_>> for DivineSmite {
+    fn from(
+        DivineSmiteContext {
+            class,
+            level,
+            smite_policy,
+            weapon_range,
+        }: DivineSmiteContext,
+    ) -> Self {
+        Self(
+            smite_policy
+                .filter(|smite_policy| {
+                    class == Some(&ClassType::Paladin)
+                        && **level >= 2
+                        && **smite_policy != SmitePolicy::Never
+                        && weapon_range != Some(&WeaponRange::Ranged)
+                })
+                .copied(),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct DivineSmiteDiceContext<'a> {
+    pub slot_level: u32,
+    pub target_creature_type: &'a CreatureType,
+}
+
+/// 2d8 for a 1st-level slot and 1d8 for each level above, plus 1d8 against
+/// undead and fiends
+#[derive(Debug)]
+pub struct DivineSmiteDice(Dice);
+
+impl Deref for DivineSmiteDice {
+    type Target = Dice;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<DivineSmiteDiceContext<'_>> for DivineSmiteDice {
+    fn from(
+        DivineSmiteDiceContext {
+            slot_level,
+            target_creature_type,
+        }: DivineSmiteDiceContext,
+    ) -> Self {
+        let slot_dice = (slot_level + 1).min(MAX_DIVINE_SMITE_DICE);
+        let creature_dice = u32::from(matches!(
+            target_creature_type,
+            CreatureType::Undead | CreatureType::Fiend
+        ));
+
+        Self(Dice::new(slot_dice + creature_dice, Die::new(DieType::D8)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smite_dice_are_capped_before_the_undead_bonus() {
+        let smite_dice = |slot_level, target_creature_type| {
+            DivineSmiteDice::from(DivineSmiteDiceContext {
+                slot_level,
+                target_creature_type,
+            })
+            .max_value()
+        };
+
+        assert_eq!(smite_dice(1, &CreatureType::Humanoid), 16);
+        assert_eq!(smite_dice(5, &CreatureType::Humanoid), 40);
+        assert_eq!(smite_dice(5, &CreatureType::Undead), 48);
+    }
+
+    #[test]
+    fn smite_policy_chooses_a_slot() {
+        let spell_slots = SpellSlots::new([4, 2, 0, 0, 0, 0, 0, 0, 0]);
+
+        assert_eq!(
+            SmitePolicy::CriticalsOnly.choose_slot(CriticalState::Normal, &spell_slots),
+            None
+        );
+        assert_eq!(
+            SmitePolicy::CriticalsOnly.choose_slot(CriticalState::Critical, &spell_slots),
+            Some(2)
+        );
+        assert_eq!(
+            SmitePolicy::Always.choose_slot(CriticalState::Normal, &spell_slots),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn only_paladins_smite_in_melee() {
+        let level = Level::try_from(2).unwrap();
+        let divine_smite = |class, weapon_range| {
+            DivineSmite::from(DivineSmiteContext {
+                class: Some(class),
+                level: &level,
+                smite_policy: Some(&SmitePolicy::Always),
+                weapon_range,
+            })
+        };
+
+        assert!(divine_smite(&ClassType::Paladin, Some(&WeaponRange::Melee)).is_some());
+        assert!(divine_smite(&ClassType::Paladin, Some(&WeaponRange::Ranged)).is_none());
+        assert!(divine_smite(&ClassType::Fighter, Some(&WeaponRange::Melee)).is_none());
+    }
+}
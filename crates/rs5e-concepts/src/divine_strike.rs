@@ -0,0 +1,45 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#LifeDomain>
+
+use crate::{level::Level, subclass_type::SubclassType, weapon::WeaponModel};
+use rs5e_dice::{Dice, Die, DieType};
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct DivineStrikeContext<'a> {
+    pub subclass: Option<&'a SubclassType>,
+    pub level: &'a Level,
+    pub weapon: Option<&'a WeaponModel>,
+}
+
+/// Radiant damage dealt once per turn on a weapon hit
+#[derive(Debug)]
+pub struct DivineStrikeDice(Option<Dice>);
+
+impl Deref for DivineStrikeDice {
+    type Target = Option<Dice>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<DivineStrikeContext<'_>> for DivineStrikeDice {
+    fn from(
+        DivineStrikeContext {
+            subclass,
+            level,
+            weapon,
+        }: DivineStrikeContext,
+    ) -> Self {
+        let count = match **level {
+            14.. => 2,
+            8.. => 1,
+            _ => 0,
+        };
+
+        Self(
+            (subclass == Some(&SubclassType::Life) && count > 0 && weapon.is_some())
+                .then(|| Dice::new(count, Die::new(DieType::D8))),
+        )
+    }
+}
@@ -0,0 +1,222 @@
+//! Source: <https://www.dndbeyond.com/sources/dmg/creating-adventures#CreatingaCombatEncounter>
+
+use crate::level::{Level, MAX_LEVEL};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::Add;
+use typeshare::typeshare;
+
+/// Easy, medium, hard and deadly thresholds of a character of each level
+const XP_THRESHOLDS_BY_LEVEL: [[u32; 4]; MAX_LEVEL as usize] = [
+    [25, 50, 75, 100],
+    [50, 100, 150, 200],
+    [75, 150, 225, 400],
+    [125, 250, 375, 500],
+    [250, 500, 750, 1100],
+    [300, 600, 900, 1400],
+    [350, 750, 1100, 1700],
+    [450, 900, 1400, 2100],
+    [550, 1100, 1600, 2400],
+    [600, 1200, 1900, 2800],
+    [800, 1600, 2400, 3600],
+    [1000, 2000, 3000, 4500],
+    [1100, 2200, 3400, 5100],
+    [1250, 2500, 3800, 5700],
+    [1400, 2800, 4300, 6400],
+    [1600, 3200, 4800, 7200],
+    [2000, 3900, 5900, 8800],
+    [2100, 4200, 6300, 9500],
+    [2400, 4900, 7300, 10900],
+    [2800, 5700, 8500, 12700],
+];
+
+/// Encounter multipliers in halves, so ×1½ is 3. A party of fewer than
+/// three characters uses the next one up, a party of six or more the next
+/// one down, hence the ×½ and ×5 at either end
+const MULTIPLIER_HALVES: [u32; 8] = [1, 2, 3, 4, 5, 6, 8, 10];
+
+/// Parties of three to five characters use the multipliers as listed
+const SMALL_PARTY_SIZE: usize = 3;
+const LARGE_PARTY_SIZE: usize = 6;
+
+/// The adjusted XP each difficulty starts at, for one character or summed
+/// for the whole party
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct XpThresholds {
+    pub easy: u32,
+    pub medium: u32,
+    pub hard: u32,
+    pub deadly: u32,
+}
+
+impl From<&Level> for XpThresholds {
+    fn from(level: &Level) -> Self {
+        let [easy, medium, hard, deadly] = XP_THRESHOLDS_BY_LEVEL[**level as usize - 1];
+
+        Self {
+            easy,
+            medium,
+            hard,
+            deadly,
+        }
+    }
+}
+
+impl Add for XpThresholds {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            easy: self.easy.saturating_add(other.easy),
+            medium: self.medium.saturating_add(other.medium),
+            hard: self.hard.saturating_add(other.hard),
+            deadly: self.deadly.saturating_add(other.deadly),
+        }
+    }
+}
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EncounterDifficulty {
+    /// Below the easy threshold, hardly worth any resources
+    Trivial,
+    Easy,
+    Medium,
+    Hard,
+    Deadly,
+}
+
+#[derive(Debug)]
+pub struct EncounterContext<'a> {
+    pub party_levels: &'a [&'a Level],
+    /// The XP each monster is worth, one entry per monster
+    pub monster_xp: &'a [u32],
+}
+
+/// How hard a fight is for a party, worked out before simulating it
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct Encounter {
+    /// The thresholds of every character in the party summed
+    pub party_thresholds: XpThresholds,
+    /// The XP the party earns for defeating the monsters
+    pub total_xp: u32,
+    /// More monsters make a fight harder than their XP alone suggests
+    pub multiplier: f64,
+    /// Compared to the party's thresholds to find the difficulty
+    pub adjusted_xp: u32,
+    pub difficulty: EncounterDifficulty,
+}
+
+impl From<EncounterContext<'_>> for Encounter {
+    fn from(
+        EncounterContext {
+            party_levels,
+            monster_xp,
+        }: EncounterContext,
+    ) -> Self {
+        let party_thresholds = party_levels
+            .iter()
+            .map(|level| XpThresholds::from(*level))
+            .fold(XpThresholds::default(), Add::add);
+        // Thousands of the mightiest monsters are worth more XP than fits, and
+        // are deadly all the same
+        let total_xp = monster_xp
+            .iter()
+            .fold(0, |total_xp: u32, xp| total_xp.saturating_add(*xp));
+
+        let multiplier_index = match monster_xp.len() {
+            0 | 1 => 1,
+            2 => 2,
+            3..=6 => 3,
+            7..=10 => 4,
+            11..=14 => 5,
+            _ => 6,
+        };
+        let multiplier_index = match party_levels.len() {
+            size if size < SMALL_PARTY_SIZE => multiplier_index + 1,
+            size if size >= LARGE_PARTY_SIZE => multiplier_index - 1,
+            _ => multiplier_index,
+        };
+        let multiplier_halves = MULTIPLIER_HALVES[multiplier_index];
+        let adjusted_xp = u32::try_from(u64::from(total_xp) * u64::from(multiplier_halves) / 2)
+            .unwrap_or(u32::MAX);
+
+        let difficulty = match adjusted_xp {
+            xp if xp >= party_thresholds.deadly => EncounterDifficulty::Deadly,
+            xp if xp >= party_thresholds.hard => EncounterDifficulty::Hard,
+            xp if xp >= party_thresholds.medium => EncounterDifficulty::Medium,
+            xp if xp >= party_thresholds.easy => EncounterDifficulty::Easy,
+            _ => EncounterDifficulty::Trivial,
+        };
+
+        Self {
+            party_thresholds,
+            total_xp,
+            multiplier: f64::from(multiplier_halves) / 2.0,
+            adjusted_xp,
+            difficulty,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn more_monsters_raise_the_adjusted_xp() {
+        let level = |level| Level::try_from(level).unwrap();
+        let (level_1, level_3) = (level(1), level(3));
+
+        // Four 3rd-level characters against two monsters worth 200 XP each
+        let encounter = Encounter::from(EncounterContext {
+            party_levels: &[&level_3; 4],
+            monster_xp: &[200, 200],
+        });
+        assert_eq!(encounter.party_thresholds.medium, 600);
+        assert_eq!(encounter.party_thresholds.deadly, 1600);
+        assert_eq!(encounter.total_xp, 400);
+        assert!((encounter.multiplier - 1.5).abs() < f64::EPSILON);
+        assert_eq!(encounter.adjusted_xp, 600);
+        assert_eq!(encounter.difficulty, EncounterDifficulty::Medium);
+
+        // A small party uses the next multiplier up
+        let encounter = Encounter::from(EncounterContext {
+            party_levels: &[&level_1, &level_1],
+            monster_xp: &[50],
+        });
+        assert_eq!(encounter.adjusted_xp, 75);
+        assert_eq!(encounter.difficulty, EncounterDifficulty::Easy);
+
+        // A large party uses the next multiplier down
+        let encounter = Encounter::from(EncounterContext {
+            party_levels: &[&level_1; 6],
+            monster_xp: &[100],
+        });
+        assert_eq!(encounter.adjusted_xp, 50);
+        assert_eq!(encounter.difficulty, EncounterDifficulty::Trivial);
+
+        // Thousands of tarrasques multiply past what a u32 holds
+        let encounter = Encounter::from(EncounterContext {
+            party_levels: &[&level(20); 4],
+            monster_xp: &[155_000; 3_500],
+        });
+        assert_eq!(encounter.adjusted_xp, 2_170_000_000);
+        assert_eq!(encounter.difficulty, EncounterDifficulty::Deadly);
+
+        let encounter = Encounter::from(EncounterContext {
+            party_levels: &[&level(20); 4],
+            monster_xp: &vec![155_000; 30_000],
+        });
+        assert_eq!(encounter.total_xp, u32::MAX);
+        assert_eq!(encounter.adjusted_xp, u32::MAX);
+        assert_eq!(encounter.difficulty, EncounterDifficulty::Deadly);
+    }
+}
@@ -0,0 +1,32 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#Evasion>
+
+use crate::{class_type::ClassType, level::Level};
+use std::ops::Deref;
+
+pub const EVASION_LEVEL: u32 = 7;
+
+#[derive(Debug)]
+pub struct EvasionContext<'a> {
+    /// `None` for creatures without a class, such as monsters
+    pub class: Option<&'a ClassType>,
+    pub level: &'a Level,
+}
+
+/// Rogues and monks of 7th level take no damage from a successful Dexterity
+/// saving throw for half damage, and only half on a failed one
+#[derive(Debug)]
+pub struct Evasion(bool);
+
+impl Deref for Evasion {
+    type Target = bool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<EvasionContext<'_>> for Evasion {
+    fn from(EvasionContext { class, level }: EvasionContext) -> Self {
+        Self(matches!(class, Some(ClassType::Rogue | ClassType::Monk)) && **level >= EVASION_LEVEL)
+    }
+}
@@ -0,0 +1,70 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#ExtraAttack-219>
+
+use crate::{class_type::ClassType, level::Level};
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct ExtraAttackContext<'a> {
+    /// `None` for creatures without a class, such as monsters
+    pub class: Option<&'a ClassType>,
+    pub level: &'a Level,
+}
+
+/// Number of attacks made when taking the Attack action on a turn
+#[derive(Debug, PartialEq, Eq)]
+pub struct AttacksPerTurn(u32);
+
+impl Deref for AttacksPerTurn {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<ExtraAttackContext<'_>> for AttacksPerTurn {
+    fn from(ExtraAttackContext { class, level }: ExtraAttackContext) -> Self {
+        Self(match (class, **level) {
+            (Some(ClassType::Fighter), 20..) => 4,
+            (Some(ClassType::Fighter), 11..) => 3,
+            (
+                Some(
+                    ClassType::Barbarian
+                    | ClassType::Fighter
+                    | ClassType::Monk
+                    | ClassType::Paladin
+                    | ClassType::Ranger,
+                ),
+                5..,
+            ) => 2,
+            _ => 1,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attacks_per_turn(class: &ClassType, level: u32) -> u32 {
+        *AttacksPerTurn::from(ExtraAttackContext {
+            class: Some(class),
+            level: &Level::try_from(level).unwrap(),
+        })
+    }
+
+    #[test]
+    fn martial_classes_gain_extra_attack_at_level_5() {
+        assert_eq!(attacks_per_turn(&ClassType::Paladin, 4), 1);
+        assert_eq!(attacks_per_turn(&ClassType::Paladin, 5), 2);
+        assert_eq!(attacks_per_turn(&ClassType::Paladin, 20), 2);
+        assert_eq!(attacks_per_turn(&ClassType::Rogue, 20), 1);
+    }
+
+    #[test]
+    fn fighters_gain_more_attacks_at_levels_11_and_20() {
+        assert_eq!(attacks_per_turn(&ClassType::Fighter, 5), 2);
+        assert_eq!(attacks_per_turn(&ClassType::Fighter, 11), 3);
+        assert_eq!(attacks_per_turn(&ClassType::Fighter, 20), 4);
+    }
+}
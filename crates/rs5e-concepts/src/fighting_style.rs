@@ -0,0 +1,287 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#FightingStyle>
+
+use crate::{
+    advantage_type::{AdvantageType, Disadvantage},
+    armor_category::ArmorCategory,
+    armor_class::ArmorClass,
+    class_type::ClassType,
+    level::Level,
+    weapon_property::WeaponProperty,
+    weapon_range::WeaponRange,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+use typeshare::typeshare;
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FightingStyle {
+    /// +2 bonus to attack rolls you make with ranged weapons
+    Archery,
+    /// +1 bonus to AC while you are wearing armor
+    Defense,
+    /// +2 bonus to damage rolls when wielding a melee weapon in one hand
+    /// and no other weapons
+    Dueling,
+    /// Reroll a 1 or 2 on a damage die for an attack with a melee weapon
+    /// wielded with two hands
+    GreatWeaponFighting,
+    /// Use your reaction to impose disadvantage on an attack against an ally
+    /// within 5 feet of you, requires a shield
+    Protection,
+    /// Add your ability modifier to the damage of the off-hand attack
+    TwoWeaponFighting,
+}
+
+impl FightingStyle {
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Archery => "Archery",
+            Self::Defense => "Defense",
+            Self::Dueling => "Dueling",
+            Self::GreatWeaponFighting => "Great Weapon Fighting",
+            Self::Protection => "Protection",
+            Self::TwoWeaponFighting => "Two-Weapon Fighting",
+        }
+    }
+
+    /// Fighters choose a style at 1st level, paladins and rangers at 2nd
+    /// from a smaller list
+    #[must_use]
+    pub fn is_available_to(&self, class: &ClassType, level: &Level) -> bool {
+        match class {
+            ClassType::Fighter => true,
+            ClassType::Paladin => {
+                **level >= 2
+                    && matches!(
+                        self,
+                        Self::Defense
+                            | Self::Dueling
+                            | Self::GreatWeaponFighting
+                            | Self::Protection
+                    )
+            }
+            ClassType::Ranger => {
+                **level >= 2
+                    && matches!(
+                        self,
+                        Self::Archery | Self::Defense | Self::Dueling | Self::TwoWeaponFighting
+                    )
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AttackerFightingStyleContext<'a> {
+    pub fighting_style: Option<&'a FightingStyle>,
+    pub weapon_range: Option<&'a WeaponRange>,
+    pub weapon_properties: Option<&'a [WeaponProperty]>,
+    pub wielding_shield: bool,
+    pub wielding_off_hand_weapon: bool,
+}
+
+#[derive(Debug)]
+pub enum AttackerFightingStyleBonus {
+    AttackRoll(i32),
+    Damage(i32),
+    DamageDieReroll { at_or_below: u32 },
+    None,
+}
+
+impl AttackerFightingStyleBonus {
+    #[must_use]
+    pub const fn attack_roll_bonus(&self) -> i32 {
+        match self {
+            Self::AttackRoll(bonus) => *bonus,
+            _ => 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn damage_bonus(&self) -> i32 {
+        match self {
+            Self::Damage(bonus) => *bonus,
+            _ => 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn damage_die_reroll_at_or_below(&self) -> Option<u32> {
+        match self {
+            Self::DamageDieReroll { at_or_below } => Some(*at_or_below),
+            _ => None,
+        }
+    }
+}
+
+impl From<AttackerFightingStyleContext<'_>> for AttackerFightingStyleBonus {
+    fn from(
+        AttackerFightingStyleContext {
+            fighting_style,
+            weapon_range,
+            weapon_properties,
+            wielding_shield,
+            wielding_off_hand_weapon,
+        }: AttackerFightingStyleContext,
+    ) -> Self {
+        let has_property = |property: &WeaponProperty| {
+            weapon_properties.is_some_and(|properties| properties.contains(property))
+        };
+
+        match (fighting_style, weapon_range) {
+            (Some(FightingStyle::Archery), Some(WeaponRange::Ranged)) => Self::AttackRoll(2),
+            (Some(FightingStyle::Dueling), Some(WeaponRange::Melee))
+                if !has_property(&WeaponProperty::TwoHanded) && !wielding_off_hand_weapon =>
+            {
+                Self::Damage(2)
+            }
+            // A versatile weapon can only be wielded with two hands without a shield
+            (Some(FightingStyle::GreatWeaponFighting), Some(WeaponRange::Melee))
+                if has_property(&WeaponProperty::TwoHanded)
+                    || (has_property(&WeaponProperty::Versatile)
+                        && !wielding_shield
+                        && !wielding_off_hand_weapon) =>
+            {
+                Self::DamageDieReroll { at_or_below: 2 }
+            }
+            _ => Self::None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DefenderFightingStyleContext<'a> {
+    pub fighting_style: Option<&'a FightingStyle>,
+    pub armor_category: Option<&'a ArmorCategory>,
+}
+
+#[derive(Debug)]
+pub struct DefenderFightingStyleBonus(Option<ArmorClass>);
+
+impl Deref for DefenderFightingStyleBonus {
+    type Target = Option<ArmorClass>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<DefenderFightingStyleContext<'_>> for DefenderFightingStyleBonus {
+    fn from(
+        DefenderFightingStyleContext {
+            fighting_style,
+            armor_category,
+        }: DefenderFightingStyleContext,
+    ) -> Self {
+        // A shield on its own is not armor for the purposes of Defense
+        let wearing_armor = armor_category.is_some_and(|cat| cat != &ArmorCategory::Shield);
+
+        Self(
+            (fighting_style == Some(&FightingStyle::Defense) && wearing_armor)
+                .then(|| ArmorClass::new(1)),
+        )
+    }
+}
+
+/// An ally within 5 feet of the target who may react to the attack
+#[derive(Debug)]
+pub struct ProtectionContext<'a> {
+    pub ally_fighting_style: Option<&'a FightingStyle>,
+    pub ally_wielding_shield: bool,
+}
+
+#[derive(Debug)]
+pub struct ProtectionPenalty(Option<Disadvantage>);
+
+impl Deref for ProtectionPenalty {
+    type Target = Option<Disadvantage>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Option<ProtectionContext<'_>>> for ProtectionPenalty {
+    fn from(context: Option<ProtectionContext<'_>>) -> Self {
+        Self(
+            context
+                .is_some_and(|ally| {
+                    ally.ally_fighting_style == Some(&FightingStyle::Protection)
+                        && ally.ally_wielding_shield
+                })
+                .then_some(Disadvantage),
+        )
+    }
+}
+
+impl From<&ProtectionPenalty> for AdvantageType {
+    fn from(value: &ProtectionPenalty) -> Self {
+        value.deref().as_ref().map_or(Self::Normal, |disadvantage| {
+            Self::Disadvantage(disadvantage.clone())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paladins_cannot_take_archery() {
+        let level = Level::try_from(2).unwrap();
+
+        assert!(FightingStyle::Archery.is_available_to(&ClassType::Fighter, &level));
+        assert!(!FightingStyle::Archery.is_available_to(&ClassType::Paladin, &level));
+        assert!(!FightingStyle::Dueling.is_available_to(&ClassType::Paladin, &Level::default()));
+        assert!(!FightingStyle::Dueling.is_available_to(&ClassType::Rogue, &level));
+    }
+
+    #[test]
+    fn dueling_requires_one_handed_melee_weapon() {
+        let one_handed = AttackerFightingStyleBonus::from(AttackerFightingStyleContext {
+            fighting_style: Some(&FightingStyle::Dueling),
+            weapon_range: Some(&WeaponRange::Melee),
+            weapon_properties: Some(&[WeaponProperty::Versatile]),
+            wielding_shield: true,
+            wielding_off_hand_weapon: false,
+        });
+        let two_handed = AttackerFightingStyleBonus::from(AttackerFightingStyleContext {
+            fighting_style: Some(&FightingStyle::Dueling),
+            weapon_range: Some(&WeaponRange::Melee),
+            weapon_properties: Some(&[WeaponProperty::Heavy, WeaponProperty::TwoHanded]),
+            wielding_shield: false,
+            wielding_off_hand_weapon: false,
+        });
+        let dual_wielding = AttackerFightingStyleBonus::from(AttackerFightingStyleContext {
+            fighting_style: Some(&FightingStyle::Dueling),
+            weapon_range: Some(&WeaponRange::Melee),
+            weapon_properties: Some(&[WeaponProperty::Finesse, WeaponProperty::Light]),
+            wielding_shield: false,
+            wielding_off_hand_weapon: true,
+        });
+
+        assert_eq!(one_handed.damage_bonus(), 2);
+        assert_eq!(two_handed.damage_bonus(), 0);
+        assert_eq!(dual_wielding.damage_bonus(), 0);
+    }
+
+    #[test]
+    fn defense_requires_armor() {
+        let shield_only = DefenderFightingStyleBonus::from(DefenderFightingStyleContext {
+            fighting_style: Some(&FightingStyle::Defense),
+            armor_category: Some(&ArmorCategory::Shield),
+        });
+        let armored = DefenderFightingStyleBonus::from(DefenderFightingStyleContext {
+            fighting_style: Some(&FightingStyle::Defense),
+            armor_category: Some(&ArmorCategory::Heavy),
+        });
+
+        assert!(shield_only.is_none());
+        assert_eq!(*armored, Some(ArmorClass::new(1)));
+    }
+}
@@ -0,0 +1,103 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/using-ability-scores#Hiding>
+
+use crate::{
+    advantage_type::{Advantage, AdvantageType},
+    special_ability::SpecialAbility,
+};
+use std::ops::Deref;
+
+/// Advantage on a check adds 5 to the matching passive score
+pub const KEEN_SENSES_PASSIVE_BONUS: u32 = 5;
+
+#[derive(Debug)]
+pub struct HideContext<'a> {
+    /// The hider's Dexterity (Stealth) check
+    pub stealth_total: i32,
+    pub passive_perception: u32,
+    pub observer_special_abilities: &'a [SpecialAbility],
+}
+
+/// The stealth check is contested by the observer's passive Wisdom
+/// (Perception), which Keen Senses raises
+#[derive(Debug)]
+pub struct Hidden {
+    pub hidden: bool,
+    pub observer_passive_perception: u32,
+}
+
+impl From<HideContext<'_>> for Hidden {
+    fn from(
+        HideContext {
+            stealth_total,
+            passive_perception,
+            observer_special_abilities,
+        }: HideContext,
+    ) -> Self {
+        let observer_passive_perception = passive_perception
+            + if observer_special_abilities.contains(&SpecialAbility::KeenSenses) {
+                KEEN_SENSES_PASSIVE_BONUS
+            } else {
+                0
+            };
+
+        Self {
+            hidden: stealth_total >= i32::try_from(observer_passive_perception).unwrap_or(0),
+            observer_passive_perception,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UnseenAttackerContext {
+    pub hidden: bool,
+}
+
+/// Advantage on the first attack made while hidden, which also reveals the
+/// attacker
+#[derive(Debug)]
+pub struct UnseenAttackerBonus(Option<Advantage>);
+
+impl Deref for UnseenAttackerBonus {
+    type Target = Option<Advantage>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<UnseenAttackerContext> for UnseenAttackerBonus {
+    fn from(UnseenAttackerContext { hidden }: UnseenAttackerContext) -> Self {
+        Self(hidden.then_some(Advantage))
+    }
+}
+
+impl From<&UnseenAttackerBonus> for AdvantageType {
+    fn from(value: &UnseenAttackerBonus) -> Self {
+        value
+            .deref()
+            .as_ref()
+            .map_or(Self::Normal, |advantage| Self::Advantage(advantage.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keen_senses_raise_the_passive_perception_to_beat() {
+        let hidden = |observer_special_abilities| {
+            Hidden::from(HideContext {
+                stealth_total: 14,
+                passive_perception: 12,
+                observer_special_abilities,
+            })
+        };
+
+        assert!(hidden(&[]).hidden);
+
+        let keen = hidden(&[SpecialAbility::KeenSenses]);
+        assert!(!keen.hidden);
+        assert_eq!(keen.observer_passive_perception, 17);
+    }
+}
@@ -1,11 +1,20 @@
-use crate::{damage_roll::DamageRoll, damage_type::DamageType, hp_change::HpChange};
+use crate::{
+    bonus_damage::BonusDamageRoll, damage_roll::DamageRoll, damage_type::DamageType,
+    hp_change::HpChange,
+};
 
 #[derive(Debug)]
 pub enum Hit {
     Success {
         damage_roll: DamageRoll,
+        bonus_damage_rolls: Vec<BonusDamageRoll>,
+        /// After resistances, may be less than the damage roll
+        damage_dealt: u32,
         hp_change: HpChange,
         damage_type: DamageType,
+        /// Gained by the attacker for dropping the defender, e.g. from
+        /// Dark One's Blessing
+        temporary_hp_gained: Option<u32>,
     },
     Miss,
 }
@@ -16,4 +25,33 @@ impl Hit {
     pub const fn is_miss(&self) -> bool {
         matches!(self, Self::Miss)
     }
+
+    /// Zero on a miss
+    #[inline]
+    #[must_use]
+    pub const fn damage_dealt(&self) -> u32 {
+        match self {
+            Self::Success { damage_dealt, .. } => *damage_dealt,
+            Self::Miss => 0,
+        }
+    }
+
+    /// Every type of damage dealt, empty on a miss
+    #[must_use]
+    pub fn damage_types(&self) -> Vec<DamageType> {
+        match self {
+            Self::Success {
+                damage_type,
+                bonus_damage_rolls,
+                ..
+            } => std::iter::once(*damage_type)
+                .chain(
+                    bonus_damage_rolls
+                        .iter()
+                        .map(|bonus_damage_roll| bonus_damage_roll.damage_type),
+                )
+                .collect(),
+            Self::Miss => Vec::new(),
+        }
+    }
 }
@@ -2,6 +2,8 @@
 pub struct Hp {
     pub max: u32,
     pub current: u32,
+    /// Lost first when taking damage, e.g. from Dark One's Blessing
+    pub temporary: u32,
 }
 
 impl Hp {
@@ -10,6 +12,7 @@ impl Hp {
         Self {
             current: value,
             max: value,
+            temporary: 0,
         }
     }
 }
@@ -5,4 +5,6 @@ pub struct HpChange {
     pub max: u32,
     pub before: u32,
     pub after: u32,
+    /// Damage taken by temporary hit points before reaching `before`
+    pub temporary_absorbed: u32,
 }
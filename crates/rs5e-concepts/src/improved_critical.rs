@@ -0,0 +1,55 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#Champion>
+
+use crate::{level::Level, subclass_type::SubclassType};
+use std::ops::Deref;
+
+/// Only a natural 20 is a critical hit without Improved Critical
+pub const DEFAULT_CRITICAL_RANGE: u32 = 20;
+
+#[derive(Debug)]
+pub struct ImprovedCriticalContext<'a> {
+    pub subclass: Option<&'a SubclassType>,
+    pub level: &'a Level,
+}
+
+/// The lowest d20 roll that scores a critical hit
+#[derive(Debug)]
+pub struct CriticalRange(u32);
+
+impl Deref for CriticalRange {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<ImprovedCriticalContext<'_>> for CriticalRange {
+    fn from(ImprovedCriticalContext { subclass, level }: ImprovedCriticalContext) -> Self {
+        Self(match (subclass, **level) {
+            // Superior Critical
+            (Some(SubclassType::Champion), 15..) => 18,
+            (Some(SubclassType::Champion), 3..) => 19,
+            _ => DEFAULT_CRITICAL_RANGE,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn champions_widen_their_critical_range() {
+        let critical_range = |subclass, level: u32| {
+            *CriticalRange::from(ImprovedCriticalContext {
+                subclass,
+                level: &Level::try_from(level).unwrap(),
+            })
+        };
+
+        assert_eq!(critical_range(None, 15), 20);
+        assert_eq!(critical_range(Some(&SubclassType::Champion), 3), 19);
+        assert_eq!(critical_range(Some(&SubclassType::Champion), 15), 18);
+    }
+}
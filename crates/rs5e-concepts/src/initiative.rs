@@ -0,0 +1,141 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/combat#Initiative>
+
+use crate::{
+    ability_modifier::AbilityModifier,
+    advantage_type::{Advantage, AdvantageType},
+    class_type::ClassType,
+    level::Level,
+    roll::Roll,
+};
+use std::{cmp::Reverse, ops::Deref};
+
+pub const FERAL_INSTINCT_LEVEL: u32 = 7;
+
+#[derive(Debug)]
+pub struct FeralInstinctContext<'a> {
+    /// `None` for creatures without a class, such as monsters
+    pub class: Option<&'a ClassType>,
+    pub level: &'a Level,
+}
+
+/// Barbarians of 7th level have advantage on initiative rolls
+#[derive(Debug)]
+pub struct FeralInstinctBonus(Option<Advantage>);
+
+impl Deref for FeralInstinctBonus {
+    type Target = Option<Advantage>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<FeralInstinctContext<'_>> for FeralInstinctBonus {
+    fn from(FeralInstinctContext { class, level }: FeralInstinctContext) -> Self {
+        Self(
+            (class == Some(&ClassType::Barbarian) && **level >= FERAL_INSTINCT_LEVEL)
+                .then_some(Advantage),
+        )
+    }
+}
+
+impl From<&FeralInstinctBonus> for AdvantageType {
+    fn from(value: &FeralInstinctBonus) -> Self {
+        value
+            .deref()
+            .as_ref()
+            .map_or(Self::Normal, |advantage| Self::Advantage(advantage.clone()))
+    }
+}
+
+/// A Dexterity check rolled once at the start of combat, deciding the turn
+/// order for the whole encounter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Initiative {
+    pub raw_value: u32,
+    /// The lower roll when rolled with advantage, e.g. Feral Instinct
+    pub discarded_raw_value: Option<u32>,
+    pub dexterity_modifier: AbilityModifier,
+    /// Breaks ties between equal totals, the higher score going first
+    pub dexterity_score: u32,
+    /// Rolled off only when both the totals and the Dexterity scores tie
+    pub tiebreaker: Option<u32>,
+}
+
+impl Roll for Initiative {
+    fn raw_value(&self) -> u32 {
+        self.raw_value
+    }
+
+    fn bonus_value(&self) -> i32 {
+        self.dexterity_modifier.value()
+    }
+}
+
+impl Initiative {
+    /// Keeps the higher of the two rolls
+    #[must_use]
+    pub const fn with_advantage(mut self, reroll: u32) -> Self {
+        let (kept, discarded) = if reroll > self.raw_value {
+            (reroll, self.raw_value)
+        } else {
+            (self.raw_value, reroll)
+        };
+        self.raw_value = kept;
+        self.discarded_raw_value = Some(discarded);
+        self
+    }
+
+    /// Sorting by this key puts the first to act first
+    #[must_use]
+    pub fn turn_order_key(&self) -> Reverse<(u32, u32, u32)> {
+        Reverse((
+            self.total_value(),
+            self.dexterity_score,
+            self.tiebreaker.unwrap_or(0),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ties_are_broken_by_dexterity_then_a_roll() {
+        let initiative = |raw_value, dexterity_score, modifier| Initiative {
+            raw_value,
+            discarded_raw_value: None,
+            dexterity_modifier: AbilityModifier::new(modifier),
+            dexterity_score,
+            tiebreaker: None,
+        };
+
+        let quick = initiative(12, 16, 3);
+        let steady = initiative(14, 12, 1);
+        let slow = initiative(10, 10, 0);
+        assert_eq!(quick.total_value(), steady.total_value());
+        assert!(quick.turn_order_key() < steady.turn_order_key());
+        assert!(steady.turn_order_key() < slow.turn_order_key());
+
+        let mut rolled_off = quick.clone();
+        rolled_off.tiebreaker = Some(7);
+        assert!(rolled_off.turn_order_key() < quick.turn_order_key());
+
+        let advantage = slow.with_advantage(18);
+        assert_eq!(advantage.raw_value, 18);
+        assert_eq!(advantage.discarded_raw_value, Some(10));
+
+        let level = Level::try_from(FERAL_INSTINCT_LEVEL).unwrap();
+        assert!(FeralInstinctBonus::from(FeralInstinctContext {
+            class: Some(&ClassType::Barbarian),
+            level: &level,
+        })
+        .is_some());
+        assert!(FeralInstinctBonus::from(FeralInstinctContext {
+            class: Some(&ClassType::Fighter),
+            level: &level,
+        })
+        .is_none());
+    }
+}
@@ -0,0 +1,74 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#Ki>
+
+use crate::level::Level;
+
+pub const FLURRY_OF_BLOWS_KI_COST: u32 = 1;
+
+#[derive(Debug)]
+pub struct NoKiRemaining;
+
+/// Tracks a monk's ki points over the course of a combat
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ki {
+    points_remaining: u32,
+}
+
+impl Ki {
+    /// Monks gain one ki point per level from 2nd level
+    #[must_use]
+    pub fn new(level: &Level) -> Self {
+        Self {
+            points_remaining: if **level >= 2 { **level } else { 0 },
+        }
+    }
+
+    #[must_use]
+    pub const fn points_remaining(&self) -> u32 {
+        self.points_remaining
+    }
+
+    /// # Errors
+    ///
+    /// When fewer points than the cost are left
+    pub const fn spend(&mut self, cost: u32) -> Result<(), NoKiRemaining> {
+        if self.points_remaining < cost {
+            return Err(NoKiRemaining);
+        }
+
+        self.points_remaining -= cost;
+        Ok(())
+    }
+}
+
+/// The bonus action a monk takes after attacking with an unarmed strike or
+/// a monk weapon
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MartialArtsBonusAction {
+    /// Spends 1 ki point for two unarmed strikes
+    FlurryOfBlows,
+    UnarmedStrike,
+}
+
+impl MartialArtsBonusAction {
+    #[must_use]
+    pub const fn strikes(&self) -> u32 {
+        match self {
+            Self::FlurryOfBlows => 2,
+            Self::UnarmedStrike => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ki_runs_out() {
+        let mut ki = Ki::new(&Level::try_from(2).unwrap());
+
+        assert!(ki.spend(FLURRY_OF_BLOWS_KI_COST).is_ok());
+        assert!(ki.spend(FLURRY_OF_BLOWS_KI_COST).is_ok());
+        assert!(ki.spend(FLURRY_OF_BLOWS_KI_COST).is_err());
+    }
+}
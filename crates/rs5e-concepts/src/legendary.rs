@@ -0,0 +1,173 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/monsters#LegendaryCreatures>
+
+use crate::monster_action::{MonsterAttack, MonsterSaveAction};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+/// Legendary creatures can take 3 legendary actions each round
+pub const LEGENDARY_ACTIONS_PER_ROUND: u32 = 3;
+
+/// Names the use tracker of Legendary Resistance
+pub const LEGENDARY_RESISTANCE: &str = "Legendary Resistance";
+
+/// What a legendary action does
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase", tag = "t", content = "c")]
+pub enum LegendaryActionEffect {
+    /// Makes one of the monster's attacks, e.g. a dragon's tail attack
+    Attack(MonsterAttack),
+    /// Forces a saving throw, e.g. a dragon's wing attack
+    SaveAction(MonsterSaveAction),
+    /// Moving, making a check or anything else without an effect in combat
+    /// here
+    NotImplemented,
+}
+
+/// Taken at the end of another creature's turn
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LegendaryAction {
+    pub name: String,
+    /// Most cost 1, others are listed as e.g. "Costs 2 Actions"
+    pub cost: u32,
+    pub effect: LegendaryActionEffect,
+}
+
+impl LegendaryAction {
+    #[must_use]
+    pub const fn is_implemented(&self) -> bool {
+        !matches!(self.effect, LegendaryActionEffect::NotImplemented)
+    }
+}
+
+#[derive(Debug)]
+pub struct NoLegendaryActionsRemaining;
+
+/// Tracks the legendary actions left until the start of the monster's
+/// next turn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegendaryActionPoints {
+    points_remaining: u32,
+}
+
+impl Default for LegendaryActionPoints {
+    fn default() -> Self {
+        Self {
+            points_remaining: LEGENDARY_ACTIONS_PER_ROUND,
+        }
+    }
+}
+
+impl LegendaryActionPoints {
+    #[must_use]
+    pub const fn points_remaining(&self) -> u32 {
+        self.points_remaining
+    }
+
+    /// The most expensive action the points left cover, the first listed
+    /// on a tie
+    #[must_use]
+    pub fn choose<'a>(
+        &self,
+        legendary_actions: &'a [LegendaryAction],
+    ) -> Option<&'a LegendaryAction> {
+        legendary_actions
+            .iter()
+            .filter(|legendary_action| {
+                legendary_action.is_implemented() && legendary_action.cost <= self.points_remaining
+            })
+            .rev()
+            .max_by_key(|legendary_action| legendary_action.cost)
+    }
+
+    /// # Errors
+    ///
+    /// When fewer points than the cost are left
+    pub const fn spend(&mut self, cost: u32) -> Result<(), NoLegendaryActionsRemaining> {
+        if self.points_remaining < cost {
+            return Err(NoLegendaryActionsRemaining);
+        }
+
+        self.points_remaining -= cost;
+        Ok(())
+    }
+
+    /// Spent points are regained at the start of the monster's turn
+    pub const fn refresh(&mut self) {
+        self.points_remaining = LEGENDARY_ACTIONS_PER_ROUND;
+    }
+}
+
+/// When a monster with Legendary Resistance turns a failed saving throw
+/// into a success
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum LegendaryResistancePolicy {
+    /// On every failed saving throw while uses are left
+    #[default]
+    Always,
+    /// Only when failing would impose a condition, e.g. paralysis, keeping
+    /// the uses for the worst effects
+    AgainstConditions,
+    Never,
+}
+
+impl LegendaryResistancePolicy {
+    /// Whether a failed saving throw is turned into a success
+    #[must_use]
+    pub const fn resists(&self, imposes_condition: bool) -> bool {
+        match self {
+            Self::Always => true,
+            Self::AgainstConditions => imposes_condition,
+            Self::Never => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weapon_range::WeaponRange;
+
+    #[test]
+    fn one_action_is_taken_at_the_end_of_each_turn() {
+        let legendary_action = |name: &str, cost, effect| LegendaryAction {
+            name: name.to_string(),
+            cost,
+            effect,
+        };
+        let attack = LegendaryActionEffect::Attack(MonsterAttack {
+            name: "Tail".to_string(),
+            attack_range: WeaponRange::Melee,
+            attack_bonus: 14,
+            damage: Vec::new(),
+        });
+        let legendary_actions = [
+            legendary_action("Detect", 1, LegendaryActionEffect::NotImplemented),
+            legendary_action("Tail Attack", 1, attack.clone()),
+            legendary_action("Bite", 2, attack),
+            legendary_action("Disrupt Life", 3, LegendaryActionEffect::NotImplemented),
+        ];
+        let mut points = LegendaryActionPoints::default();
+        let end_of_turn = |points: &mut LegendaryActionPoints| {
+            let chosen = points.choose(&legendary_actions)?;
+            points.spend(chosen.cost).ok()?;
+            Some(chosen.name.as_str())
+        };
+
+        // The rest of the points wait for the end of the next creature's turn
+        assert_eq!(end_of_turn(&mut points), Some("Bite"));
+        assert_eq!(points.points_remaining(), 1);
+        assert_eq!(end_of_turn(&mut points), Some("Tail Attack"));
+        assert_eq!(end_of_turn(&mut points), None);
+
+        points.refresh();
+        assert_eq!(points.points_remaining(), LEGENDARY_ACTIONS_PER_ROUND);
+    }
+}
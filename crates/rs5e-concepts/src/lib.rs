@@ -7,35 +7,78 @@ pub mod ability_modifier;
 pub mod ability_scores;
 pub mod ability_type;
 pub mod advantage_type;
+pub mod area_of_effect;
 pub mod armor;
 pub mod armor_category;
 pub mod armor_class;
 pub mod armor_proficiency_penalty;
 pub mod armor_type;
 pub mod attack_roll;
+pub mod bonus_damage;
+pub mod challenge_rating;
 pub mod class_type;
+pub mod colossus_slayer;
+pub mod concentration;
+pub mod condition;
 pub mod cover_state;
+pub mod creature_type;
 pub mod critical_state;
+pub mod damage_resistance;
 pub mod damage_roll;
 pub mod damage_source;
 pub mod damage_type;
+pub mod dark_ones_blessing;
+pub mod divine_smite;
+pub mod divine_strike;
+pub mod encounter;
+pub mod evasion;
+pub mod extra_attack;
+pub mod fighting_style;
 pub mod hit;
+pub mod hide;
 pub mod hit_dice;
 pub mod hp;
 pub mod hp_change;
 pub mod id;
+pub mod improved_critical;
+pub mod initiative;
+pub mod ki;
+pub mod legendary;
 pub mod level;
+pub mod martial_arts;
 pub mod material;
+pub mod monster;
+pub mod monster_action;
 pub mod physical_attack_type;
+pub mod position;
 pub mod proficiency_bonus;
 pub mod proficiency_type;
 pub mod prone_state;
+pub mod rage;
+pub mod reckless_attack;
 pub mod roll;
 pub mod roll_type;
+pub mod sacred_weapon;
+pub mod saving_throw;
+pub mod senses;
+pub mod sneak_attack;
+pub mod special_ability;
+pub mod spell;
+pub mod spell_attack;
+pub mod spell_save;
+pub mod spell_selection;
+pub mod spell_slots;
+pub mod spellcasting;
+pub mod subclass_type;
+pub mod tool_type;
+pub mod two_weapon_fighting;
+pub mod unarmored_defense;
+pub mod usage;
 pub mod util;
 pub mod weapon;
 pub mod weapon_ability_modifier;
 pub mod weapon_category;
 pub mod weapon_proficiency_bonus;
+pub mod weapon_property;
 pub mod weapon_range;
 pub mod weapon_type;
@@ -0,0 +1,97 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#MartialArts>
+
+use crate::{
+    class_type::ClassType, level::Level, weapon::WeaponModel, weapon_property::WeaponProperty,
+};
+use rs5e_dice::{Dice, Die, DieType};
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct MartialArtsContext<'a> {
+    /// `None` for creatures without a class, such as monsters
+    pub class: Option<&'a ClassType>,
+    pub level: &'a Level,
+    /// `None` for unarmed strikes
+    pub weapon: Option<&'a WeaponModel>,
+}
+
+#[derive(Debug)]
+pub struct MartialArtsBonus {
+    pub martial_arts_dice: Dice,
+    /// The martial arts die replaces the weapon's damage dice when it is
+    /// larger, and always replaces the damage of an unarmed strike
+    pub damage_dice: Option<Dice>,
+}
+
+/// Applies to unarmed strikes and monk weapons, which may also use DEX
+/// instead of STR. `None` otherwise.
+#[derive(Debug)]
+pub struct MartialArts(Option<MartialArtsBonus>);
+
+impl Deref for MartialArts {
+    type Target = Option<MartialArtsBonus>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<MartialArtsContext<'_>> for MartialArts {
+    fn from(
+        MartialArtsContext {
+            class,
+            level,
+            weapon,
+        }: MartialArtsContext,
+    ) -> Self {
+        let monk_weapon_or_unarmed =
+            weapon.is_none_or(|weapon| weapon.properties.contains(&WeaponProperty::Monk));
+
+        if class != Some(&ClassType::Monk) || !monk_weapon_or_unarmed {
+            return Self(None);
+        }
+
+        let martial_arts_dice = Dice::new(
+            1,
+            Die::new(match **level {
+                1..=4 => DieType::D4,
+                5..=10 => DieType::D6,
+                11..=16 => DieType::D8,
+                _ => DieType::D10,
+            }),
+        );
+
+        Self(Some(MartialArtsBonus {
+            martial_arts_dice,
+            damage_dice: match weapon {
+                Some(weapon) if weapon.damage_dice.max_value() >= martial_arts_dice.max_value() => {
+                    None
+                }
+                _ => Some(martial_arts_dice),
+            },
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn martial_arts_die_scales_with_monk_level() {
+        let martial_arts = |class, level| {
+            MartialArts::from(MartialArtsContext {
+                class: Some(class),
+                level: &Level::try_from(level).unwrap(),
+                weapon: None,
+            })
+            .as_ref()
+            .map(|bonus| bonus.martial_arts_dice.max_value())
+        };
+
+        assert_eq!(martial_arts(&ClassType::Monk, 1), Some(4));
+        assert_eq!(martial_arts(&ClassType::Monk, 11), Some(8));
+        assert_eq!(martial_arts(&ClassType::Monk, 17), Some(10));
+        assert_eq!(martial_arts(&ClassType::Fighter, 17), None);
+    }
+}
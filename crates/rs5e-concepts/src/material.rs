@@ -3,10 +3,65 @@ use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
 #[typeshare]
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[serde(rename_all = "camelCase")]
 pub enum Material {
+    Cloth,
+    Leather,
+    Hide,
+    Wood,
     Metal,
-    Unknown,
+    /// Mithral armor doesn't impose disadvantage on Dexterity (Stealth) checks
+    Mithral,
+    /// Any critical hit against the wearer of adamantine armor becomes a normal hit
+    Adamantine,
+}
+
+impl Material {
+    #[must_use]
+    pub const fn is_metal(&self) -> bool {
+        matches!(self, Self::Metal | Self::Mithral | Self::Adamantine)
+    }
+
+    #[must_use]
+    pub const fn negates_critical_hits(&self) -> bool {
+        matches!(self, Self::Adamantine)
+    }
+
+    #[must_use]
+    pub const fn removes_stealth_disadvantage(&self) -> bool {
+        matches!(self, Self::Mithral)
+    }
+}
+
+#[typeshare]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[serde(rename_all = "camelCase")]
+pub enum MaterialRestriction {
+    /// Druids will not wear armor or use shields made of metal
+    Nonmetal,
+}
+
+impl MaterialRestriction {
+    #[must_use]
+    pub const fn allows(&self, material: &Material) -> bool {
+        match self {
+            Self::Nonmetal => !material.is_metal(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonmetal_restriction() {
+        assert!(MaterialRestriction::Nonmetal.allows(&Material::Hide));
+        assert!(MaterialRestriction::Nonmetal.allows(&Material::Wood));
+        assert!(!MaterialRestriction::Nonmetal.allows(&Material::Metal));
+        assert!(!MaterialRestriction::Nonmetal.allows(&Material::Mithral));
+    }
 }
@@ -0,0 +1,90 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/monsters>
+
+use crate::{
+    ability_scores::AbilityScores,
+    armor_class::ArmorClass,
+    challenge_rating::ChallengeRating,
+    creature_type::CreatureType,
+    legendary::{LegendaryAction, LEGENDARY_RESISTANCE},
+    monster_action::{MonsterAttack, MonsterSaveAction, Multiattack},
+    proficiency_type::ProficiencyType,
+    senses::Senses,
+    special_ability::SpecialAbility,
+    usage::{LimitedUse, Usage},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use typeshare::typeshare;
+
+/// A monster's stat block
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MonsterModel {
+    /// The SRD index, e.g. "goblin"
+    pub index: String,
+    pub name: String,
+    pub creature_type: CreatureType,
+    /// Already includes any armor or shield the monster is described with
+    pub armor_class: ArmorClass,
+    /// The average of the monster's hit dice
+    pub hit_points: u32,
+    pub ability_scores: AbilityScores,
+    /// The saving throws the stat block lists
+    pub proficiencies: Vec<ProficiencyType>,
+    pub challenge_rating: ChallengeRating,
+    /// Earned for defeating the monster
+    pub xp: u32,
+    pub senses: Senses,
+    /// The Dexterity (Stealth) total when the stat block lists it
+    pub stealth: Option<i32>,
+    pub special_abilities: Vec<SpecialAbility>,
+    /// The actions that make an attack roll
+    pub attacks: Vec<MonsterAttack>,
+    pub multiattack: Option<Multiattack>,
+    /// The actions that force a saving throw and deal damage
+    pub save_actions: Vec<MonsterSaveAction>,
+    /// Taken at the end of other creatures' turns
+    pub legendary_actions: Vec<LegendaryAction>,
+}
+
+impl MonsterModel {
+    /// The attacks made with the monster's action, following Multiattack
+    /// when it has one and otherwise making its first attack
+    #[must_use]
+    pub fn attack_sequence(&self) -> Vec<&MonsterAttack> {
+        self.multiattack.as_ref().map_or_else(
+            || self.attacks.first().into_iter().collect(),
+            |multiattack| multiattack.attack_sequence(&self.attacks),
+        )
+    }
+
+    /// A fresh tracker for every action with limited usage and for
+    /// Legendary Resistance
+    #[must_use]
+    pub fn limited_uses(&self) -> Vec<LimitedUse> {
+        self.save_actions
+            .iter()
+            .filter_map(|save_action| {
+                save_action
+                    .usage
+                    .clone()
+                    .map(|usage| LimitedUse::new(save_action.name.clone(), usage))
+            })
+            .chain(self.special_abilities.iter().filter_map(
+                |special_ability| match special_ability {
+                    SpecialAbility::LegendaryResistance(times) => Some(LimitedUse::new(
+                        LEGENDARY_RESISTANCE.to_string(),
+                        Usage::PerDay(*times),
+                    )),
+                    _ => None,
+                },
+            ))
+            .collect()
+    }
+}
+
+/// Every monster in the SRD data by index
+pub type MonsterModelMap = HashMap<String, MonsterModel>;
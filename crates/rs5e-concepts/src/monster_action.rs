@@ -0,0 +1,127 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/monsters#Actions>
+
+use crate::{damage_type::DamageType, spell::SpellDc, usage::Usage, weapon_range::WeaponRange};
+use rs5e_dice::Dice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+/// Damage of one type dealt by an attack, e.g. 2d10 + 8 piercing. Flat
+/// damage such as a bat's 1 piercing is rolled as 1d1
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MonsterDamage {
+    pub damage_type: DamageType,
+    pub damage_dice: Dice,
+    pub damage_bonus: i32,
+}
+
+/// An attack from a stat block. The to-hit bonus already includes the
+/// monster's ability modifier and proficiency bonus
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MonsterAttack {
+    pub name: String,
+    pub attack_range: WeaponRange,
+    pub attack_bonus: i32,
+    /// The first damage is the attack's own, any others are dealt on top of
+    /// it, e.g. the fire damage of a red dragon's bite
+    pub damage: Vec<MonsterDamage>,
+}
+
+/// An action that forces a saving throw instead of making an attack roll,
+/// e.g. a dragon's breath weapon
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MonsterSaveAction {
+    pub name: String,
+    pub dc: SpellDc,
+    pub dc_value: u32,
+    /// Rolled once, before the saving throw
+    pub damage: Vec<MonsterDamage>,
+    /// `None` for actions the monster can take every turn
+    pub usage: Option<Usage>,
+}
+
+/// Attacks made as part of Multiattack, e.g. two with claws
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiattackStep {
+    /// Names one of the monster's attacks
+    pub attack_name: String,
+    pub count: u32,
+}
+
+/// Several attacks taken with one action. Where the stat block offers a
+/// choice, the first option is taken
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Multiattack {
+    pub steps: Vec<MultiattackStep>,
+}
+
+impl Multiattack {
+    /// Every attack in the order they are made, skipping steps that name
+    /// none of the attacks
+    #[must_use]
+    pub fn attack_sequence<'a>(&self, attacks: &'a [MonsterAttack]) -> Vec<&'a MonsterAttack> {
+        self.steps
+            .iter()
+            .filter_map(|step| {
+                attacks
+                    .iter()
+                    .find(|attack| attack.name == step.attack_name)
+                    .map(|attack| (attack, step.count))
+            })
+            .flat_map(|(attack, count)| std::iter::repeat_n(attack, count as usize))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn multiattack_repeats_each_attack_in_order() {
+        let attack = |name: &str| MonsterAttack {
+            name: name.to_string(),
+            attack_range: WeaponRange::Melee,
+            attack_bonus: 14,
+            damage: Vec::from([MonsterDamage {
+                damage_type: DamageType::Slashing,
+                damage_dice: Dice::from_str("2d6").unwrap(),
+                damage_bonus: 8,
+            }]),
+        };
+        let attacks = [attack("Bite"), attack("Claw"), attack("Tail")];
+        let step = |attack_name: &str, count| MultiattackStep {
+            attack_name: attack_name.to_string(),
+            count,
+        };
+
+        let multiattack = Multiattack {
+            steps: Vec::from([step("Bite", 1), step("Claw", 2), step("Wing Attack", 1)]),
+        };
+
+        assert_eq!(
+            multiattack
+                .attack_sequence(&attacks)
+                .iter()
+                .map(|attack| attack.name.as_str())
+                .collect::<Vec<_>>(),
+            ["Bite", "Claw", "Claw"]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+pub const DEFAULT_POSITION: Position = Position { x: 0, y: 0 };
+
+/// A point on the battlefield, in feet
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        DEFAULT_POSITION
+    }
+}
+
+impl Position {
+    #[must_use]
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Feet to the other point in a straight line, rounded up
+    #[must_use]
+    pub fn distance_to(&self, other: &Self) -> u32 {
+        u32::try_from(self.unbounded_distance_to(*other)).unwrap_or(u32::MAX)
+    }
+
+    /// Points at opposite corners of the battlefield are further apart than
+    /// a `u32` holds
+    fn unbounded_distance_to(self, other: Self) -> u128 {
+        let squared = self.squared_distance_to(&other);
+        let distance = squared.isqrt();
+        if distance * distance < squared {
+            distance + 1
+        } else {
+            distance
+        }
+    }
+
+    #[must_use]
+    pub fn squared_distance_to(&self, other: &Self) -> u128 {
+        let (dx, dy) = self.offset_to(other);
+        (dx * dx + dy * dy).unsigned_abs()
+    }
+
+    /// The other point relative to this one, wide enough that squaring and
+    /// multiplying offsets between any two points cannot overflow
+    #[must_use]
+    pub fn offset_to(&self, other: &Self) -> (i128, i128) {
+        (
+            i128::from(other.x) - i128::from(self.x),
+            i128::from(other.y) - i128::from(self.y),
+        )
+    }
+
+    /// The point `feet` along the straight line toward the other point
+    #[must_use]
+    pub fn toward(&self, other: &Self, feet: u32) -> Self {
+        let distance = i128::try_from(self.unbounded_distance_to(*other)).unwrap_or(i128::MAX);
+        if distance == 0 {
+            return *self;
+        }
+
+        let (dx, dy) = self.offset_to(other);
+        let step = |offset: i128| {
+            i32::try_from(offset * i128::from(feet) / distance).unwrap_or(if offset < 0 {
+                i32::MIN
+            } else {
+                i32::MAX
+            })
+        };
+
+        Self {
+            x: self.x.saturating_add(step(dx)),
+            y: self.y.saturating_add(step(dy)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances_are_rounded_up_to_the_next_foot() {
+        let origin = Position::default();
+
+        assert_eq!(origin.distance_to(&Position::new(30, 0)), 30);
+        assert_eq!(origin.distance_to(&Position::new(30, 40)), 50);
+        assert_eq!(origin.distance_to(&Position::new(5, 5)), 8);
+        assert_eq!(
+            origin.toward(&Position::new(30, 40), 10),
+            Position::new(6, 8)
+        );
+    }
+
+    #[test]
+    fn opposite_corners_do_not_overflow() {
+        let (min, max) = (
+            Position::new(i32::MIN, i32::MIN),
+            Position::new(i32::MAX, i32::MAX),
+        );
+
+        assert_eq!(
+            min.squared_distance_to(&max),
+            2 * u128::from(u32::MAX) * u128::from(u32::MAX)
+        );
+        assert_eq!(min.distance_to(&max), u32::MAX);
+        assert_eq!(
+            min.toward(&max, 10),
+            Position::new(i32::MIN + 7, i32::MIN + 7)
+        );
+        assert_eq!(
+            max.toward(&min, 10),
+            Position::new(i32::MAX - 7, i32::MAX - 7)
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{level::Level, weapon_proficiency_bonus::WEAPON_PROFICIENCY_BONUS_TABLE};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
@@ -21,6 +22,14 @@ impl ProficiencyBonus {
     }
 }
 
+/// Characters gain proficiency bonus with their level
+impl From<&Level> for ProficiencyBonus {
+    fn from(level: &Level) -> Self {
+        // Safety: Level type guarantees a stored value between 1 and 20
+        Self(WEAPON_PROFICIENCY_BONUS_TABLE[**level as usize - 1])
+    }
+}
+
 impl Deref for ProficiencyBonus {
     type Target = u32;
 
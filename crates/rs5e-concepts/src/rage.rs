@@ -0,0 +1,206 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#Rage>
+
+use crate::{ability_type::AbilityType, level::Level, weapon_range::WeaponRange};
+use std::ops::Deref;
+
+/// Rage lasts for 1 minute
+pub const RAGE_DURATION_ROUNDS: u32 = 10;
+
+#[derive(Debug)]
+pub struct NoRagesRemaining;
+
+/// Tracks a barbarian's rages over the course of a combat
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rage {
+    /// `None` once rages become unlimited at 20th level
+    uses_remaining: Option<u32>,
+    rounds_remaining: u32,
+    /// Berserkers may rage into a frenzy, see [`Rage::with_frenzy`]
+    frenzy: bool,
+}
+
+impl Rage {
+    #[must_use]
+    pub fn new(level: &Level) -> Self {
+        Self {
+            uses_remaining: match **level {
+                1..=2 => Some(2),
+                3..=5 => Some(3),
+                6..=11 => Some(4),
+                12..=16 => Some(5),
+                17..=19 => Some(6),
+                _ => None,
+            },
+            rounds_remaining: 0,
+            frenzy: false,
+        }
+    }
+
+    /// Source: <https://www.dndbeyond.com/sources/basic-rules/classes#PathoftheBerserker>
+    ///
+    /// Every rage becomes a frenzy, granting a melee weapon attack as a
+    /// bonus action on each turn after the first and a level of exhaustion
+    /// once it ends
+    #[must_use]
+    pub const fn with_frenzy(mut self) -> Self {
+        self.frenzy = true;
+        self
+    }
+
+    #[must_use]
+    pub const fn frenzy(&self) -> bool {
+        self.frenzy
+    }
+
+    #[must_use]
+    pub const fn is_frenzied(&self) -> bool {
+        self.frenzy && self.is_raging()
+    }
+
+    #[must_use]
+    pub const fn is_raging(&self) -> bool {
+        self.rounds_remaining > 0
+    }
+
+    #[must_use]
+    pub const fn uses_remaining(&self) -> Option<u32> {
+        self.uses_remaining
+    }
+
+    /// Entering a rage takes a bonus action and one use
+    ///
+    /// # Errors
+    ///
+    /// When every rage has been used
+    pub const fn enter(&mut self) -> Result<(), NoRagesRemaining> {
+        match self.uses_remaining {
+            Some(0) => return Err(NoRagesRemaining),
+            Some(uses_remaining) => self.uses_remaining = Some(uses_remaining - 1),
+            None => {}
+        }
+
+        self.rounds_remaining = RAGE_DURATION_ROUNDS;
+        Ok(())
+    }
+
+    /// Rage ends at once when the barbarian is incapacitated.
+    /// Returns whether a rage ended.
+    pub const fn end(&mut self) -> bool {
+        let was_raging = self.is_raging();
+        self.rounds_remaining = 0;
+        was_raging
+    }
+
+    /// Rage ends early if the barbarian made no attack on their turn.
+    /// Returns whether the rage ended.
+    pub const fn end_turn(&mut self, attacked: bool) -> bool {
+        if !self.is_raging() {
+            return false;
+        }
+
+        self.rounds_remaining = if attacked {
+            self.rounds_remaining - 1
+        } else {
+            0
+        };
+
+        !self.is_raging()
+    }
+}
+
+#[derive(Debug)]
+pub struct RageDamageContext<'a> {
+    pub raging: bool,
+    pub level: &'a Level,
+    pub ability_type: &'a AbilityType,
+    pub weapon_range: Option<&'a WeaponRange>,
+}
+
+/// Applies to melee weapon attacks using Strength, unarmed strikes included
+#[derive(Debug)]
+pub struct RageDamageBonus(Option<i32>);
+
+impl Deref for RageDamageBonus {
+    type Target = Option<i32>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<RageDamageContext<'_>> for RageDamageBonus {
+    fn from(
+        RageDamageContext {
+            raging,
+            level,
+            ability_type,
+            weapon_range,
+        }: RageDamageContext,
+    ) -> Self {
+        let melee = weapon_range.is_none_or(|range| range == &WeaponRange::Melee);
+
+        Self(
+            (raging && melee && ability_type == &AbilityType::Strength).then_some(match **level {
+                1..=8 => 2,
+                9..=15 => 3,
+                _ => 4,
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rage_uses_are_consumed() {
+        let mut rage = Rage::new(&Level::default());
+
+        assert!(rage.enter().is_ok());
+        assert!(rage.enter().is_ok());
+        assert!(rage.enter().is_err());
+        assert!(Rage::new(&Level::try_from(20).unwrap()).enter().is_ok());
+    }
+
+    #[test]
+    fn rage_ends_without_an_attack() {
+        let mut rage = Rage::new(&Level::default());
+        rage.enter().unwrap();
+
+        assert!(!rage.end_turn(true));
+        assert!(rage.is_raging());
+        assert!(rage.end_turn(false));
+        assert!(!rage.is_raging());
+    }
+
+    #[test]
+    fn rage_ends_when_incapacitated() {
+        let mut rage = Rage::new(&Level::default());
+        rage.enter().unwrap();
+
+        assert!(rage.end());
+        assert!(!rage.is_raging());
+        assert!(!rage.end());
+    }
+
+    #[test]
+    fn rage_damage_only_applies_to_strength_melee_attacks() {
+        let level = Level::try_from(9).unwrap();
+        let melee = RageDamageBonus::from(RageDamageContext {
+            raging: true,
+            level: &level,
+            ability_type: &AbilityType::Strength,
+            weapon_range: Some(&WeaponRange::Melee),
+        });
+        let ranged = RageDamageBonus::from(RageDamageContext {
+            raging: true,
+            level: &level,
+            ability_type: &AbilityType::Dexterity,
+            weapon_range: Some(&WeaponRange::Ranged),
+        });
+
+        assert_eq!(*melee, Some(3));
+        assert_eq!(*ranged, None);
+    }
+}
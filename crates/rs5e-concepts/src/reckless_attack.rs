@@ -0,0 +1,90 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#RecklessAttack>
+
+use crate::{
+    ability_type::AbilityType,
+    advantage_type::{Advantage, AdvantageType},
+    weapon_range::WeaponRange,
+};
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct RecklessAttackContext<'a> {
+    pub reckless_attack: bool,
+    pub ability_type: &'a AbilityType,
+    pub weapon_range: Option<&'a WeaponRange>,
+}
+
+/// Advantage on melee weapon attack rolls using Strength
+#[derive(Debug)]
+pub struct RecklessAttackBonus(Option<Advantage>);
+
+impl Deref for RecklessAttackBonus {
+    type Target = Option<Advantage>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<RecklessAttackContext<'_>> for RecklessAttackBonus {
+    fn from(
+        RecklessAttackContext {
+            reckless_attack,
+            ability_type,
+            weapon_range,
+        }: RecklessAttackContext,
+    ) -> Self {
+        let melee = weapon_range.is_none_or(|range| range == &WeaponRange::Melee);
+
+        Self(
+            (reckless_attack && melee && ability_type == &AbilityType::Strength)
+                .then_some(Advantage),
+        )
+    }
+}
+
+/// Attack rolls against a defender who attacked recklessly have advantage
+/// until the defender's next turn
+#[derive(Debug)]
+pub struct RecklessTargetContext {
+    pub attacked_recklessly: bool,
+}
+
+#[derive(Debug)]
+pub struct RecklessTargetBonus(Option<Advantage>);
+
+impl Deref for RecklessTargetBonus {
+    type Target = Option<Advantage>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<RecklessTargetContext> for RecklessTargetBonus {
+    fn from(
+        RecklessTargetContext {
+            attacked_recklessly,
+        }: RecklessTargetContext,
+    ) -> Self {
+        Self(attacked_recklessly.then_some(Advantage))
+    }
+}
+
+impl From<&RecklessAttackBonus> for AdvantageType {
+    fn from(value: &RecklessAttackBonus) -> Self {
+        value
+            .deref()
+            .as_ref()
+            .map_or(Self::Normal, |advantage| Self::Advantage(advantage.clone()))
+    }
+}
+
+impl From<&RecklessTargetBonus> for AdvantageType {
+    fn from(value: &RecklessTargetBonus) -> Self {
+        value
+            .deref()
+            .as_ref()
+            .map_or(Self::Normal, |advantage| Self::Advantage(advantage.clone()))
+    }
+}
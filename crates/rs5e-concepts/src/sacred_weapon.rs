@@ -0,0 +1,172 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#OathofDevotion>
+
+use crate::{
+    ability_scores::AbilityScores, ability_type::AbilityType, level::Level,
+    subclass_type::SubclassType, weapon::WeaponModel,
+};
+use std::ops::Deref;
+
+/// Sacred Weapon lasts for 1 minute
+pub const SACRED_WEAPON_DURATION_ROUNDS: u32 = 10;
+
+#[derive(Debug)]
+pub struct NoChannelDivinityRemaining;
+
+/// Tracks a Devotion paladin's Channel Divinity over the course of a combat
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SacredWeapon {
+    /// Paladins regain their single use on a short or long rest
+    channel_divinity_uses: u32,
+    rounds_remaining: u32,
+}
+
+impl Default for SacredWeapon {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SacredWeapon {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            channel_divinity_uses: 1,
+            rounds_remaining: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.rounds_remaining > 0
+    }
+
+    #[must_use]
+    pub const fn uses_remaining(&self) -> u32 {
+        self.channel_divinity_uses
+    }
+
+    /// Imbuing the weapon takes an action and a use of Channel Divinity
+    ///
+    /// # Errors
+    ///
+    /// When Channel Divinity has already been used
+    pub const fn activate(&mut self) -> Result<(), NoChannelDivinityRemaining> {
+        if self.channel_divinity_uses == 0 {
+            return Err(NoChannelDivinityRemaining);
+        }
+
+        self.channel_divinity_uses -= 1;
+        self.rounds_remaining = SACRED_WEAPON_DURATION_ROUNDS;
+        Ok(())
+    }
+
+    /// Returns whether the weapon stopped glowing this turn
+    pub const fn end_turn(&mut self) -> bool {
+        if !self.is_active() {
+            return false;
+        }
+
+        self.rounds_remaining -= 1;
+        !self.is_active()
+    }
+}
+
+#[derive(Debug)]
+pub struct SacredWeaponContext<'a> {
+    pub active: bool,
+    pub subclass: Option<&'a SubclassType>,
+    pub level: &'a Level,
+    pub ability_scores: &'a AbilityScores,
+    pub weapon: Option<&'a WeaponModel>,
+}
+
+/// While Channel Divinity imbues the paladin's weapon, adds the Charisma
+/// modifier to attack rolls with that weapon, minimum +1.
+#[derive(Debug)]
+pub struct SacredWeaponBonus(Option<i32>);
+
+impl Deref for SacredWeaponBonus {
+    type Target = Option<i32>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<SacredWeaponContext<'_>> for SacredWeaponBonus {
+    fn from(
+        SacredWeaponContext {
+            active,
+            subclass,
+            level,
+            ability_scores,
+            weapon,
+        }: SacredWeaponContext,
+    ) -> Self {
+        Self(
+            (active
+                && subclass == Some(&SubclassType::Devotion)
+                && **level >= SubclassType::Devotion.level()
+                && weapon.is_some())
+            .then(|| {
+                ability_scores
+                    .score(&AbilityType::Charisma)
+                    .modifier()
+                    .value()
+                    .max(1)
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        damage_type::DamageType, weapon_category::WeaponCategory, weapon_range::WeaponRange,
+        weapon_type::WeaponType,
+    };
+    use rs5e_dice::{Dice, Die, DieType};
+
+    #[test]
+    fn sacred_weapon_spends_channel_divinity_and_lasts_a_minute() {
+        let mut sacred_weapon = SacredWeapon::new();
+
+        assert!(sacred_weapon.activate().is_ok());
+        assert_eq!(sacred_weapon.uses_remaining(), 0);
+        assert!(sacred_weapon.activate().is_err());
+
+        for _ in 1..SACRED_WEAPON_DURATION_ROUNDS {
+            assert!(!sacred_weapon.end_turn());
+        }
+
+        assert!(sacred_weapon.end_turn());
+        assert!(!sacred_weapon.is_active());
+    }
+
+    #[test]
+    fn sacred_weapon_bonus_only_applies_while_active() {
+        let level = Level::try_from(3).unwrap();
+        let ability_scores = AbilityScores::default();
+        let weapon = WeaponModel {
+            weapon_type: WeaponType::Longsword,
+            weapon_range: WeaponRange::Melee,
+            weapon_category: WeaponCategory::Martial,
+            damage_type: DamageType::Slashing,
+            damage_dice: Dice::new(1, Die::new(DieType::D8)),
+            properties: vec![],
+        };
+        let bonus = |active| {
+            SacredWeaponBonus::from(SacredWeaponContext {
+                active,
+                subclass: Some(&SubclassType::Devotion),
+                level: &level,
+                ability_scores: &ability_scores,
+                weapon: Some(&weapon),
+            })
+        };
+
+        assert_eq!(*bonus(true), Some(1));
+        assert_eq!(*bonus(false), None);
+    }
+}
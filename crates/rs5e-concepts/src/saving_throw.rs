@@ -0,0 +1,169 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/using-ability-scores#SavingThrows>
+
+use crate::{
+    ability_modifier::AbilityModifier,
+    ability_scores::AbilityScores,
+    ability_type::AbilityType,
+    armor_class::ArmorClass,
+    cover_state::{CoverBonus, CoverState},
+    proficiency_bonus::ProficiencyBonus,
+    proficiency_type::ProficiencyType,
+};
+
+#[derive(Debug)]
+pub struct SavingThrowContext<'a> {
+    pub ability: &'a AbilityType,
+    pub ability_scores: &'a AbilityScores,
+    pub proficiencies: &'a [ProficiencyType],
+    pub proficiency_bonus: &'a ProficiencyBonus,
+    pub cover_state: &'a CoverState,
+    /// The target gains no benefit from cover, e.g. against sacred flame
+    pub ignores_cover: bool,
+}
+
+/// The ability modifier, plus the proficiency bonus for saving throws the
+/// class is proficient in
+#[derive(Debug, PartialEq, Eq)]
+pub struct SavingThrowBonus {
+    pub ability_modifier: AbilityModifier,
+    pub proficiency_bonus: Option<ProficiencyBonus>,
+    /// Half and three-quarters cover add to Dexterity saving throws
+    pub cover_bonus: u32,
+}
+
+impl SavingThrowBonus {
+    #[must_use]
+    pub fn value(&self) -> i32 {
+        self.ability_modifier.value()
+            + self
+                .proficiency_bonus
+                .as_ref()
+                .map_or(0, |bonus| i32::try_from(bonus.value()).unwrap_or(0))
+            + i32::try_from(self.cover_bonus).unwrap_or(0)
+    }
+}
+
+impl From<SavingThrowContext<'_>> for SavingThrowBonus {
+    fn from(
+        SavingThrowContext {
+            ability,
+            ability_scores,
+            proficiencies,
+            proficiency_bonus,
+            cover_state,
+            ignores_cover,
+        }: SavingThrowContext,
+    ) -> Self {
+        let proficient = proficiencies.contains(&ProficiencyType::SavingThrow(ability.clone()));
+
+        Self {
+            ability_modifier: ability_scores.score(ability).modifier(),
+            proficiency_bonus: proficient.then(|| proficiency_bonus.clone()),
+            cover_bonus: match (ability, CoverBonus::from(cover_state)) {
+                _ if ignores_cover => 0,
+                (AbilityType::Dexterity, CoverBonus::CanBeTargeted(cover_bonus)) => {
+                    cover_bonus.armor_class_bonus().map_or(0, ArmorClass::value)
+                }
+                _ => 0,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SavingThrow {
+    pub ability: AbilityType,
+    pub raw_value: u32,
+    /// The lower roll when rolled with advantage, e.g. Magic Resistance
+    pub discarded_raw_value: Option<u32>,
+    pub bonus: SavingThrowBonus,
+    pub dc: u32,
+    /// Set by conditions such as paralysis, regardless of the roll
+    pub automatic_failure: bool,
+    /// A failed saving throw turned into a success
+    pub legendary_resistance: bool,
+}
+
+impl SavingThrow {
+    /// Keeps the higher of the two rolls
+    #[must_use]
+    pub const fn with_advantage(mut self, reroll: u32) -> Self {
+        let (kept, discarded) = if reroll > self.raw_value {
+            (reroll, self.raw_value)
+        } else {
+            (self.raw_value, reroll)
+        };
+        self.raw_value = kept;
+        self.discarded_raw_value = Some(discarded);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_legendary_resistance(mut self) -> Self {
+        self.legendary_resistance = true;
+        self
+    }
+
+    #[must_use]
+    pub fn total_value(&self) -> i32 {
+        i32::try_from(self.raw_value).unwrap_or(0) + self.bonus.value()
+    }
+
+    /// Meeting the DC is a success
+    #[must_use]
+    pub fn succeeded(&self) -> bool {
+        self.legendary_resistance
+            || (!self.automatic_failure
+                && self.total_value() >= i32::try_from(self.dc).unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ability_scores::AbilityScore, level::Level};
+
+    #[test]
+    fn proficient_saving_throws_add_the_proficiency_bonus_and_cover() {
+        let mut ability_scores = AbilityScores::default();
+        *ability_scores.score_mut(&AbilityType::Dexterity) = AbilityScore::try_from(16).unwrap();
+        let proficiencies = [ProficiencyType::SavingThrow(AbilityType::Dexterity)];
+        let proficiency_bonus = ProficiencyBonus::from(&Level::try_from(5).unwrap());
+
+        let bonus_ignoring_cover = |ability, ignores_cover| {
+            SavingThrowBonus::from(SavingThrowContext {
+                ability,
+                ability_scores: &ability_scores,
+                proficiencies: &proficiencies,
+                proficiency_bonus: &proficiency_bonus,
+                cover_state: &CoverState::Half,
+                ignores_cover,
+            })
+        };
+        let bonus = |ability| bonus_ignoring_cover(ability, false);
+
+        assert_eq!(bonus(&AbilityType::Dexterity).value(), 8);
+        assert_eq!(bonus(&AbilityType::Wisdom).value(), 0);
+        assert_eq!(
+            bonus_ignoring_cover(&AbilityType::Dexterity, true).value(),
+            6
+        );
+
+        let saving_throw = SavingThrow {
+            ability: AbilityType::Dexterity,
+            raw_value: 7,
+            discarded_raw_value: None,
+            bonus: bonus(&AbilityType::Dexterity),
+            dc: 15,
+            automatic_failure: false,
+            legendary_resistance: false,
+        };
+        assert!(saving_throw.succeeded());
+        let automatic_failure = SavingThrow {
+            automatic_failure: true,
+            ..saving_throw
+        };
+        assert!(!automatic_failure.succeeded());
+        assert!(automatic_failure.with_legendary_resistance().succeeded());
+    }
+}
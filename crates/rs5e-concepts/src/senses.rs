@@ -0,0 +1,19 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/monsters#Senses>
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+/// Special senses in feet, `None` when the creature lacks them
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Senses {
+    pub blindsight: Option<u32>,
+    pub darkvision: Option<u32>,
+    pub tremorsense: Option<u32>,
+    pub truesight: Option<u32>,
+    /// 10 plus the Wisdom (Perception) modifier
+    pub passive_perception: u32,
+}
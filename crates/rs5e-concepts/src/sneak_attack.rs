@@ -0,0 +1,113 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#SneakAttack>
+
+use crate::{
+    advantage_type::AdvantageType, class_type::ClassType, level::Level,
+    weapon_property::WeaponProperty, weapon_range::WeaponRange,
+};
+use rs5e_dice::{Dice, Die, DieType};
+use std::ops::Deref;
+
+#[derive(Debug)]
+pub struct SneakAttackContext<'a> {
+    /// `None` for creatures without a class, such as monsters
+    pub class: Option<&'a ClassType>,
+    pub level: &'a Level,
+    pub weapon_range: Option<&'a WeaponRange>,
+    pub weapon_properties: Option<&'a [WeaponProperty]>,
+    pub advantage_type: &'a AdvantageType,
+    pub ally_adjacent_to_target: bool,
+}
+
+/// Dealt once per turn on a hit, `None` when the attack does not qualify
+#[derive(Debug)]
+pub struct SneakAttackDice(Option<Dice>);
+
+impl Deref for SneakAttackDice {
+    type Target = Option<Dice>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<SneakAttackContext<'_>> for SneakAttackDice {
+    fn from(
+        SneakAttackContext {
+            class,
+            level,
+            weapon_range,
+            weapon_properties,
+            advantage_type,
+            ally_adjacent_to_target,
+        }: SneakAttackContext,
+    ) -> Self {
+        let finesse_or_ranged = weapon_range == Some(&WeaponRange::Ranged)
+            || weapon_properties
+                .is_some_and(|properties| properties.contains(&WeaponProperty::Finesse));
+
+        let qualifies = match advantage_type {
+            AdvantageType::Advantage(_) => true,
+            AdvantageType::Normal => ally_adjacent_to_target,
+            AdvantageType::Disadvantage(_) => false,
+        };
+
+        Self(
+            (class == Some(&ClassType::Rogue) && finesse_or_ranged && qualifies)
+                .then(|| Dice::new(level.div_ceil(2), Die::new(DieType::D6))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advantage_type::{Advantage, Disadvantage};
+
+    #[test]
+    fn sneak_attack_scales_with_rogue_level() {
+        let level = Level::try_from(5).unwrap();
+        let sneak_attack_dice = SneakAttackDice::from(SneakAttackContext {
+            class: Some(&ClassType::Rogue),
+            level: &level,
+            weapon_range: Some(&WeaponRange::Ranged),
+            weapon_properties: Some(&[]),
+            advantage_type: &AdvantageType::Advantage(Advantage),
+            ally_adjacent_to_target: false,
+        });
+
+        assert_eq!(sneak_attack_dice.map(|dice| dice.max_value()), Some(18));
+    }
+
+    #[test]
+    fn sneak_attack_needs_advantage_or_an_adjacent_ally() {
+        let level = Level::default();
+        let sneak_attack_dice = |advantage_type, ally_adjacent_to_target| {
+            SneakAttackDice::from(SneakAttackContext {
+                class: Some(&ClassType::Rogue),
+                level: &level,
+                weapon_range: Some(&WeaponRange::Melee),
+                weapon_properties: Some(&[WeaponProperty::Finesse]),
+                advantage_type,
+                ally_adjacent_to_target,
+            })
+        };
+
+        assert!(sneak_attack_dice(&AdvantageType::Normal, false).is_none());
+        assert!(sneak_attack_dice(&AdvantageType::Normal, true).is_some());
+        assert!(sneak_attack_dice(&AdvantageType::Disadvantage(Disadvantage), true).is_none());
+    }
+
+    #[test]
+    fn sneak_attack_needs_a_finesse_or_ranged_weapon() {
+        let sneak_attack_dice = SneakAttackDice::from(SneakAttackContext {
+            class: Some(&ClassType::Rogue),
+            level: &Level::default(),
+            weapon_range: Some(&WeaponRange::Melee),
+            weapon_properties: Some(&[WeaponProperty::Heavy]),
+            advantage_type: &AdvantageType::Advantage(Advantage),
+            ally_adjacent_to_target: false,
+        });
+
+        assert!(sneak_attack_dice.is_none());
+    }
+}
@@ -0,0 +1,303 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/monsters#SpecialTraits>
+
+use crate::{
+    advantage_type::{Advantage, AdvantageType, Disadvantage},
+    critical_state::CriticalState,
+    damage_type::DamageType,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+use typeshare::typeshare;
+
+/// A trait from a monster's stat block that changes how it fights
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase", tag = "t", content = "c")]
+pub enum SpecialAbility {
+    /// Advantage on attack rolls against a creature with one of the
+    /// monster's allies within 5 feet of it
+    PackTactics,
+    /// Advantage on saving throws against spells
+    MagicResistance,
+    Regeneration(Regeneration),
+    /// Disadvantage on attack rolls while in sunlight
+    SunlightSensitivity,
+    /// May drop to 1 hit point instead of 0 with a Constitution saving throw
+    UndeadFortitude,
+    /// Disengage or Hide as a bonus action
+    NimbleEscape,
+    /// Advantage on Wisdom (Perception) checks relying on some senses, e.g.
+    /// Keen Smell or Keen Hearing and Sight
+    KeenSenses,
+    /// A melee weapon deals one extra die, already included in the attacks
+    Brute,
+    /// Turns a failed saving throw into a success a number of times a day
+    LegendaryResistance(u32),
+    /// Listed in the stat block but without any effect in combat here
+    NotImplemented(String),
+}
+
+impl SpecialAbility {
+    #[must_use]
+    pub const fn is_implemented(&self) -> bool {
+        !matches!(self, Self::NotImplemented(_))
+    }
+}
+
+/// Hit points regained at the start of each of the monster's turns
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Regeneration {
+    pub hit_points: u32,
+    /// Taking damage of these types stops it at the start of the next turn,
+    /// e.g. acid or fire for a troll
+    pub suppressed_by: Vec<DamageType>,
+    /// Vampires do not regenerate in sunlight
+    pub suppressed_in_sunlight: bool,
+}
+
+fn regeneration(special_abilities: &[SpecialAbility]) -> Option<&Regeneration> {
+    special_abilities
+        .iter()
+        .find_map(|special_ability| match special_ability {
+            SpecialAbility::Regeneration(regeneration) => Some(regeneration),
+            _ => None,
+        })
+}
+
+#[derive(Debug)]
+pub struct PackTacticsContext<'a> {
+    pub special_abilities: &'a [SpecialAbility],
+    /// The ally is also not incapacitated
+    pub ally_adjacent_to_target: bool,
+}
+
+#[derive(Debug)]
+pub struct PackTacticsBonus(Option<Advantage>);
+
+impl Deref for PackTacticsBonus {
+    type Target = Option<Advantage>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<PackTacticsContext<'_>> for PackTacticsBonus {
+    fn from(
+        PackTacticsContext {
+            special_abilities,
+            ally_adjacent_to_target,
+        }: PackTacticsContext,
+    ) -> Self {
+        Self(
+            (ally_adjacent_to_target && special_abilities.contains(&SpecialAbility::PackTactics))
+                .then_some(Advantage),
+        )
+    }
+}
+
+impl From<&PackTacticsBonus> for AdvantageType {
+    fn from(value: &PackTacticsBonus) -> Self {
+        value
+            .deref()
+            .as_ref()
+            .map_or(Self::Normal, |advantage| Self::Advantage(advantage.clone()))
+    }
+}
+
+#[derive(Debug)]
+pub struct SunlightSensitivityContext<'a> {
+    pub special_abilities: &'a [SpecialAbility],
+    pub in_sunlight: bool,
+}
+
+#[derive(Debug)]
+pub struct SunlightSensitivityPenalty(Option<Disadvantage>);
+
+impl Deref for SunlightSensitivityPenalty {
+    type Target = Option<Disadvantage>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<SunlightSensitivityContext<'_>> for SunlightSensitivityPenalty {
+    fn from(
+        SunlightSensitivityContext {
+            special_abilities,
+            in_sunlight,
+        }: SunlightSensitivityContext,
+    ) -> Self {
+        Self(
+            (in_sunlight && special_abilities.contains(&SpecialAbility::SunlightSensitivity))
+                .then_some(Disadvantage),
+        )
+    }
+}
+
+impl From<&SunlightSensitivityPenalty> for AdvantageType {
+    fn from(value: &SunlightSensitivityPenalty) -> Self {
+        value.deref().as_ref().map_or(Self::Normal, |disadvantage| {
+            Self::Disadvantage(disadvantage.clone())
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct MagicResistanceContext<'a> {
+    pub special_abilities: &'a [SpecialAbility],
+}
+
+/// Applies to saving throws against spells
+#[derive(Debug)]
+pub struct MagicResistanceBonus(Option<Advantage>);
+
+impl Deref for MagicResistanceBonus {
+    type Target = Option<Advantage>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<MagicResistanceContext<'_>> for MagicResistanceBonus {
+    fn from(MagicResistanceContext { special_abilities }: MagicResistanceContext) -> Self {
+        Self(
+            special_abilities
+                .contains(&SpecialAbility::MagicResistance)
+                .then_some(Advantage),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct UndeadFortitudeContext<'a> {
+    pub special_abilities: &'a [SpecialAbility],
+    /// The damage that dropped the creature to 0 hit points
+    pub damage: u32,
+    pub damage_types: &'a [DamageType],
+    pub critical_state: CriticalState,
+}
+
+pub const UNDEAD_FORTITUDE_BASE_DC: u32 = 5;
+
+/// The DC of the Constitution saving throw to drop to 1 hit point instead,
+/// which radiant damage and critical hits do not allow
+#[derive(Debug)]
+pub struct UndeadFortitudeDc(Option<u32>);
+
+impl Deref for UndeadFortitudeDc {
+    type Target = Option<u32>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<UndeadFortitudeContext<'_>> for UndeadFortitudeDc {
+    fn from(
+        UndeadFortitudeContext {
+            special_abilities,
+            damage,
+            damage_types,
+            critical_state,
+        }: UndeadFortitudeContext,
+    ) -> Self {
+        Self(
+            (special_abilities.contains(&SpecialAbility::UndeadFortitude)
+                && !damage_types.contains(&DamageType::Radiant)
+                && !critical_state.is_critical())
+            .then_some(UNDEAD_FORTITUDE_BASE_DC + damage),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct RegenerationContext<'a> {
+    pub special_abilities: &'a [SpecialAbility],
+    /// Damage taken since the monster's last turn that stops it
+    pub suppressed_by: Option<&'a DamageType>,
+    pub in_sunlight: bool,
+}
+
+/// Why Regeneration does not work at the start of a turn
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RegenerationSuppressed {
+    Damage(DamageType),
+    Sunlight,
+}
+
+/// `None` for monsters without Regeneration
+#[derive(Debug)]
+pub struct RegenerationHitPoints(Option<Result<u32, RegenerationSuppressed>>);
+
+impl Deref for RegenerationHitPoints {
+    type Target = Option<Result<u32, RegenerationSuppressed>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<RegenerationContext<'_>> for RegenerationHitPoints {
+    fn from(
+        RegenerationContext {
+            special_abilities,
+            suppressed_by,
+            in_sunlight,
+        }: RegenerationContext,
+    ) -> Self {
+        Self(regeneration(special_abilities).map(|regeneration| {
+            match (
+                suppressed_by,
+                in_sunlight && regeneration.suppressed_in_sunlight,
+            ) {
+                (Some(damage_type), _) => Err(RegenerationSuppressed::Damage(*damage_type)),
+                (None, true) => Err(RegenerationSuppressed::Sunlight),
+                (None, false) => Ok(regeneration.hit_points),
+            }
+        }))
+    }
+}
+
+/// The damage type that stops the monster's Regeneration, if any
+#[must_use]
+pub fn regeneration_suppressed_by(
+    special_abilities: &[SpecialAbility],
+    damage_types: &[DamageType],
+) -> Option<DamageType> {
+    let regeneration = regeneration(special_abilities)?;
+
+    damage_types
+        .iter()
+        .find(|damage_type| regeneration.suppressed_by.contains(damage_type))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undead_fortitude_is_denied_by_radiant_damage_and_critical_hits() {
+        let special_abilities = [SpecialAbility::UndeadFortitude];
+        let dc = |damage_type, critical_state| {
+            *UndeadFortitudeDc::from(UndeadFortitudeContext {
+                special_abilities: &special_abilities,
+                damage: 9,
+                damage_types: &[damage_type],
+                critical_state,
+            })
+        };
+
+        assert_eq!(dc(DamageType::Slashing, CriticalState::Normal), Some(14));
+        assert_eq!(dc(DamageType::Radiant, CriticalState::Normal), None);
+        assert_eq!(dc(DamageType::Slashing, CriticalState::Critical), None);
+    }
+}
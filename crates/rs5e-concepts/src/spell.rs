@@ -0,0 +1,341 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/spellcasting>
+
+use crate::{
+    ability_type::AbilityType, class_type::ClassType, condition::Condition,
+    creature_type::CreatureType, damage_type::DamageType, level::Level,
+    util::macros::implement_from_str,
+};
+use rs5e_dice::Dice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
+use typeshare::typeshare;
+
+/// Cantrips are spells of level 0 and never use a spell slot
+pub const CANTRIP_LEVEL: u32 = 0;
+
+pub const FEET_PER_MILE: u32 = 5280;
+
+/// Touch spells reach a creature within 5 feet
+pub const TOUCH_RANGE: u32 = 5;
+
+#[derive(Debug)]
+pub struct ParseSpellRangeError;
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "camelCase", tag = "t", content = "c")]
+pub enum SpellRange {
+    /// The caster is the point of origin or the only target
+    SelfOnly,
+    Touch,
+    Feet(u32),
+    Miles(u32),
+    Sight,
+    Unlimited,
+    Special,
+}
+
+impl SpellRange {
+    /// Whether a target `distance` feet away is within range
+    #[must_use]
+    pub const fn reaches(&self, distance: u32) -> bool {
+        self.reaches_with_area(distance, None)
+    }
+
+    /// Spells with a range of self may still reach others with their area,
+    /// e.g. the 15-foot cone of burning hands
+    #[must_use]
+    pub const fn reaches_with_area(
+        &self,
+        distance: u32,
+        area_of_effect: Option<&AreaOfEffect>,
+    ) -> bool {
+        match self {
+            Self::SelfOnly => match area_of_effect {
+                Some(area_of_effect) => distance <= area_of_effect.size,
+                None => distance == 0,
+            },
+            Self::Touch => distance <= TOUCH_RANGE,
+            Self::Feet(feet) => distance <= *feet,
+            Self::Miles(miles) => distance <= *miles * FEET_PER_MILE,
+            Self::Sight | Self::Unlimited | Self::Special => true,
+        }
+    }
+}
+
+// Ranges as written in the SRD spell data, e.g. "120 feet" or "Touch"
+impl FromStr for SpellRange {
+    type Err = ParseSpellRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Self" => Self::SelfOnly,
+            "Touch" => Self::Touch,
+            "Sight" => Self::Sight,
+            "Unlimited" => Self::Unlimited,
+            "Special" => Self::Special,
+            _ => {
+                let (distance, unit) = s.split_once(' ').ok_or(ParseSpellRangeError)?;
+                let distance = distance.parse().map_err(|_| ParseSpellRangeError)?;
+
+                match unit {
+                    "feet" => Self::Feet(distance),
+                    "mile" | "miles" => Self::Miles(distance),
+                    _ => return Err(ParseSpellRangeError),
+                }
+            }
+        })
+    }
+}
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SpellAttackType {
+    Melee,
+    Ranged,
+}
+
+implement_from_str!(SpellAttackType, [["melee", Melee], ["ranged", Ranged]]);
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AreaOfEffectType {
+    Cone,
+    Cube,
+    Cylinder,
+    Line,
+    Sphere,
+}
+
+implement_from_str!(
+    AreaOfEffectType,
+    [
+        ["cone", Cone],
+        ["cube", Cube],
+        ["cylinder", Cylinder],
+        ["line", Line],
+        ["sphere", Sphere]
+    ]
+);
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AreaOfEffect {
+    pub area_type: AreaOfEffectType,
+    /// Feet, e.g. the radius of a sphere or the length of a cone
+    pub size: u32,
+}
+
+/// What a successful saving throw does to the spell's effect
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SpellDcSuccess {
+    /// Half damage, rounded down
+    Half,
+    /// No damage and no effect
+    None,
+    /// Spell specific, treated as no effect
+    Other,
+}
+
+implement_from_str!(
+    SpellDcSuccess,
+    [["half", Half], ["none", None], ["other", Other]]
+);
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellDc {
+    pub dc_type: AbilityType,
+    pub dc_success: SpellDcSuccess,
+}
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellDamage {
+    /// A few spells, such as chaos bolt, pick the type as they are cast
+    pub damage_type: Option<DamageType>,
+    /// Keyed by the slot the spell is cast with
+    pub damage_at_slot_level: BTreeMap<u32, Dice>,
+    /// Keyed by the character level the damage starts at, for cantrips
+    pub damage_at_character_level: BTreeMap<u32, Dice>,
+}
+
+impl SpellDamage {
+    /// Spells that do not list higher slots deal the same damage with them
+    #[must_use]
+    pub fn dice_at_slot_level(&self, slot_level: u32) -> Option<Dice> {
+        self.damage_at_slot_level
+            .range(..=slot_level)
+            .next_back()
+            .map(|(_, dice)| *dice)
+    }
+
+    #[must_use]
+    pub fn dice_at_character_level(&self, level: &Level) -> Option<Dice> {
+        self.damage_at_character_level
+            .range(..=**level)
+            .next_back()
+            .map(|(_, dice)| *dice)
+    }
+}
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellModel {
+    /// The SRD index, e.g. "fire-bolt"
+    pub index: String,
+    pub name: String,
+    /// 0 for cantrips
+    pub level: u32,
+    pub range: SpellRange,
+    pub attack_type: Option<SpellAttackType>,
+    /// Spells which force a saving throw instead of an attack roll
+    pub dc: Option<SpellDc>,
+    pub damage: Option<SpellDamage>,
+    /// Imposed on a failed saving throw, e.g. paralysis from hold person
+    pub condition: Option<Condition>,
+    /// The only creatures the spell affects, e.g. humanoids for hold person
+    pub target_creature_type: Option<CreatureType>,
+    /// The target gains no benefit from cover against the saving throw,
+    /// e.g. sacred flame
+    pub ignores_cover: bool,
+    pub area_of_effect: Option<AreaOfEffect>,
+    /// Separate attack rolls per cast, e.g. the three rays of Scorching Ray
+    pub attacks: u32,
+    pub concentration: bool,
+    pub classes: Vec<ClassType>,
+}
+
+impl SpellModel {
+    #[must_use]
+    pub const fn is_cantrip(&self) -> bool {
+        self.level == CANTRIP_LEVEL
+    }
+
+    /// Whether a creature of the type can be targeted by the spell
+    #[must_use]
+    pub fn affects(&self, creature_type: CreatureType) -> bool {
+        self.target_creature_type
+            .is_none_or(|target_creature_type| target_creature_type == creature_type)
+    }
+
+    /// Cantrips scale with the caster's level, other spells with the slot
+    #[must_use]
+    pub fn damage_dice(&self, slot_level: Option<u32>, level: &Level) -> Option<Dice> {
+        let damage = self.damage.as_ref()?;
+
+        slot_level.map_or_else(
+            || damage.dice_at_character_level(level),
+            |slot_level| damage.dice_at_slot_level(slot_level),
+        )
+    }
+
+    /// Spells with several attacks gain one for each slot level above their own
+    #[must_use]
+    pub const fn attacks_at_slot_level(&self, slot_level: Option<u32>) -> u32 {
+        match slot_level {
+            Some(slot_level) if self.attacks > 1 => {
+                self.attacks + slot_level.saturating_sub(self.level)
+            }
+            _ => self.attacks,
+        }
+    }
+}
+
+/// Every spell in the SRD data by index
+pub type SpellModelMap = HashMap<String, SpellModel>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rs5e_dice::{Die, DieType};
+
+    #[test]
+    fn ranges_parse_and_reach() {
+        assert_eq!(
+            SpellRange::from_str("120 feet").unwrap(),
+            SpellRange::Feet(120)
+        );
+        assert_eq!(
+            SpellRange::from_str("1 mile").unwrap(),
+            SpellRange::Miles(1)
+        );
+        assert_eq!(SpellRange::from_str("Touch").unwrap(), SpellRange::Touch);
+        assert!(SpellRange::from_str("Far away").is_err());
+
+        assert!(SpellRange::Feet(60).reaches(60));
+        assert!(!SpellRange::Feet(60).reaches(65));
+        assert!(SpellRange::Touch.reaches(5));
+        assert!(!SpellRange::Touch.reaches(10));
+    }
+
+    #[test]
+    fn cantrips_scale_with_character_level() {
+        let d10 = |n| Dice::new(n, Die::new(DieType::D10));
+        let fire_bolt = SpellModel {
+            index: "fire-bolt".to_string(),
+            name: "Fire Bolt".to_string(),
+            level: CANTRIP_LEVEL,
+            range: SpellRange::Feet(120),
+            attack_type: Some(SpellAttackType::Ranged),
+            dc: None,
+            damage: Some(SpellDamage {
+                damage_type: Some(DamageType::Fire),
+                damage_at_slot_level: BTreeMap::new(),
+                damage_at_character_level: BTreeMap::from([
+                    (1, d10(1)),
+                    (5, d10(2)),
+                    (11, d10(3)),
+                    (17, d10(4)),
+                ]),
+            }),
+            condition: None,
+            target_creature_type: None,
+            ignores_cover: false,
+            area_of_effect: None,
+            attacks: 1,
+            concentration: false,
+            classes: Vec::from([ClassType::Sorcerer, ClassType::Wizard]),
+        };
+
+        let dice_at = |level| fire_bolt.damage_dice(None, &Level::try_from(level).unwrap());
+
+        assert_eq!(dice_at(4), Some(d10(1)));
+        assert_eq!(dice_at(5), Some(d10(2)));
+        assert_eq!(dice_at(16), Some(d10(3)));
+        assert_eq!(dice_at(20), Some(d10(4)));
+        assert_eq!(fire_bolt.attacks_at_slot_level(None), 1);
+        assert!(fire_bolt.affects(CreatureType::Undead));
+
+        let hold_person = SpellModel {
+            index: "hold-person".to_string(),
+            name: "Hold Person".to_string(),
+            level: 2,
+            attack_type: None,
+            damage: None,
+            condition: Some(Condition::Paralyzed),
+            target_creature_type: Some(CreatureType::Humanoid),
+            concentration: true,
+            ..fire_bolt
+        };
+        assert!(hold_person.affects(CreatureType::Humanoid));
+        assert!(!hold_person.affects(CreatureType::Undead));
+    }
+}
@@ -0,0 +1,285 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/spellcasting#SpellAttackRolls>
+
+use crate::{
+    advantage_type::{AdvantageType, Disadvantage},
+    class_type::ClassType,
+    damage_type::DamageType,
+    level::Level,
+    spell::{SpellAttackType, SpellModel, SpellRange, TOUCH_RANGE},
+    spell_slots::SpellSlots,
+};
+use rs5e_dice::Dice;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidAttackSpell {
+    NoSpellcasting,
+    NotOnClassSpellList,
+    NoAttackOrSave,
+    /// Casting again would have no effect, e.g. on a paralyzed target
+    AlreadyAffected,
+    /// The spell only affects other creatures, e.g. hold person on a zombie
+    WrongCreatureType,
+    /// No slot of the spell's level or higher at the character's level
+    SpellLevelTooHigh,
+    /// Cantrips are cast without a slot
+    CantripSlotLevel,
+    /// e.g. a 3rd-level spell in a 1st-level slot
+    SlotLevelBelowSpellLevel,
+    /// The chosen slot level is not one the character has
+    NoSpellSlotOfLevel,
+    NoSpellSlotRemaining,
+    OutOfRange(SpellRange),
+}
+
+impl InvalidAttackSpell {
+    #[must_use]
+    pub const fn reason(&self) -> &'static str {
+        match self {
+            Self::NoSpellcasting => "they cannot cast spells at their class and level",
+            Self::NotOnClassSpellList => "it is not on their class spell list",
+            Self::NoAttackOrSave => {
+                "it neither makes a spell attack for damage nor forces a saving throw"
+            }
+            Self::AlreadyAffected => "the target already suffers its effect",
+            Self::WrongCreatureType => "it does not affect creatures of the target's type",
+            Self::SpellLevelTooHigh => "they have no spell slot of its level",
+            Self::CantripSlotLevel => "cantrips are cast without a spell slot",
+            Self::SlotLevelBelowSpellLevel => "the chosen spell slot is below the spell's level",
+            Self::NoSpellSlotOfLevel => "they have no spell slot of the chosen level",
+            Self::NoSpellSlotRemaining => "no spell slot of its level is left",
+            Self::OutOfRange(_) => "the target is out of range",
+        }
+    }
+}
+
+/// Only spells that roll to hit for damage, or force a saving throw against
+/// damage or a condition, can replace weapon attacks. A chosen slot level
+/// must fit the spell and be one the character has
+///
+/// # Errors
+///
+/// When the character could never cast the spell as an attack
+pub fn validate_attack_spell(
+    spell: &SpellModel,
+    class: &ClassType,
+    spell_slots: Option<&SpellSlots>,
+    slot_level: Option<u32>,
+) -> Result<(), InvalidAttackSpell> {
+    let spell_slots = spell_slots.ok_or(InvalidAttackSpell::NoSpellcasting)?;
+
+    if !spell.classes.contains(class) {
+        Err(InvalidAttackSpell::NotOnClassSpellList)
+    } else if !(spell.attack_type.is_some() && spell.damage.is_some()
+        || spell.dc.is_some() && (spell.damage.is_some() || spell.condition.is_some()))
+    {
+        Err(InvalidAttackSpell::NoAttackOrSave)
+    } else if !spell.is_cantrip() && !spell_slots.fits(spell.level) {
+        Err(InvalidAttackSpell::SpellLevelTooHigh)
+    } else {
+        match slot_level {
+            Some(_) if spell.is_cantrip() => Err(InvalidAttackSpell::CantripSlotLevel),
+            Some(slot_level) if slot_level < spell.level => {
+                Err(InvalidAttackSpell::SlotLevelBelowSpellLevel)
+            }
+            Some(slot_level) if spell_slots.maximum(slot_level) == 0 => {
+                Err(InvalidAttackSpell::NoSpellSlotOfLevel)
+            }
+            Some(_) | None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SpellAttackContext<'a, 'b> {
+    pub spell: &'a SpellModel,
+    /// None for cantrips
+    pub slot_level: Option<u32>,
+    pub level: &'b Level,
+    /// Feet between the caster and the target
+    pub distance: u32,
+}
+
+/// A spell cast at a single target with one or more attack rolls
+#[derive(Debug)]
+pub struct SpellAttack<'a> {
+    pub spell: &'a SpellModel,
+    pub attack_type: SpellAttackType,
+    pub slot_level: Option<u32>,
+    pub damage_dice: Dice,
+    pub damage_type: DamageType,
+    pub attacks: u32,
+    /// Ranged spell attacks have disadvantage within 5 feet of the target
+    pub advantage_type: AdvantageType,
+}
+
+impl<'a> TryFrom<SpellAttackContext<'a, '_>> for SpellAttack<'a> {
+    type Error = InvalidAttackSpell;
+
+    fn try_from(
+        SpellAttackContext {
+            spell,
+            slot_level,
+            level,
+            distance,
+        }: SpellAttackContext<'a, '_>,
+    ) -> Result<Self, Self::Error> {
+        if !spell
+            .range
+            .reaches_with_area(distance, spell.area_of_effect.as_ref())
+        {
+            return Err(InvalidAttackSpell::OutOfRange(spell.range));
+        }
+
+        let attack_type = spell
+            .attack_type
+            .ok_or(InvalidAttackSpell::NoAttackOrSave)?;
+        let damage_dice = spell
+            .damage_dice(slot_level, level)
+            .ok_or(InvalidAttackSpell::NoAttackOrSave)?;
+        let damage_type = spell
+            .damage
+            .as_ref()
+            .and_then(|damage| damage.damage_type)
+            .ok_or(InvalidAttackSpell::NoAttackOrSave)?;
+
+        Ok(Self {
+            spell,
+            attack_type,
+            slot_level,
+            damage_dice,
+            damage_type,
+            attacks: spell.attacks_at_slot_level(slot_level),
+            advantage_type: match attack_type {
+                SpellAttackType::Ranged if distance <= TOUCH_RANGE => {
+                    AdvantageType::Disadvantage(Disadvantage)
+                }
+                _ => AdvantageType::Normal,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spell::SpellDamage;
+    use rs5e_dice::{Die, DieType};
+    use std::collections::BTreeMap;
+
+    fn scorching_ray() -> SpellModel {
+        SpellModel {
+            index: "scorching-ray".to_string(),
+            name: "Scorching Ray".to_string(),
+            level: 2,
+            range: SpellRange::Feet(120),
+            attack_type: Some(SpellAttackType::Ranged),
+            dc: None,
+            damage: Some(SpellDamage {
+                damage_type: Some(DamageType::Fire),
+                damage_at_slot_level: BTreeMap::from([(2, Dice::new(2, Die::new(DieType::D6)))]),
+                damage_at_character_level: BTreeMap::new(),
+            }),
+            condition: None,
+            target_creature_type: None,
+            ignores_cover: false,
+            area_of_effect: None,
+            attacks: 3,
+            concentration: false,
+            classes: Vec::from([ClassType::Sorcerer, ClassType::Wizard]),
+        }
+    }
+
+    #[test]
+    fn attack_spells_are_validated_against_class_and_slots() {
+        let spell = scorching_ray();
+        let slots = |maximum| SpellSlots::new(maximum);
+
+        assert_eq!(
+            validate_attack_spell(
+                &spell,
+                &ClassType::Wizard,
+                Some(&slots([4, 2, 0, 0, 0, 0, 0, 0, 0])),
+                None
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            validate_attack_spell(
+                &spell,
+                &ClassType::Wizard,
+                Some(&slots([2, 0, 0, 0, 0, 0, 0, 0, 0])),
+                None
+            ),
+            Err(InvalidAttackSpell::SpellLevelTooHigh)
+        );
+        assert_eq!(
+            validate_attack_spell(
+                &spell,
+                &ClassType::Cleric,
+                Some(&slots([4, 2, 0, 0, 0, 0, 0, 0, 0])),
+                None
+            ),
+            Err(InvalidAttackSpell::NotOnClassSpellList)
+        );
+        assert_eq!(
+            validate_attack_spell(&spell, &ClassType::Wizard, None, None),
+            Err(InvalidAttackSpell::NoSpellcasting)
+        );
+    }
+
+    #[test]
+    fn chosen_slot_levels_must_fit_the_spell() {
+        let spell = scorching_ray();
+        let slots = SpellSlots::new([4, 3, 2, 0, 0, 0, 0, 0, 0]);
+        let validate = |slot_level| {
+            validate_attack_spell(&spell, &ClassType::Wizard, Some(&slots), slot_level)
+        };
+
+        assert_eq!(validate(Some(3)), Ok(()));
+        assert_eq!(
+            validate(Some(1)),
+            Err(InvalidAttackSpell::SlotLevelBelowSpellLevel)
+        );
+        assert_eq!(
+            validate(Some(4)),
+            Err(InvalidAttackSpell::NoSpellSlotOfLevel)
+        );
+        assert_eq!(
+            validate_attack_spell(
+                &SpellModel {
+                    level: 0,
+                    ..scorching_ray()
+                },
+                &ClassType::Wizard,
+                Some(&slots),
+                Some(1)
+            ),
+            Err(InvalidAttackSpell::CantripSlotLevel)
+        );
+    }
+
+    #[test]
+    fn spell_attacks_respect_range_and_gain_rays_when_upcast() {
+        let spell = scorching_ray();
+        let level = Level::try_from(5).unwrap();
+        let spell_attack = |slot_level, distance| {
+            SpellAttack::try_from(SpellAttackContext {
+                spell: &spell,
+                slot_level: Some(slot_level),
+                level: &level,
+                distance,
+            })
+        };
+
+        assert_eq!(spell_attack(2, 30).unwrap().attacks, 3);
+        assert_eq!(spell_attack(3, 30).unwrap().attacks, 4);
+        assert_eq!(
+            spell_attack(2, 5).unwrap().advantage_type,
+            AdvantageType::Disadvantage(Disadvantage)
+        );
+        assert_eq!(
+            spell_attack(2, 150).unwrap_err(),
+            InvalidAttackSpell::OutOfRange(SpellRange::Feet(120))
+        );
+    }
+}
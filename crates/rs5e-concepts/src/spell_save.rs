@@ -0,0 +1,143 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/spellcasting#SavingThrows>
+
+use crate::{
+    ability_type::AbilityType,
+    condition::Condition,
+    damage_type::DamageType,
+    level::Level,
+    spell::{SpellDc, SpellDcSuccess, SpellModel},
+    spell_attack::InvalidAttackSpell,
+};
+use rs5e_dice::Dice;
+
+#[derive(Debug)]
+pub struct SpellSaveContext<'a, 'b> {
+    pub spell: &'a SpellModel,
+    /// None for cantrips
+    pub slot_level: Option<u32>,
+    pub level: &'b Level,
+    /// Feet between the caster and the target
+    pub distance: u32,
+}
+
+/// A spell every target resists with a saving throw instead of being hit
+/// by an attack roll
+#[derive(Debug)]
+pub struct SpellSave<'a> {
+    pub spell: &'a SpellModel,
+    pub slot_level: Option<u32>,
+    pub dc: &'a SpellDc,
+    /// Rolled once and shared by every target
+    pub damage_dice: Option<Dice>,
+    pub damage_type: Option<DamageType>,
+    pub condition: Option<Condition>,
+}
+
+impl<'a> TryFrom<SpellSaveContext<'a, '_>> for SpellSave<'a> {
+    type Error = InvalidAttackSpell;
+
+    fn try_from(
+        SpellSaveContext {
+            spell,
+            slot_level,
+            level,
+            distance,
+        }: SpellSaveContext<'a, '_>,
+    ) -> Result<Self, Self::Error> {
+        if !spell
+            .range
+            .reaches_with_area(distance, spell.area_of_effect.as_ref())
+        {
+            return Err(InvalidAttackSpell::OutOfRange(spell.range));
+        }
+
+        let dc = spell
+            .dc
+            .as_ref()
+            .ok_or(InvalidAttackSpell::NoAttackOrSave)?;
+        let damage_dice = spell.damage_dice(slot_level, level);
+
+        if damage_dice.is_none() && spell.condition.is_none() {
+            return Err(InvalidAttackSpell::NoAttackOrSave);
+        }
+
+        Ok(Self {
+            spell,
+            slot_level,
+            dc,
+            damage_dice,
+            damage_type: spell.damage.as_ref().and_then(|damage| damage.damage_type),
+            condition: spell.condition,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct SaveDamageContext<'a> {
+    pub dc: &'a SpellDc,
+    pub saved: bool,
+    pub evasion: bool,
+}
+
+/// The share of the rolled damage a target takes after their saving throw
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SaveDamage {
+    Full,
+    Half,
+    None,
+}
+
+impl SaveDamage {
+    #[must_use]
+    pub const fn apply(&self, damage: u32) -> u32 {
+        match self {
+            Self::Full => damage,
+            Self::Half => damage / 2,
+            Self::None => 0,
+        }
+    }
+}
+
+impl From<SaveDamageContext<'_>> for SaveDamage {
+    fn from(SaveDamageContext { dc, saved, evasion }: SaveDamageContext) -> Self {
+        // Evasion only helps against Dexterity saves for half damage
+        let evasion = evasion
+            && dc.dc_type == AbilityType::Dexterity
+            && dc.dc_success == SpellDcSuccess::Half;
+
+        match (saved, dc.dc_success) {
+            (true, SpellDcSuccess::Half) if evasion => Self::None,
+            (true, SpellDcSuccess::Half) => Self::Half,
+            (true, SpellDcSuccess::None | SpellDcSuccess::Other) => Self::None,
+            (false, _) if evasion => Self::Half,
+            (false, _) => Self::Full,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evasion_improves_dexterity_saves_for_half_damage() {
+        let fireball = SpellDc {
+            dc_type: AbilityType::Dexterity,
+            dc_success: SpellDcSuccess::Half,
+        };
+        let save_damage =
+            |dc, saved, evasion| SaveDamage::from(SaveDamageContext { dc, saved, evasion });
+
+        assert_eq!(save_damage(&fireball, true, false).apply(28), 14);
+        assert_eq!(save_damage(&fireball, false, false).apply(28), 28);
+        assert_eq!(save_damage(&fireball, true, true).apply(28), 0);
+        assert_eq!(save_damage(&fireball, false, true).apply(28), 14);
+
+        let sacred_flame = SpellDc {
+            dc_type: AbilityType::Dexterity,
+            dc_success: SpellDcSuccess::None,
+        };
+        assert_eq!(save_damage(&sacred_flame, true, false), SaveDamage::None);
+        assert_eq!(save_damage(&sacred_flame, false, true), SaveDamage::Full);
+    }
+}
@@ -0,0 +1,247 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes>
+
+use crate::{
+    ability_scores::AbilityScores, class_type::ClassType, level::Level, spell::SpellModel,
+    spell_slots::SpellSlots, spellcasting::ClassSpellcasting,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+/// Classes that prepare spells always prepare at least one
+pub const MIN_PREPARED_SPELLS: u32 = 1;
+
+/// Bards, rangers, sorcerers and warlocks know a set number of spells, the
+/// other spellcasters prepare theirs from the whole class spell list
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "camelCase", tag = "t", content = "c")]
+pub enum SpellPreparation {
+    Known(u32),
+    Prepared(u32),
+}
+
+impl SpellPreparation {
+    #[must_use]
+    pub const fn limit(&self) -> u32 {
+        match self {
+            Self::Known(limit) | Self::Prepared(limit) => *limit,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SpellLimitsContext<'a> {
+    pub class: &'a ClassType,
+    pub level: &'a Level,
+    pub ability_scores: &'a AbilityScores,
+    pub class_spellcasting: &'a ClassSpellcasting,
+}
+
+/// Cantrips are chosen separately from leveled spells
+#[derive(Debug, PartialEq, Eq)]
+pub struct SpellLimits {
+    pub cantrips: u32,
+    pub spells: SpellPreparation,
+}
+
+impl From<SpellLimitsContext<'_>> for SpellLimits {
+    fn from(
+        SpellLimitsContext {
+            class,
+            level,
+            ability_scores,
+            class_spellcasting,
+        }: SpellLimitsContext,
+    ) -> Self {
+        let spells = class_spellcasting.spells_known(level).map_or_else(
+            || {
+                // Paladins prepare spells for half their level, rounded down
+                let prepared_level = if class == &ClassType::Paladin {
+                    **level / 2
+                } else {
+                    **level
+                };
+                let modifier = ability_scores
+                    .score(&class_spellcasting.ability)
+                    .modifier()
+                    .value();
+
+                SpellPreparation::Prepared(
+                    u32::try_from(i64::from(prepared_level) + i64::from(modifier))
+                        .unwrap_or(0)
+                        .max(MIN_PREPARED_SPELLS),
+                )
+            },
+            SpellPreparation::Known,
+        );
+
+        Self {
+            cantrips: class_spellcasting.cantrips_known(level),
+            spells,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidSpellSelection {
+    NoSpellcasting,
+    NotOnClassSpellList(String),
+    /// No slot of the spell's level or higher at the character's level
+    SpellLevelTooHigh(String),
+    TooManyCantrips(u32),
+    TooManySpells(SpellPreparation),
+}
+
+impl InvalidSpellSelection {
+    #[must_use]
+    pub const fn reason(&self) -> &'static str {
+        match self {
+            Self::NoSpellcasting => "they cannot cast spells at their class and level",
+            Self::NotOnClassSpellList(_) => "it is not on their class spell list",
+            Self::SpellLevelTooHigh(_) => "they have no spell slot of its level",
+            Self::TooManyCantrips(_) => "they know more cantrips than their level allows",
+            Self::TooManySpells(SpellPreparation::Known(_)) => {
+                "they know more spells than their level allows"
+            }
+            Self::TooManySpells(SpellPreparation::Prepared(_)) => {
+                "they prepare more spells than their level and spellcasting ability allow"
+            }
+        }
+    }
+}
+
+/// Whether a character of the class with these slots may know or prepare
+/// the spell
+#[must_use]
+pub fn is_choosable(spell: &SpellModel, class: &ClassType, spell_slots: &SpellSlots) -> bool {
+    spell.classes.contains(class) && (spell.is_cantrip() || spell_slots.fits(spell.level))
+}
+
+/// # Errors
+///
+/// When a spell cannot be chosen or there are more than the limits allow
+pub fn validate_spell_selection(
+    spells: &[&SpellModel],
+    class: &ClassType,
+    spell_slots: &SpellSlots,
+    limits: &SpellLimits,
+) -> Result<(), InvalidSpellSelection> {
+    if let Some(spell) = spells.iter().find(|spell| !spell.classes.contains(class)) {
+        return Err(InvalidSpellSelection::NotOnClassSpellList(
+            spell.name.clone(),
+        ));
+    }
+
+    if let Some(spell) = spells
+        .iter()
+        .find(|spell| !is_choosable(spell, class, spell_slots))
+    {
+        return Err(InvalidSpellSelection::SpellLevelTooHigh(spell.name.clone()));
+    }
+
+    let cantrips = spells.iter().filter(|spell| spell.is_cantrip()).count();
+    let leveled_spells = spells.len() - cantrips;
+
+    if cantrips > limits.cantrips as usize {
+        Err(InvalidSpellSelection::TooManyCantrips(limits.cantrips))
+    } else if leveled_spells > limits.spells.limit() as usize {
+        Err(InvalidSpellSelection::TooManySpells(limits.spells))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ability_scores::AbilityScore,
+        ability_type::AbilityType,
+        level::MAX_LEVEL,
+        spell::{SpellRange, CANTRIP_LEVEL},
+        spell_slots::MAX_SPELL_LEVEL,
+    };
+
+    fn spell(name: &str, level: u32) -> SpellModel {
+        SpellModel {
+            index: name.to_lowercase(),
+            name: name.to_string(),
+            level,
+            range: SpellRange::Feet(60),
+            attack_type: None,
+            dc: None,
+            damage: None,
+            condition: None,
+            target_creature_type: None,
+            ignores_cover: false,
+            area_of_effect: None,
+            attacks: 1,
+            concentration: false,
+            classes: Vec::from([ClassType::Cleric, ClassType::Paladin]),
+        }
+    }
+
+    #[test]
+    fn prepared_spells_are_limited_by_level_and_ability() {
+        let mut ability_scores = AbilityScores::default();
+        *ability_scores.score_mut(&AbilityType::Wisdom) = AbilityScore::try_from(16).unwrap();
+        let class_spellcasting = ClassSpellcasting {
+            ability: AbilityType::Wisdom,
+            level: Level::try_from(1).unwrap(),
+            spell_slots: [[2, 1, 0, 0, 0, 0, 0, 0, 0]; MAX_LEVEL as usize],
+            cantrips_known: [3; MAX_LEVEL as usize],
+            spells_known: None,
+        };
+        let level = Level::try_from(3).unwrap();
+        let limits = |class| {
+            SpellLimits::from(SpellLimitsContext {
+                class,
+                level: &level,
+                ability_scores: &ability_scores,
+                class_spellcasting: &class_spellcasting,
+            })
+        };
+
+        let cleric = limits(&ClassType::Cleric);
+        assert_eq!(cleric.spells, SpellPreparation::Prepared(6));
+        assert_eq!(
+            limits(&ClassType::Paladin).spells,
+            SpellPreparation::Prepared(4)
+        );
+
+        let slots = SpellSlots::new([2, 1, 0, 0, 0, 0, 0, 0, 0]);
+        let (cantrip, bless, fireball) = (
+            spell("Light", CANTRIP_LEVEL),
+            spell("Bless", 1),
+            spell("Fireball", 3),
+        );
+        let validate = |spells: &[&SpellModel]| {
+            validate_spell_selection(spells, &ClassType::Cleric, &slots, &cleric)
+        };
+
+        assert_eq!(validate(&[&cantrip, &bless]), Ok(()));
+        assert_eq!(
+            validate(&[&fireball]),
+            Err(InvalidSpellSelection::SpellLevelTooHigh(
+                "Fireball".to_string()
+            ))
+        );
+        assert_eq!(
+            validate(&[&cantrip; 4]),
+            Err(InvalidSpellSelection::TooManyCantrips(3))
+        );
+        assert_eq!(
+            validate(&[&bless; 7]),
+            Err(InvalidSpellSelection::TooManySpells(
+                SpellPreparation::Prepared(6)
+            ))
+        );
+        assert!(!is_choosable(
+            &bless,
+            &ClassType::Wizard,
+            &SpellSlots::new([1; MAX_SPELL_LEVEL as usize])
+        ));
+    }
+}
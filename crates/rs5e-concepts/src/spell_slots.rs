@@ -0,0 +1,151 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/spellcasting#SpellSlots>
+
+pub const MAX_SPELL_LEVEL: u32 = 9;
+
+#[derive(Debug)]
+pub struct NoSpellSlotRemaining {
+    pub slot_level: u32,
+}
+
+#[derive(Debug)]
+pub struct NoSpellSlotExpended {
+    pub slot_level: u32,
+}
+
+/// Tracks the spell slots a spellcaster has left over the course of a combat
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SpellSlots {
+    maximum: [u32; MAX_SPELL_LEVEL as usize],
+    remaining: [u32; MAX_SPELL_LEVEL as usize],
+}
+
+impl SpellSlots {
+    /// `maximum[0]` is the number of 1st-level slots
+    #[must_use]
+    pub const fn new(maximum: [u32; MAX_SPELL_LEVEL as usize]) -> Self {
+        Self {
+            maximum,
+            remaining: maximum,
+        }
+    }
+
+    #[must_use]
+    pub fn maximum(&self, slot_level: u32) -> u32 {
+        slot_level
+            .checked_sub(1)
+            .and_then(|index| self.maximum.get(index as usize))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn remaining(&self, slot_level: u32) -> u32 {
+        slot_level
+            .checked_sub(1)
+            .and_then(|index| self.remaining.get(index as usize))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn total_remaining(&self) -> u32 {
+        self.remaining.iter().sum()
+    }
+
+    #[must_use]
+    pub fn lowest_available(&self) -> Option<u32> {
+        (1..=MAX_SPELL_LEVEL).find(|slot_level| self.remaining(*slot_level) > 0)
+    }
+
+    /// Whether the spell could ever be cast with one of these slots
+    #[must_use]
+    pub fn fits(&self, spell_level: u32) -> bool {
+        (spell_level.max(1)..=MAX_SPELL_LEVEL).any(|slot_level| self.maximum(slot_level) > 0)
+    }
+
+    /// Spells are cast with a slot of their own level or higher
+    #[must_use]
+    pub fn lowest_available_at_or_above(&self, spell_level: u32) -> Option<u32> {
+        (spell_level.max(1)..=MAX_SPELL_LEVEL).find(|slot_level| self.remaining(*slot_level) > 0)
+    }
+
+    #[must_use]
+    pub fn highest_available(&self) -> Option<u32> {
+        (1..=MAX_SPELL_LEVEL)
+            .rev()
+            .find(|slot_level| self.remaining(*slot_level) > 0)
+    }
+
+    /// # Errors
+    ///
+    /// When no slot of that level is left
+    pub fn expend(&mut self, slot_level: u32) -> Result<(), NoSpellSlotRemaining> {
+        match slot_level
+            .checked_sub(1)
+            .and_then(|index| self.remaining.get_mut(index as usize))
+        {
+            Some(remaining) if *remaining > 0 => {
+                *remaining -= 1;
+                Ok(())
+            }
+            _ => Err(NoSpellSlotRemaining { slot_level }),
+        }
+    }
+
+    /// Regains a single expended slot, as with a wizard's Arcane Recovery
+    ///
+    /// # Errors
+    ///
+    /// When no slot of that level has been expended
+    pub fn recover(&mut self, slot_level: u32) -> Result<(), NoSpellSlotExpended> {
+        let maximum = self.maximum(slot_level);
+
+        match slot_level
+            .checked_sub(1)
+            .and_then(|index| self.remaining.get_mut(index as usize))
+        {
+            Some(remaining) if *remaining < maximum => {
+                *remaining += 1;
+                Ok(())
+            }
+            _ => Err(NoSpellSlotExpended { slot_level }),
+        }
+    }
+
+    /// Regains every expended slot, as after a long rest
+    pub const fn recover_all(&mut self) {
+        self.remaining = self.maximum;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expending_slots_runs_out() {
+        let mut spell_slots = SpellSlots::new([4, 2, 0, 0, 0, 0, 0, 0, 0]);
+
+        assert_eq!(spell_slots.highest_available(), Some(2));
+        assert!(spell_slots.expend(2).is_ok());
+        assert!(spell_slots.expend(2).is_ok());
+        assert!(spell_slots.expend(2).is_err());
+        assert_eq!(spell_slots.highest_available(), Some(1));
+        assert_eq!(spell_slots.total_remaining(), 4);
+    }
+
+    #[test]
+    fn recovering_slots_stops_at_the_maximum() {
+        let mut spell_slots = SpellSlots::new([4, 2, 0, 0, 0, 0, 0, 0, 0]);
+
+        assert!(spell_slots.recover(1).is_err());
+        assert!(spell_slots.expend(2).is_ok());
+        assert!(spell_slots.expend(2).is_ok());
+        assert!(spell_slots.recover(2).is_ok());
+        assert_eq!(spell_slots.remaining(2), 1);
+
+        spell_slots.recover_all();
+        assert_eq!(spell_slots.remaining(2), 2);
+        assert!(spell_slots.recover(3).is_err());
+    }
+}
@@ -0,0 +1,149 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/spellcasting#SpellcastingAbility>
+
+use crate::{
+    ability_modifier::AbilityModifier,
+    ability_scores::AbilityScores,
+    ability_type::AbilityType,
+    class_type::ClassType,
+    level::{Level, MAX_LEVEL},
+    proficiency_bonus::ProficiencyBonus,
+    spell_slots::{SpellSlots, MAX_SPELL_LEVEL},
+};
+use std::{collections::HashMap, ops::Deref};
+
+pub const BASE_SPELL_SAVE_DC: i32 = 8;
+
+/// How a class casts spells, taken from the SRD class and level data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassSpellcasting {
+    pub ability: AbilityType,
+    /// The class level at which spellcasting is gained, e.g. 2nd for paladins
+    pub level: Level,
+    /// `spell_slots[0]` holds the slots of a 1st-level character
+    pub spell_slots: [[u32; MAX_SPELL_LEVEL as usize]; MAX_LEVEL as usize],
+    /// Indexed by character level like the spell slots
+    pub cantrips_known: [u32; MAX_LEVEL as usize],
+    /// None for classes that prepare their spells instead
+    pub spells_known: Option<[u32; MAX_LEVEL as usize]>,
+}
+
+impl ClassSpellcasting {
+    #[must_use]
+    pub fn is_available_at(&self, level: &Level) -> bool {
+        *level >= self.level
+    }
+
+    #[must_use]
+    pub fn spell_slots(&self, level: &Level) -> SpellSlots {
+        // Safety: Level type guarantees a stored value between 1 and 20
+        SpellSlots::new(self.spell_slots[**level as usize - 1])
+    }
+
+    #[must_use]
+    pub fn cantrips_known(&self, level: &Level) -> u32 {
+        // Safety: Level type guarantees a stored value between 1 and 20
+        self.cantrips_known[**level as usize - 1]
+    }
+
+    #[must_use]
+    pub fn spells_known(&self, level: &Level) -> Option<u32> {
+        // Safety: Level type guarantees a stored value between 1 and 20
+        self.spells_known
+            .map(|spells_known| spells_known[**level as usize - 1])
+    }
+}
+
+/// Classes which never cast spells, such as fighters, are not in the map
+pub type SpellcastingByClassMap = HashMap<ClassType, ClassSpellcasting>;
+
+#[derive(Debug)]
+pub struct SpellcastingContext<'a> {
+    pub spellcasting_ability: Option<&'a AbilityType>,
+    pub ability_scores: &'a AbilityScores,
+    pub proficiency_bonus: &'a ProficiencyBonus,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SpellcastingStats {
+    pub ability: AbilityType,
+    pub ability_modifier: AbilityModifier,
+    pub proficiency_bonus: ProficiencyBonus,
+    /// Proficiency bonus plus the spellcasting ability modifier
+    pub attack_bonus: i32,
+    /// 8 plus the proficiency bonus and the spellcasting ability modifier
+    pub save_dc: u32,
+}
+
+#[derive(Debug)]
+pub struct Spellcasting(Option<SpellcastingStats>);
+
+impl Deref for Spellcasting {
+    type Target = Option<SpellcastingStats>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<SpellcastingContext<'_>> for Spellcasting {
+    fn from(
+        SpellcastingContext {
+            spellcasting_ability,
+            ability_scores,
+            proficiency_bonus,
+        }: SpellcastingContext,
+    ) -> Self {
+        Self(spellcasting_ability.map(|ability| {
+            let proficiency_bonus = proficiency_bonus.clone();
+            let ability_modifier = ability_scores.score(ability).modifier();
+            let attack_bonus =
+                i32::try_from(proficiency_bonus.value()).unwrap_or(0) + ability_modifier.value();
+
+            SpellcastingStats {
+                ability: ability.clone(),
+                ability_modifier,
+                proficiency_bonus,
+                attack_bonus,
+                save_dc: u32::try_from(BASE_SPELL_SAVE_DC + attack_bonus).unwrap_or(0),
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ability_scores::AbilityScore;
+
+    #[test]
+    fn attack_bonus_and_save_dc_use_the_spellcasting_ability() {
+        let mut ability_scores = AbilityScores::default();
+        *ability_scores.score_mut(&AbilityType::Intelligence) = AbilityScore::try_from(18).unwrap();
+
+        let spellcasting = |spellcasting_ability, level| {
+            Spellcasting::from(SpellcastingContext {
+                spellcasting_ability,
+                ability_scores: &ability_scores,
+                proficiency_bonus: &ProficiencyBonus::from(&Level::try_from(level).unwrap()),
+            })
+        };
+
+        assert_eq!(
+            *spellcasting(Some(&AbilityType::Intelligence), 5),
+            Some(SpellcastingStats {
+                ability: AbilityType::Intelligence,
+                ability_modifier: AbilityModifier::new(4),
+                proficiency_bonus: ProficiencyBonus::new(3),
+                attack_bonus: 7,
+                save_dc: 15,
+            })
+        );
+        assert_eq!(
+            spellcasting(Some(&AbilityType::Wisdom), 1)
+                .as_ref()
+                .map(|stats| stats.save_dc),
+            Some(10)
+        );
+        assert_eq!(*spellcasting(None, 5), None);
+    }
+}
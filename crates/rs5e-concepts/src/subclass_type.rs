@@ -0,0 +1,111 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes>
+
+use crate::{class_type::ClassType, level::Level, util::macros::implement_from_str};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+/// The subclasses in the SRD, one per class
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum SubclassType {
+    Berserker,
+    Lore,
+    Life,
+    Land,
+    Champion,
+    OpenHand,
+    Devotion,
+    Hunter,
+    Thief,
+    Draconic,
+    Fiend,
+    Evocation,
+}
+
+impl SubclassType {
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Berserker => "Path of the Berserker",
+            Self::Lore => "College of Lore",
+            Self::Life => "Life Domain",
+            Self::Land => "Circle of the Land",
+            Self::Champion => "Champion",
+            Self::OpenHand => "Way of the Open Hand",
+            Self::Devotion => "Oath of Devotion",
+            Self::Hunter => "Hunter",
+            Self::Thief => "Thief",
+            Self::Draconic => "Draconic Bloodline",
+            Self::Fiend => "The Fiend",
+            Self::Evocation => "School of Evocation",
+        }
+    }
+
+    #[must_use]
+    pub const fn class(&self) -> ClassType {
+        match self {
+            Self::Berserker => ClassType::Barbarian,
+            Self::Lore => ClassType::Bard,
+            Self::Life => ClassType::Cleric,
+            Self::Land => ClassType::Druid,
+            Self::Champion => ClassType::Fighter,
+            Self::OpenHand => ClassType::Monk,
+            Self::Devotion => ClassType::Paladin,
+            Self::Hunter => ClassType::Ranger,
+            Self::Thief => ClassType::Rogue,
+            Self::Draconic => ClassType::Sorcerer,
+            Self::Fiend => ClassType::Warlock,
+            Self::Evocation => ClassType::Wizard,
+        }
+    }
+
+    /// The level at which the class chooses its subclass
+    #[must_use]
+    pub const fn level(&self) -> u32 {
+        match self {
+            Self::Life | Self::Draconic | Self::Fiend => 1,
+            Self::Land | Self::Evocation => 2,
+            _ => 3,
+        }
+    }
+
+    #[must_use]
+    pub fn is_available_to(&self, class: &ClassType, level: &Level) -> bool {
+        &self.class() == class && **level >= self.level()
+    }
+}
+
+implement_from_str!(
+    SubclassType,
+    [
+        ["berserker", Berserker],
+        ["lore", Lore],
+        ["life", Life],
+        ["land", Land],
+        ["champion", Champion],
+        ["open-hand", OpenHand],
+        ["devotion", Devotion],
+        ["hunter", Hunter],
+        ["thief", Thief],
+        ["draconic", Draconic],
+        ["fiend", Fiend],
+        ["evocation", Evocation]
+    ]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subclasses_require_their_class_and_level() {
+        let level = |value: u32| Level::try_from(value).unwrap();
+
+        assert!(SubclassType::Champion.is_available_to(&ClassType::Fighter, &level(3)));
+        assert!(!SubclassType::Champion.is_available_to(&ClassType::Fighter, &level(2)));
+        assert!(!SubclassType::Champion.is_available_to(&ClassType::Barbarian, &level(3)));
+        assert!(SubclassType::Fiend.is_available_to(&ClassType::Warlock, &level(1)));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::util::macros::implement_from_str;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+#[typeshare]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[serde(rename_all = "camelCase")]
+pub enum ToolType {
+    AlchemistsSupplies,
+    BrewersSupplies,
+    CalligraphersSupplies,
+    CarpentersTools,
+    CartographersTools,
+    CobblersTools,
+    CooksUtensils,
+    DisguiseKit,
+    ForgeryKit,
+    GlassblowersTools,
+    HerbalismKit,
+    JewelersTools,
+    LeatherworkersTools,
+    MasonsTools,
+    NavigatorsTools,
+    PaintersSupplies,
+    PoisonersKit,
+    PottersTools,
+    SmithsTools,
+    ThievesTools,
+    TinkersTools,
+    WeaversTools,
+    WoodcarversTools,
+}
+
+implement_from_str!(
+    ToolType,
+    [
+        ["alchemists-supplies", AlchemistsSupplies],
+        ["brewers-supplies", BrewersSupplies],
+        ["calligraphers-supplies", CalligraphersSupplies],
+        ["carpenters-tools", CarpentersTools],
+        ["cartographers-tools", CartographersTools],
+        ["cobblers-tools", CobblersTools],
+        ["cooks-utensils", CooksUtensils],
+        ["disguise-kit", DisguiseKit],
+        ["forgery-kit", ForgeryKit],
+        ["glassblowers-tools", GlassblowersTools],
+        ["herbalism-kit", HerbalismKit],
+        ["jewelers-tools", JewelersTools],
+        ["leatherworkers-tools", LeatherworkersTools],
+        ["masons-tools", MasonsTools],
+        ["navigators-tools", NavigatorsTools],
+        ["painters-supplies", PaintersSupplies],
+        ["poisoners-kit", PoisonersKit],
+        ["potters-tools", PottersTools],
+        ["smiths-tools", SmithsTools],
+        ["thieves-tools", ThievesTools],
+        ["tinkers-tools", TinkersTools],
+        ["weavers-tools", WeaversTools],
+        ["woodcarvers-tools", WoodcarversTools]
+    ]
+);
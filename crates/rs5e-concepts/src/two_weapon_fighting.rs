@@ -0,0 +1,187 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/combat#TwoWeaponFighting>
+
+use crate::{
+    ability_modifier::AbilityModifier, fighting_style::FightingStyle, weapon::WeaponModel,
+    weapon_property::WeaponProperty, weapon_range::WeaponRange,
+};
+use std::ops::Deref;
+
+/// The hand an attack is made with
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum WeaponHand {
+    #[default]
+    MainHand,
+    /// A bonus action attack with a second light weapon
+    OffHand,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidOffHandWeapon {
+    NoMainHandWeapon,
+    TwoHandedMainHandWeapon,
+    NotMelee,
+    NotLight,
+    WieldingShield,
+}
+
+impl InvalidOffHandWeapon {
+    #[must_use]
+    pub const fn reason(&self) -> &'static str {
+        match self {
+            Self::NoMainHandWeapon => "an off-hand weapon needs a main-hand weapon",
+            Self::TwoHandedMainHandWeapon => "a two-handed weapon leaves no hand free",
+            Self::NotMelee => "both weapons must be melee weapons",
+            Self::NotLight => "both weapons must be light",
+            Self::WieldingShield => "a shield leaves no hand free",
+        }
+    }
+}
+
+/// Both hands need a light melee weapon to attack with the off hand
+///
+/// # Errors
+///
+/// When the weapons cannot be wielded together
+pub fn validate_off_hand_weapon(
+    main_hand_weapon: Option<&WeaponModel>,
+    off_hand_weapon: &WeaponModel,
+    wielding_shield: bool,
+) -> Result<(), InvalidOffHandWeapon> {
+    let main_hand_weapon = main_hand_weapon.ok_or(InvalidOffHandWeapon::NoMainHandWeapon)?;
+
+    if wielding_shield {
+        Err(InvalidOffHandWeapon::WieldingShield)
+    } else if main_hand_weapon
+        .properties
+        .contains(&WeaponProperty::TwoHanded)
+    {
+        Err(InvalidOffHandWeapon::TwoHandedMainHandWeapon)
+    } else if [main_hand_weapon, off_hand_weapon]
+        .iter()
+        .any(|weapon| weapon.weapon_range != WeaponRange::Melee)
+    {
+        Err(InvalidOffHandWeapon::NotMelee)
+    } else if [main_hand_weapon, off_hand_weapon]
+        .iter()
+        .any(|weapon| !weapon.properties.contains(&WeaponProperty::Light))
+    {
+        Err(InvalidOffHandWeapon::NotLight)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct DamageAbilityModifierContext<'a> {
+    pub hand: WeaponHand,
+    pub fighting_style: Option<&'a FightingStyle>,
+    pub ability_modifier: &'a AbilityModifier,
+}
+
+/// Added to the damage roll, an off-hand attack only adds a negative
+/// modifier unless the attacker has the Two-Weapon Fighting style
+#[derive(Debug)]
+pub struct DamageAbilityModifier(AbilityModifier);
+
+impl Deref for DamageAbilityModifier {
+    type Target = AbilityModifier;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<DamageAbilityModifierContext<'_>> for DamageAbilityModifier {
+    fn from(
+        DamageAbilityModifierContext {
+            hand,
+            fighting_style,
+            ability_modifier,
+        }: DamageAbilityModifierContext,
+    ) -> Self {
+        let adds_modifier = hand == WeaponHand::MainHand
+            || fighting_style == Some(&FightingStyle::TwoWeaponFighting)
+            || ability_modifier.value() < 0;
+
+        Self(if adds_modifier {
+            ability_modifier.clone()
+        } else {
+            AbilityModifier::new(0)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        damage_type::DamageType, weapon_category::WeaponCategory, weapon_type::WeaponType,
+    };
+    use rs5e_dice::{Dice, Die, DieType};
+
+    fn weapon(properties: Vec<WeaponProperty>) -> WeaponModel {
+        WeaponModel {
+            weapon_type: WeaponType::Shortsword,
+            weapon_range: WeaponRange::Melee,
+            weapon_category: WeaponCategory::Martial,
+            damage_type: DamageType::Piercing,
+            damage_dice: Dice::new(1, Die::new(DieType::D6)),
+            properties,
+        }
+    }
+
+    #[test]
+    fn off_hand_weapons_must_be_light_melee_weapons() {
+        let light = weapon(vec![WeaponProperty::Light]);
+        let two_handed = weapon(vec![WeaponProperty::TwoHanded]);
+        let hand_crossbow = WeaponModel {
+            weapon_type: WeaponType::CrossbowHand,
+            weapon_range: WeaponRange::Ranged,
+            ..weapon(vec![WeaponProperty::Light])
+        };
+
+        assert_eq!(
+            validate_off_hand_weapon(Some(&light), &light, false),
+            Ok(())
+        );
+        assert_eq!(
+            validate_off_hand_weapon(Some(&two_handed), &light, false),
+            Err(InvalidOffHandWeapon::TwoHandedMainHandWeapon)
+        );
+        assert_eq!(
+            validate_off_hand_weapon(Some(&light), &weapon(Vec::new()), false),
+            Err(InvalidOffHandWeapon::NotLight)
+        );
+        assert_eq!(
+            validate_off_hand_weapon(Some(&light), &hand_crossbow, false),
+            Err(InvalidOffHandWeapon::NotMelee)
+        );
+        assert_eq!(
+            validate_off_hand_weapon(Some(&hand_crossbow), &light, false),
+            Err(InvalidOffHandWeapon::NotMelee)
+        );
+        assert_eq!(
+            validate_off_hand_weapon(Some(&light), &light, true),
+            Err(InvalidOffHandWeapon::WieldingShield)
+        );
+    }
+
+    #[test]
+    fn off_hand_damage_needs_the_fighting_style() {
+        let damage_ability_modifier = |fighting_style, value| {
+            DamageAbilityModifier::from(DamageAbilityModifierContext {
+                hand: WeaponHand::OffHand,
+                fighting_style,
+                ability_modifier: &AbilityModifier::new(value),
+            })
+            .value()
+        };
+
+        assert_eq!(damage_ability_modifier(None, 3), 0);
+        assert_eq!(damage_ability_modifier(None, -1), -1);
+        assert_eq!(
+            damage_ability_modifier(Some(&FightingStyle::TwoWeaponFighting), 3),
+            3
+        );
+    }
+}
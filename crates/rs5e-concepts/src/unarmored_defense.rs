@@ -0,0 +1,203 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/classes#UnarmoredDefense>
+
+use crate::{
+    ability_scores::AbilityScores, ability_type::AbilityType, armor_category::ArmorCategory,
+    armor_class::ArmorClass, class_type::ClassType, subclass_type::SubclassType,
+};
+use std::ops::Deref;
+
+/// Ways to calculate AC without armor, the highest applicable one is used
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UnarmoredDefenseFormula {
+    /// Flat AC without any class feature
+    Default,
+    /// 10 + DEX + CON, a shield may be used
+    Barbarian,
+    /// 10 + DEX + WIS, lost when using a shield
+    Monk,
+    /// 13 + DEX, from the Draconic Bloodline sorcerer origin. The extra hit
+    /// point per level is left to the character's hit points.
+    DraconicResilience,
+}
+
+impl UnarmoredDefenseFormula {
+    fn armor_class(self, ability_scores: &AbilityScores, default: &ArmorClass) -> ArmorClass {
+        let modifier = |ability_type| ability_scores.score(&ability_type).modifier().value();
+
+        let armor_class = match self {
+            Self::Default => return default.clone(),
+            Self::Barbarian => {
+                10 + modifier(AbilityType::Dexterity) + modifier(AbilityType::Constitution)
+            }
+            Self::Monk => 10 + modifier(AbilityType::Dexterity) + modifier(AbilityType::Wisdom),
+            Self::DraconicResilience => 13 + modifier(AbilityType::Dexterity),
+        };
+
+        ArmorClass::new(armor_class.try_into().unwrap_or(0))
+    }
+}
+
+#[derive(Debug)]
+pub struct UnarmoredDefenseContext<'a> {
+    /// `None` for creatures without a class, such as monsters
+    pub class: Option<&'a ClassType>,
+    pub subclass: Option<&'a SubclassType>,
+    pub ability_scores: &'a AbilityScores,
+    pub armor_category: Option<&'a ArmorCategory>,
+    /// Used when no formula does better, e.g. 10
+    pub default_armor_class: &'a ArmorClass,
+}
+
+#[derive(Debug, Clone)]
+pub struct UnarmoredArmorClass {
+    pub formula: UnarmoredDefenseFormula,
+    pub armor_class: ArmorClass,
+}
+
+/// `None` when the defender wears armor, a shield alone does not count
+#[derive(Debug)]
+pub struct UnarmoredDefense(Option<UnarmoredArmorClass>);
+
+impl Deref for UnarmoredDefense {
+    type Target = Option<UnarmoredArmorClass>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<UnarmoredDefenseContext<'_>> for UnarmoredDefense {
+    fn from(
+        UnarmoredDefenseContext {
+            class,
+            subclass,
+            ability_scores,
+            armor_category,
+            default_armor_class,
+        }: UnarmoredDefenseContext,
+    ) -> Self {
+        let wielding_shield = match armor_category {
+            None => false,
+            Some(ArmorCategory::Shield) => true,
+            Some(_) => return Self(None),
+        };
+
+        let class_formula = match class {
+            Some(ClassType::Barbarian) => Some(UnarmoredDefenseFormula::Barbarian),
+            Some(ClassType::Monk) if !wielding_shield => Some(UnarmoredDefenseFormula::Monk),
+            Some(ClassType::Sorcerer) if subclass == Some(&SubclassType::Draconic) => {
+                Some(UnarmoredDefenseFormula::DraconicResilience)
+            }
+            _ => None,
+        };
+
+        // Later formulas win ties so the class feature is the one reported
+        Self(
+            [Some(UnarmoredDefenseFormula::Default), class_formula]
+                .into_iter()
+                .flatten()
+                .map(|formula| UnarmoredArmorClass {
+                    formula,
+                    armor_class: formula.armor_class(ability_scores, default_armor_class),
+                })
+                .max_by_key(|unarmored| unarmored.armor_class.value()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ability_scores::AbilityScore;
+
+    fn ability_scores(dexterity: u32, constitution: u32, wisdom: u32) -> AbilityScores {
+        let mut ability_scores = AbilityScores::default();
+        *ability_scores.score_mut(&AbilityType::Dexterity) =
+            AbilityScore::try_from(dexterity).unwrap();
+        *ability_scores.score_mut(&AbilityType::Constitution) =
+            AbilityScore::try_from(constitution).unwrap();
+        *ability_scores.score_mut(&AbilityType::Wisdom) = AbilityScore::try_from(wisdom).unwrap();
+        ability_scores
+    }
+
+    fn unarmored_defense(
+        class: &ClassType,
+        subclass: Option<&SubclassType>,
+        ability_scores: &AbilityScores,
+        armor_category: Option<&ArmorCategory>,
+    ) -> Option<UnarmoredArmorClass> {
+        UnarmoredDefense::from(UnarmoredDefenseContext {
+            class: Some(class),
+            subclass,
+            ability_scores,
+            armor_category,
+            default_armor_class: &ArmorClass::default(),
+        })
+        .clone()
+    }
+
+    #[test]
+    fn monks_add_wisdom_without_a_shield() {
+        let ability_scores = ability_scores(16, 10, 14);
+
+        let unarmored = unarmored_defense(&ClassType::Monk, None, &ability_scores, None).unwrap();
+        assert_eq!(unarmored.formula, UnarmoredDefenseFormula::Monk);
+        assert_eq!(unarmored.armor_class, ArmorClass::new(15));
+
+        let unarmored = unarmored_defense(
+            &ClassType::Monk,
+            None,
+            &ability_scores,
+            Some(&ArmorCategory::Shield),
+        )
+        .unwrap();
+        assert_eq!(unarmored.formula, UnarmoredDefenseFormula::Default);
+    }
+
+    #[test]
+    fn barbarians_add_constitution_with_a_shield() {
+        let ability_scores = ability_scores(14, 16, 10);
+
+        let unarmored = unarmored_defense(
+            &ClassType::Barbarian,
+            None,
+            &ability_scores,
+            Some(&ArmorCategory::Shield),
+        )
+        .unwrap();
+        assert_eq!(unarmored.formula, UnarmoredDefenseFormula::Barbarian);
+        assert_eq!(unarmored.armor_class, ArmorClass::new(15));
+
+        assert!(unarmored_defense(
+            &ClassType::Barbarian,
+            None,
+            &ability_scores,
+            Some(&ArmorCategory::Heavy)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn the_highest_formula_wins() {
+        // A clumsy barbarian is better off with the default
+        let ability_scores = ability_scores(6, 8, 10);
+
+        let unarmored =
+            unarmored_defense(&ClassType::Barbarian, None, &ability_scores, None).unwrap();
+        assert_eq!(unarmored.formula, UnarmoredDefenseFormula::Default);
+        assert_eq!(unarmored.armor_class, ArmorClass::new(10));
+
+        let unarmored = unarmored_defense(
+            &ClassType::Sorcerer,
+            Some(&SubclassType::Draconic),
+            &ability_scores,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            unarmored.formula,
+            UnarmoredDefenseFormula::DraconicResilience
+        );
+        assert_eq!(unarmored.armor_class, ArmorClass::new(11));
+    }
+}
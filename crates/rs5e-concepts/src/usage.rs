@@ -0,0 +1,158 @@
+//! Source: <https://www.dndbeyond.com/sources/basic-rules/monsters#LimitedUsage>
+
+use rs5e_dice::Dice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+/// How often a monster can take one of its actions
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase", tag = "t", content = "c")]
+pub enum Usage {
+    /// Regained at the start of the monster's turn on a high enough roll,
+    /// e.g. "Recharge 5–6"
+    RechargeOnRoll(Recharge),
+    /// A number of uses, e.g. "3/Day"
+    PerDay(u32),
+    /// Used once until the monster rests, which never happens mid-combat
+    RechargeAfterRest,
+}
+
+#[typeshare]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Recharge {
+    pub dice: Dice,
+    pub min_value: u32,
+}
+
+#[derive(Debug)]
+pub struct NoUsesRemaining;
+
+/// A roll to regain a spent action at the start of the monster's turn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RechargeRoll {
+    pub name: String,
+    pub dice: Dice,
+    pub roll: u32,
+    pub min_value: u32,
+}
+
+impl RechargeRoll {
+    #[must_use]
+    pub const fn recharged(&self) -> bool {
+        self.roll >= self.min_value
+    }
+}
+
+/// Tracks the uses left of one of a monster's limited actions over the
+/// course of a combat
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitedUse {
+    name: String,
+    usage: Usage,
+    uses_remaining: u32,
+}
+
+impl LimitedUse {
+    /// Recharging actions start the combat ready to use
+    #[must_use]
+    pub const fn new(name: String, usage: Usage) -> Self {
+        let uses_remaining = match usage {
+            Usage::PerDay(times) => times,
+            Usage::RechargeOnRoll(_) | Usage::RechargeAfterRest => 1,
+        };
+
+        Self {
+            name,
+            usage,
+            uses_remaining,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub const fn usage(&self) -> &Usage {
+        &self.usage
+    }
+
+    #[must_use]
+    pub const fn uses_remaining(&self) -> u32 {
+        self.uses_remaining
+    }
+
+    #[must_use]
+    pub const fn is_available(&self) -> bool {
+        self.uses_remaining > 0
+    }
+
+    /// # Errors
+    ///
+    /// When no uses are left
+    pub const fn spend(&mut self) -> Result<(), NoUsesRemaining> {
+        if self.uses_remaining == 0 {
+            return Err(NoUsesRemaining);
+        }
+
+        self.uses_remaining -= 1;
+        Ok(())
+    }
+
+    /// Only a spent action that recharges on a roll is rolled for
+    pub fn roll_recharge(&mut self) -> Option<RechargeRoll> {
+        let Usage::RechargeOnRoll(recharge) = &self.usage else {
+            return None;
+        };
+        if self.is_available() {
+            return None;
+        }
+
+        let recharge_roll = RechargeRoll {
+            name: self.name.clone(),
+            dice: recharge.dice,
+            roll: recharge.dice.roll(),
+            min_value: recharge.min_value,
+        };
+        if recharge_roll.recharged() {
+            self.uses_remaining = 1;
+        }
+
+        Some(recharge_roll)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn only_spent_recharge_actions_are_rolled_for() {
+        let mut breath = LimitedUse::new(
+            "Fire Breath".to_string(),
+            Usage::RechargeOnRoll(Recharge {
+                dice: Dice::from_str("1d1").unwrap(),
+                min_value: 1,
+            }),
+        );
+        assert!(breath.roll_recharge().is_none());
+        assert!(breath.spend().is_ok());
+        assert!(breath.spend().is_err());
+        assert!(breath.roll_recharge().is_some_and(|roll| roll.recharged()));
+        assert!(breath.is_available());
+
+        let mut per_day = LimitedUse::new("Web".to_string(), Usage::PerDay(2));
+        assert!(per_day.spend().is_ok());
+        assert_eq!(per_day.uses_remaining(), 1);
+        assert!(per_day.roll_recharge().is_none());
+        assert!(per_day.spend().is_ok());
+        assert!(!per_day.is_available());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    damage_type::DamageType, weapon_category::WeaponCategory, weapon_range::WeaponRange,
-    weapon_type::WeaponType,
+    damage_type::DamageType, weapon_category::WeaponCategory, weapon_property::WeaponProperty,
+    weapon_range::WeaponRange, weapon_type::WeaponType,
 };
 use rs5e_dice::Dice;
 #[cfg(feature = "serde")]
@@ -16,4 +16,5 @@ pub struct WeaponModel {
     pub weapon_category: WeaponCategory,
     pub damage_type: DamageType,
     pub damage_dice: Dice,
+    pub properties: Vec<WeaponProperty>,
 }
@@ -9,6 +9,8 @@ use crate::{
 pub struct WeaponAbilityModifierContext<'a> {
     pub ability_scores: &'a AbilityScores,
     pub weapon_range: Option<&'a WeaponRange>,
+    /// Use the higher of STR and DEX, e.g. for monk weapons
+    pub may_use_dexterity: bool,
 }
 
 #[derive(Debug)]
@@ -25,6 +27,15 @@ impl From<WeaponAbilityModifierContext<'_>> for AbilityModifierContext {
             .weapon_range
             .map_or(AbilityType::Strength, AbilityType::from);
 
+        let ability_type = if context.may_use_dexterity
+            && **context.ability_scores.score(&AbilityType::Dexterity)
+                > **context.ability_scores.score(&ability_type)
+        {
+            AbilityType::Dexterity
+        } else {
+            ability_type
+        };
+
         let ability_score = context.ability_scores.score(&ability_type);
 
         Self {
@@ -1,10 +1,6 @@
 use crate::{
-    class_type::ClassType,
-    level::Level,
-    proficiency_bonus::ProficiencyBonus,
-    proficiency_type::{proficiency_type_by_class_map, ProficiencyType},
-    weapon_category::WeaponCategory,
-    weapon_type::WeaponType,
+    proficiency_bonus::ProficiencyBonus, proficiency_type::ProficiencyType,
+    weapon_category::WeaponCategory, weapon_type::WeaponType,
 };
 use std::ops::{Deref, Not};
 
@@ -13,8 +9,8 @@ pub const WEAPON_PROFICIENCY_BONUS_TABLE: [u32; 20] =
 
 #[derive(Debug)]
 pub struct WeaponProficiencyContext<'a> {
-    pub class: &'a ClassType,
-    pub level: &'a Level,
+    pub proficiencies: &'a [ProficiencyType],
+    pub proficiency_bonus: &'a ProficiencyBonus,
     pub weapon_category: Option<&'a WeaponCategory>,
     pub weapon_type: Option<&'a WeaponType>,
 }
@@ -33,8 +29,8 @@ impl Deref for WeaponProficiencyBonus {
 impl From<WeaponProficiencyContext<'_>> for WeaponProficiencyBonus {
     fn from(
         WeaponProficiencyContext {
-            class,
-            level,
+            proficiencies,
+            proficiency_bonus,
             weapon_category,
             weapon_type,
         }: WeaponProficiencyContext,
@@ -42,32 +38,17 @@ impl From<WeaponProficiencyContext<'_>> for WeaponProficiencyBonus {
         let mut has_proficiency = weapon_category
             // Unarmed attacks have proficiency
             .map_or(true, |cat| {
-                proficiency_type_by_class_map()
-                    .get(class)
-                    .is_some_and(|class_proficiency_list| {
-                        class_proficiency_list
-                            .contains(&ProficiencyType::WeaponCategory(cat.clone()))
-                    })
+                proficiencies.contains(&ProficiencyType::WeaponCategory(cat.clone()))
             });
 
         if has_proficiency.not() {
             has_proficiency = weapon_type
                 // Unarmed attacks have proficiency
                 .map_or(true, |wep_type| {
-                    proficiency_type_by_class_map().get(class).is_some_and(
-                        |class_proficiency_list| {
-                            class_proficiency_list
-                                .contains(&ProficiencyType::WeaponType(wep_type.clone()))
-                        },
-                    )
+                    proficiencies.contains(&ProficiencyType::WeaponType(wep_type.clone()))
                 });
         }
 
-        Self(has_proficiency.then(|| {
-            ProficiencyBonus::new(
-                // Safety: Level type guarantees a stored value between 1 and 20
-                WEAPON_PROFICIENCY_BONUS_TABLE[*level.clone() as usize - 1],
-            )
-        }))
+        Self(has_proficiency.then(|| proficiency_bonus.clone()))
     }
 }
@@ -0,0 +1,39 @@
+use crate::util::macros::implement_from_str;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+#[typeshare]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[serde(rename_all = "camelCase")]
+pub enum WeaponProperty {
+    Ammunition,
+    Finesse,
+    Heavy,
+    Light,
+    Loading,
+    Monk,
+    Reach,
+    Special,
+    Thrown,
+    TwoHanded,
+    Versatile,
+}
+
+implement_from_str!(
+    WeaponProperty,
+    [
+        ["ammunition", Ammunition],
+        ["finesse", Finesse],
+        ["heavy", Heavy],
+        ["light", Light],
+        ["loading", Loading],
+        ["monk", Monk],
+        ["reach", Reach],
+        ["special", Special],
+        ["thrown", Thrown],
+        ["two-handed", TwoHanded],
+        ["versatile", Versatile]
+    ]
+);
@@ -162,6 +162,17 @@ impl Dice {
         (1..=self.quantity).fold(0, |acc, _| acc + self.die.roll())
     }
 
+    /// Any die that lands at or below `value` is rerolled once, and the new
+    /// roll must be used even if it is lower
+    #[inline]
+    #[must_use]
+    pub fn roll_rerolling_at_or_below(&self, value: u32) -> u32 {
+        (1..=self.quantity).fold(0, |acc, _| {
+            let roll = self.die.roll();
+            acc + if roll <= value { self.die.roll() } else { roll }
+        })
+    }
+
     /// The maximum value these dice can roll
     #[inline]
     #[must_use]
@@ -275,6 +286,16 @@ mod tests {
         assert!(five_d2 < three_d4);
     }
 
+    #[test]
+    fn rerolled_dice_stay_in_range() {
+        let two_d6 = Dice::new(2, Die::new(DieType::D6));
+
+        for _ in 0..100 {
+            let roll = two_d6.roll_rerolling_at_or_below(2);
+            assert!((two_d6.min_value()..=two_d6.max_value()).contains(&roll));
+        }
+    }
+
     #[test]
     fn die_display() {
         assert_eq!(DieType::D12.to_string(), "D12".to_string());
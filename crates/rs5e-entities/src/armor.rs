@@ -33,4 +33,8 @@ impl Armor for ArmorEntity<'_> {
     fn armor_class(&self) -> &ArmorClass {
         &self.model.armor_class
     }
+
+    fn stealth_disadvantage(&self) -> bool {
+        self.model.stealth_disadvantage
+    }
 }
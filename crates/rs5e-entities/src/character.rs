@@ -2,13 +2,46 @@ use crate::{armor::ArmorEntity, weapon::WeaponEntity};
 use rs5e_components::{
     attacker::Attacker, combatant::Combatant, damages::Damages, defender::Defender,
     destroyed::Destroyed, destructible::Destructible, has_abilities::HasAbilities,
-    has_armor::HasArmor, has_class::HasClass, has_level::HasLevel, identifiable::Identifiable,
-    named::Named,
+    has_armor::HasArmor, has_class::HasClass, has_concentration::HasConcentration,
+    has_conditions::HasConditions, has_creature_type::HasCreatureType,
+    has_divine_smite::HasDivineSmite, has_fighting_style::HasFightingStyle, has_ki::HasKi,
+    has_legendary_actions::HasLegendaryActions, has_level::HasLevel,
+    has_limited_uses::HasLimitedUses, has_proficiencies::HasProficiencies, has_rage::HasRage,
+    has_reckless_attack::HasRecklessAttack, has_sacred_weapon::HasSacredWeapon,
+    has_saving_throws::HasSavingThrows, has_skills::HasSkills, has_sneak_attack::HasSneakAttack,
+    has_special_abilities::HasSpecialAbilities, has_spell_slots::HasSpellSlots,
+    has_spellcasting::HasSpellcasting, has_subclass::HasSubclass, has_temporary_hp::HasTemporaryHp,
+    identifiable::Identifiable, named::Named,
 };
 use rs5e_concepts::{
-    ability_scores::AbilityScores, class_type::ClassType, cover_state::CoverState,
-    damage_source::DamageSource, damage_type::DamageType, hp::Hp, hp_change::HpChange, id::Id,
-    level::Level, prone_state::ProneState,
+    ability_scores::AbilityScores,
+    ability_type::AbilityType,
+    class_type::ClassType,
+    concentration::Concentration,
+    condition::SpellCondition,
+    cover_state::CoverState,
+    creature_type::CreatureType,
+    damage_source::DamageSource,
+    damage_type::DamageType,
+    divine_smite::SmitePolicy,
+    fighting_style::FightingStyle,
+    hp::Hp,
+    hp_change::HpChange,
+    id::Id,
+    ki::Ki,
+    legendary::{LegendaryActionPoints, LegendaryResistancePolicy},
+    level::Level,
+    position::Position,
+    proficiency_bonus::ProficiencyBonus,
+    proficiency_type::ProficiencyType,
+    prone_state::ProneState,
+    rage::Rage,
+    sacred_weapon::SacredWeapon,
+    special_ability::SpecialAbility,
+    spell::SpellModel,
+    spell_slots::SpellSlots,
+    subclass_type::SubclassType,
+    usage::LimitedUse,
 };
 use rs5e_dice::{Dice, Die, DieType};
 use rs5e_macro_derive::{Identifiable, Named};
@@ -26,20 +59,44 @@ pub struct DeadUnit {
 
 impl Destroyed for DeadUnit {}
 
-#[derive(Debug, Identifiable)]
+#[derive(Debug, Identifiable, Named)]
 pub struct CharacterEntity<'a> {
     pub id: Id,
     pub name: String,
     pub hp: Hp,
     pub ability_scores: AbilityScores,
     pub class: ClassType,
+    pub subclass: Option<SubclassType>,
     pub level: Level,
+    pub proficiencies: &'a [ProficiencyType],
+    pub fighting_style: Option<FightingStyle>,
+    pub rage: Option<Rage>,
+    /// Oath of Devotion paladins channel it on their first turn
+    pub sacred_weapon: Option<SacredWeapon>,
+    pub reckless_attack: bool,
+    pub attacked_recklessly: bool,
+    pub sneak_attack_used: bool,
+    pub spell_slots: Option<SpellSlots>,
+    pub spellcasting_ability: Option<AbilityType>,
+    pub smite_policy: Option<SmitePolicy>,
+    /// Cast in place of weapon attacks while a slot is left
+    pub attack_spell: Option<&'a SpellModel>,
+    /// Upcasts the attack spell, otherwise it is cast with the lowest slot left
+    pub attack_spell_slot_level: Option<u32>,
+    /// Known or prepared, cantrips included
+    pub spells: Vec<&'a SpellModel>,
+    pub ki: Option<Ki>,
+    pub subclass_damage_used: bool,
 
     pub equipped_weapon: Option<WeaponEntity<'a>>,
+    pub equipped_off_hand_weapon: Option<WeaponEntity<'a>>,
     pub equipped_armor: Option<ArmorEntity<'a>>,
 
     pub cover_state: CoverState,
     pub prone_state: ProneState,
+    pub position: Position,
+    pub conditions: Vec<SpellCondition>,
+    pub concentration: Option<Concentration>,
 }
 
 impl Damages for CharacterEntity<'_> {
@@ -64,6 +121,10 @@ impl Combatant for CharacterEntity<'_> {
     fn cover_state(&self) -> &CoverState {
         &self.cover_state
     }
+
+    fn position(&self) -> &Position {
+        &self.position
+    }
 }
 
 impl<'a> HasArmor for CharacterEntity<'a> {
@@ -84,6 +145,10 @@ impl<'a> Attacker for CharacterEntity<'a> {
     fn equipped_weapon(&self) -> Option<&Self::Weapon> {
         self.equipped_weapon.as_ref()
     }
+
+    fn equipped_off_hand_weapon(&self) -> Option<&Self::Weapon> {
+        self.equipped_off_hand_weapon.as_ref()
+    }
 }
 
 impl Defender for CharacterEntity<'_> {}
@@ -100,12 +165,27 @@ impl<'a> Destructible for CharacterEntity<'a> {
     }
 
     fn take_damage(&mut self, damage: u32) -> HpChange {
+        let temporary_absorbed = damage.min(self.hp.temporary);
+        self.hp.temporary -= temporary_absorbed;
+
+        let before = self.hp();
+        self.hp.current = self.hp.current.saturating_sub(damage - temporary_absorbed);
+        HpChange {
+            before,
+            max: self.max_hp(),
+            after: self.hp(),
+            temporary_absorbed,
+        }
+    }
+
+    fn regain_hp(&mut self, hp: u32) -> HpChange {
         let before = self.hp();
-        self.hp.current = self.hp.current.saturating_sub(damage);
+        self.hp.current = (self.hp.current + hp).min(self.hp.max);
         HpChange {
             before,
             max: self.max_hp(),
             after: self.hp(),
+            temporary_absorbed: 0,
         }
     }
 
@@ -130,8 +210,8 @@ impl HasAbilities for CharacterEntity<'_> {
 }
 
 impl HasClass for CharacterEntity<'_> {
-    fn class(&self) -> &ClassType {
-        &self.class
+    fn class(&self) -> Option<&ClassType> {
+        Some(&self.class)
     }
 }
 
@@ -140,3 +220,195 @@ impl HasLevel for CharacterEntity<'_> {
         &self.level
     }
 }
+
+impl HasProficiencies for CharacterEntity<'_> {
+    fn proficiencies(&self) -> &[ProficiencyType] {
+        self.proficiencies
+    }
+
+    fn proficiency_bonus(&self) -> ProficiencyBonus {
+        ProficiencyBonus::from(&self.level)
+    }
+}
+
+impl HasFightingStyle for CharacterEntity<'_> {
+    fn fighting_style(&self) -> Option<&FightingStyle> {
+        self.fighting_style.as_ref()
+    }
+}
+
+impl HasRage for CharacterEntity<'_> {
+    fn rage(&self) -> Option<&Rage> {
+        self.rage.as_ref()
+    }
+
+    fn rage_mut(&mut self) -> Option<&mut Rage> {
+        self.rage.as_mut()
+    }
+}
+
+impl HasSacredWeapon for CharacterEntity<'_> {
+    fn sacred_weapon(&self) -> Option<&SacredWeapon> {
+        self.sacred_weapon.as_ref()
+    }
+
+    fn sacred_weapon_mut(&mut self) -> Option<&mut SacredWeapon> {
+        self.sacred_weapon.as_mut()
+    }
+}
+
+impl HasRecklessAttack for CharacterEntity<'_> {
+    fn reckless_attack(&self) -> bool {
+        self.reckless_attack
+    }
+
+    fn attacked_recklessly(&self) -> bool {
+        self.attacked_recklessly
+    }
+
+    fn set_attacked_recklessly(&mut self, attacked_recklessly: bool) {
+        self.attacked_recklessly = attacked_recklessly;
+    }
+}
+
+impl HasSneakAttack for CharacterEntity<'_> {
+    fn sneak_attack_used(&self) -> bool {
+        self.sneak_attack_used
+    }
+
+    fn set_sneak_attack_used(&mut self, sneak_attack_used: bool) {
+        self.sneak_attack_used = sneak_attack_used;
+    }
+}
+
+impl HasSpellSlots for CharacterEntity<'_> {
+    fn spell_slots(&self) -> Option<&SpellSlots> {
+        self.spell_slots.as_ref()
+    }
+
+    fn spell_slots_mut(&mut self) -> Option<&mut SpellSlots> {
+        self.spell_slots.as_mut()
+    }
+}
+
+impl HasSpellcasting for CharacterEntity<'_> {
+    fn spellcasting_ability(&self) -> Option<&AbilityType> {
+        self.spellcasting_ability.as_ref()
+    }
+}
+
+impl HasKi for CharacterEntity<'_> {
+    fn ki(&self) -> Option<&Ki> {
+        self.ki.as_ref()
+    }
+
+    fn ki_mut(&mut self) -> Option<&mut Ki> {
+        self.ki.as_mut()
+    }
+}
+
+/// Limited actions only come from monster stat blocks
+impl HasLimitedUses for CharacterEntity<'_> {
+    fn limited_uses(&self) -> &[LimitedUse] {
+        &[]
+    }
+
+    fn limited_uses_mut(&mut self) -> &mut [LimitedUse] {
+        &mut []
+    }
+}
+
+/// Legendary actions only come from monster stat blocks
+impl HasLegendaryActions for CharacterEntity<'_> {
+    fn legendary_action_points(&self) -> Option<&LegendaryActionPoints> {
+        None
+    }
+
+    fn legendary_action_points_mut(&mut self) -> Option<&mut LegendaryActionPoints> {
+        None
+    }
+
+    fn legendary_resistance_policy(&self) -> &LegendaryResistancePolicy {
+        &LegendaryResistancePolicy::Never
+    }
+}
+
+impl HasDivineSmite for CharacterEntity<'_> {
+    fn smite_policy(&self) -> Option<&SmitePolicy> {
+        self.smite_policy.as_ref()
+    }
+}
+
+impl HasSubclass for CharacterEntity<'_> {
+    fn subclass(&self) -> Option<&SubclassType> {
+        self.subclass.as_ref()
+    }
+
+    fn subclass_damage_used(&self) -> bool {
+        self.subclass_damage_used
+    }
+
+    fn set_subclass_damage_used(&mut self, subclass_damage_used: bool) {
+        self.subclass_damage_used = subclass_damage_used;
+    }
+}
+
+impl HasTemporaryHp for CharacterEntity<'_> {
+    fn temporary_hp(&self) -> u32 {
+        self.hp.temporary
+    }
+
+    fn gain_temporary_hp(&mut self, temporary_hp: u32) {
+        self.hp.temporary = self.hp.temporary.max(temporary_hp);
+    }
+}
+
+impl HasConditions for CharacterEntity<'_> {
+    fn conditions(&self) -> &[SpellCondition] {
+        &self.conditions
+    }
+
+    fn conditions_mut(&mut self) -> &mut Vec<SpellCondition> {
+        &mut self.conditions
+    }
+}
+
+impl HasConcentration for CharacterEntity<'_> {
+    fn concentration(&self) -> Option<&Concentration> {
+        self.concentration.as_ref()
+    }
+
+    fn concentration_mut(&mut self) -> &mut Option<Concentration> {
+        &mut self.concentration
+    }
+}
+
+impl HasSavingThrows for CharacterEntity<'_> {
+    fn unmodified_saving_throw_roll(&self) -> u32 {
+        DEFAULT_ATTACK_ROLL_DICE.roll()
+    }
+}
+
+impl HasSpecialAbilities for CharacterEntity<'_> {
+    fn special_abilities(&self) -> &[SpecialAbility] {
+        &[]
+    }
+
+    fn regeneration_suppressed_by(&self) -> Option<&DamageType> {
+        None
+    }
+
+    fn set_regeneration_suppressed_by(&mut self, _damage_type: Option<DamageType>) {}
+}
+
+impl HasSkills for CharacterEntity<'_> {
+    fn unmodified_ability_check_roll(&self) -> u32 {
+        DEFAULT_ATTACK_ROLL_DICE.roll()
+    }
+}
+
+impl HasCreatureType for CharacterEntity<'_> {
+    fn creature_type(&self) -> CreatureType {
+        CreatureType::Humanoid
+    }
+}
@@ -0,0 +1,438 @@
+use crate::{
+    armor::ArmorEntity, character::CharacterEntity, character::DeadUnit, monster::MonsterEntity,
+    weapon::WeaponEntity,
+};
+use rs5e_components::{
+    attacker::Attacker, combatant::Combatant, damages::Damages, defender::Defender,
+    destructible::Destructible, has_abilities::HasAbilities, has_armor::HasArmor,
+    has_class::HasClass, has_concentration::HasConcentration, has_conditions::HasConditions,
+    has_creature_type::HasCreatureType, has_divine_smite::HasDivineSmite,
+    has_fighting_style::HasFightingStyle, has_ki::HasKi,
+    has_legendary_actions::HasLegendaryActions, has_level::HasLevel,
+    has_limited_uses::HasLimitedUses, has_proficiencies::HasProficiencies, has_rage::HasRage,
+    has_reckless_attack::HasRecklessAttack, has_sacred_weapon::HasSacredWeapon,
+    has_saving_throws::HasSavingThrows, has_skills::HasSkills, has_sneak_attack::HasSneakAttack,
+    has_special_abilities::HasSpecialAbilities, has_spell_slots::HasSpellSlots,
+    has_spellcasting::HasSpellcasting, has_subclass::HasSubclass, has_temporary_hp::HasTemporaryHp,
+    identifiable::Identifiable, named::Named,
+};
+use rs5e_concepts::{
+    ability_scores::AbilityScores,
+    ability_type::AbilityType,
+    armor_class::ArmorClass,
+    class_type::ClassType,
+    concentration::Concentration,
+    condition::SpellCondition,
+    cover_state::CoverState,
+    creature_type::CreatureType,
+    damage_source::DamageSource,
+    damage_type::DamageType,
+    divine_smite::SmitePolicy,
+    fighting_style::FightingStyle,
+    hp_change::HpChange,
+    id::Id,
+    ki::Ki,
+    legendary::{LegendaryAction, LegendaryActionPoints, LegendaryResistancePolicy},
+    level::Level,
+    monster_action::{MonsterAttack, MonsterSaveAction},
+    position::Position,
+    proficiency_bonus::ProficiencyBonus,
+    proficiency_type::ProficiencyType,
+    prone_state::ProneState,
+    rage::Rage,
+    sacred_weapon::SacredWeapon,
+    special_ability::SpecialAbility,
+    spell::SpellModel,
+    spell_slots::SpellSlots,
+    subclass_type::SubclassType,
+    usage::LimitedUse,
+};
+use rs5e_dice::Dice;
+
+/// Runs the same expression against whichever creature is wrapped
+macro_rules! delegate {
+    ($creature:expr, $inner:ident => $body:expr) => {
+        match $creature {
+            CreatureEntity::Character($inner) => $body,
+            CreatureEntity::Monster($inner) => $body,
+        }
+    };
+}
+
+/// Either side of a fight, a character built from a class or a monster
+/// built from a stat block
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum CreatureEntity<'a> {
+    Character(CharacterEntity<'a>),
+    Monster(MonsterEntity<'a>),
+}
+
+impl<'a> CreatureEntity<'a> {
+    /// Only characters cast attack spells
+    #[must_use]
+    pub const fn attack_spell(&self) -> Option<&'a SpellModel> {
+        match self {
+            Self::Character(character) => character.attack_spell,
+            Self::Monster(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn attack_spell_slot_level(&self) -> Option<u32> {
+        match self {
+            Self::Character(character) => character.attack_spell_slot_level,
+            Self::Monster(_) => None,
+        }
+    }
+
+    /// Only monsters attack from a stat block
+    #[must_use]
+    pub fn monster_attacks(&self) -> Vec<&'a MonsterAttack> {
+        match self {
+            Self::Character(_) => Vec::new(),
+            Self::Monster(monster) => monster.model.attack_sequence(),
+        }
+    }
+
+    /// Only monsters take legendary actions
+    #[must_use]
+    pub fn legendary_actions(&self) -> &'a [LegendaryAction] {
+        match self {
+            Self::Character(_) => &[],
+            Self::Monster(monster) => &monster.model.legendary_actions,
+        }
+    }
+
+    #[must_use]
+    pub fn monster_save_actions(&self) -> Vec<&'a MonsterSaveAction> {
+        match self {
+            Self::Character(_) => Vec::new(),
+            Self::Monster(monster) => monster.model.save_actions.iter().collect(),
+        }
+    }
+}
+
+impl Identifiable for CreatureEntity<'_> {
+    fn id(&self) -> Id {
+        delegate!(self, creature => creature.id())
+    }
+
+    fn set_id(&mut self, new_id: Id) {
+        delegate!(self, creature => creature.set_id(new_id));
+    }
+}
+
+impl Named for CreatureEntity<'_> {
+    fn name(&self) -> &str {
+        delegate!(self, creature => creature.name())
+    }
+
+    fn set_name(&mut self, new_name: String) {
+        delegate!(self, creature => creature.set_name(new_name));
+    }
+}
+
+impl Damages for CreatureEntity<'_> {
+    fn damage_source(&self) -> DamageSource {
+        delegate!(self, creature => creature.damage_source())
+    }
+
+    fn damage_type(&self) -> DamageType {
+        delegate!(self, creature => creature.damage_type())
+    }
+
+    fn damage_dice(&self) -> Dice {
+        delegate!(self, creature => creature.damage_dice())
+    }
+}
+
+impl Combatant for CreatureEntity<'_> {
+    fn prone_state(&self) -> &ProneState {
+        delegate!(self, creature => creature.prone_state())
+    }
+
+    fn cover_state(&self) -> &CoverState {
+        delegate!(self, creature => creature.cover_state())
+    }
+
+    fn position(&self) -> &Position {
+        delegate!(self, creature => creature.position())
+    }
+}
+
+impl<'a> HasArmor for CreatureEntity<'a> {
+    type Armor = ArmorEntity<'a>;
+
+    fn equipped_armor(&self) -> Option<&Self::Armor> {
+        delegate!(self, creature => creature.equipped_armor())
+    }
+}
+
+impl<'a> Attacker for CreatureEntity<'a> {
+    type Weapon = WeaponEntity<'a>;
+
+    fn unmodified_attack_roll(&self) -> u32 {
+        delegate!(self, creature => creature.unmodified_attack_roll())
+    }
+
+    fn equipped_weapon(&self) -> Option<&Self::Weapon> {
+        delegate!(self, creature => creature.equipped_weapon())
+    }
+
+    fn equipped_off_hand_weapon(&self) -> Option<&Self::Weapon> {
+        delegate!(self, creature => creature.equipped_off_hand_weapon())
+    }
+}
+
+impl Defender for CreatureEntity<'_> {
+    fn armor_class(&self) -> &ArmorClass {
+        delegate!(self, creature => creature.armor_class())
+    }
+}
+
+impl Destructible for CreatureEntity<'_> {
+    type Destroyed = DeadUnit;
+
+    fn hp(&self) -> u32 {
+        delegate!(self, creature => creature.hp())
+    }
+
+    fn max_hp(&self) -> u32 {
+        delegate!(self, creature => creature.max_hp())
+    }
+
+    fn take_damage(&mut self, damage: u32) -> HpChange {
+        delegate!(self, creature => creature.take_damage(damage))
+    }
+
+    fn regain_hp(&mut self, hp: u32) -> HpChange {
+        delegate!(self, creature => creature.regain_hp(hp))
+    }
+
+    fn destroy(self) -> Self::Destroyed {
+        delegate!(self, creature => creature.destroy())
+    }
+}
+
+impl HasAbilities for CreatureEntity<'_> {
+    fn ability_scores(&self) -> &AbilityScores {
+        delegate!(self, creature => creature.ability_scores())
+    }
+
+    fn ability_scores_mut(&mut self) -> &mut AbilityScores {
+        delegate!(self, creature => creature.ability_scores_mut())
+    }
+}
+
+impl HasClass for CreatureEntity<'_> {
+    fn class(&self) -> Option<&ClassType> {
+        delegate!(self, creature => creature.class())
+    }
+}
+
+impl HasLevel for CreatureEntity<'_> {
+    fn level(&self) -> &Level {
+        delegate!(self, creature => creature.level())
+    }
+}
+
+impl HasProficiencies for CreatureEntity<'_> {
+    fn proficiencies(&self) -> &[ProficiencyType] {
+        delegate!(self, creature => creature.proficiencies())
+    }
+
+    fn proficiency_bonus(&self) -> ProficiencyBonus {
+        delegate!(self, creature => creature.proficiency_bonus())
+    }
+}
+
+impl HasFightingStyle for CreatureEntity<'_> {
+    fn fighting_style(&self) -> Option<&FightingStyle> {
+        delegate!(self, creature => creature.fighting_style())
+    }
+}
+
+impl HasRage for CreatureEntity<'_> {
+    fn rage(&self) -> Option<&Rage> {
+        delegate!(self, creature => creature.rage())
+    }
+
+    fn rage_mut(&mut self) -> Option<&mut Rage> {
+        delegate!(self, creature => creature.rage_mut())
+    }
+}
+
+impl HasSacredWeapon for CreatureEntity<'_> {
+    fn sacred_weapon(&self) -> Option<&SacredWeapon> {
+        delegate!(self, creature => creature.sacred_weapon())
+    }
+
+    fn sacred_weapon_mut(&mut self) -> Option<&mut SacredWeapon> {
+        delegate!(self, creature => creature.sacred_weapon_mut())
+    }
+}
+
+impl HasRecklessAttack for CreatureEntity<'_> {
+    fn reckless_attack(&self) -> bool {
+        delegate!(self, creature => creature.reckless_attack())
+    }
+
+    fn attacked_recklessly(&self) -> bool {
+        delegate!(self, creature => creature.attacked_recklessly())
+    }
+
+    fn set_attacked_recklessly(&mut self, attacked_recklessly: bool) {
+        delegate!(self, creature => creature.set_attacked_recklessly(attacked_recklessly));
+    }
+}
+
+impl HasSneakAttack for CreatureEntity<'_> {
+    fn sneak_attack_used(&self) -> bool {
+        delegate!(self, creature => creature.sneak_attack_used())
+    }
+
+    fn set_sneak_attack_used(&mut self, sneak_attack_used: bool) {
+        delegate!(self, creature => creature.set_sneak_attack_used(sneak_attack_used));
+    }
+}
+
+impl HasSpellSlots for CreatureEntity<'_> {
+    fn spell_slots(&self) -> Option<&SpellSlots> {
+        delegate!(self, creature => creature.spell_slots())
+    }
+
+    fn spell_slots_mut(&mut self) -> Option<&mut SpellSlots> {
+        delegate!(self, creature => creature.spell_slots_mut())
+    }
+}
+
+impl HasSpellcasting for CreatureEntity<'_> {
+    fn spellcasting_ability(&self) -> Option<&AbilityType> {
+        delegate!(self, creature => creature.spellcasting_ability())
+    }
+}
+
+impl HasKi for CreatureEntity<'_> {
+    fn ki(&self) -> Option<&Ki> {
+        delegate!(self, creature => creature.ki())
+    }
+
+    fn ki_mut(&mut self) -> Option<&mut Ki> {
+        delegate!(self, creature => creature.ki_mut())
+    }
+}
+
+impl HasLimitedUses for CreatureEntity<'_> {
+    fn limited_uses(&self) -> &[LimitedUse] {
+        delegate!(self, creature => creature.limited_uses())
+    }
+
+    fn limited_uses_mut(&mut self) -> &mut [LimitedUse] {
+        delegate!(self, creature => creature.limited_uses_mut())
+    }
+}
+
+impl HasLegendaryActions for CreatureEntity<'_> {
+    fn legendary_action_points(&self) -> Option<&LegendaryActionPoints> {
+        delegate!(self, creature => creature.legendary_action_points())
+    }
+
+    fn legendary_action_points_mut(&mut self) -> Option<&mut LegendaryActionPoints> {
+        delegate!(self, creature => creature.legendary_action_points_mut())
+    }
+
+    fn legendary_resistance_policy(&self) -> &LegendaryResistancePolicy {
+        delegate!(self, creature => creature.legendary_resistance_policy())
+    }
+}
+
+impl HasDivineSmite for CreatureEntity<'_> {
+    fn smite_policy(&self) -> Option<&SmitePolicy> {
+        delegate!(self, creature => creature.smite_policy())
+    }
+}
+
+impl HasSubclass for CreatureEntity<'_> {
+    fn subclass(&self) -> Option<&SubclassType> {
+        delegate!(self, creature => creature.subclass())
+    }
+
+    fn subclass_damage_used(&self) -> bool {
+        delegate!(self, creature => creature.subclass_damage_used())
+    }
+
+    fn set_subclass_damage_used(&mut self, subclass_damage_used: bool) {
+        delegate!(self, creature => creature.set_subclass_damage_used(subclass_damage_used));
+    }
+}
+
+impl HasTemporaryHp for CreatureEntity<'_> {
+    fn temporary_hp(&self) -> u32 {
+        delegate!(self, creature => creature.temporary_hp())
+    }
+
+    fn gain_temporary_hp(&mut self, temporary_hp: u32) {
+        delegate!(self, creature => creature.gain_temporary_hp(temporary_hp));
+    }
+}
+
+impl HasConditions for CreatureEntity<'_> {
+    fn conditions(&self) -> &[SpellCondition] {
+        delegate!(self, creature => creature.conditions())
+    }
+
+    fn conditions_mut(&mut self) -> &mut Vec<SpellCondition> {
+        delegate!(self, creature => creature.conditions_mut())
+    }
+}
+
+impl HasConcentration for CreatureEntity<'_> {
+    fn concentration(&self) -> Option<&Concentration> {
+        delegate!(self, creature => creature.concentration())
+    }
+
+    fn concentration_mut(&mut self) -> &mut Option<Concentration> {
+        delegate!(self, creature => creature.concentration_mut())
+    }
+}
+
+impl HasSavingThrows for CreatureEntity<'_> {
+    fn unmodified_saving_throw_roll(&self) -> u32 {
+        delegate!(self, creature => creature.unmodified_saving_throw_roll())
+    }
+}
+
+impl HasSpecialAbilities for CreatureEntity<'_> {
+    fn special_abilities(&self) -> &[SpecialAbility] {
+        delegate!(self, creature => creature.special_abilities())
+    }
+
+    fn regeneration_suppressed_by(&self) -> Option<&DamageType> {
+        delegate!(self, creature => creature.regeneration_suppressed_by())
+    }
+
+    fn set_regeneration_suppressed_by(&mut self, damage_type: Option<DamageType>) {
+        delegate!(self, creature => creature.set_regeneration_suppressed_by(damage_type));
+    }
+}
+
+impl HasSkills for CreatureEntity<'_> {
+    fn unmodified_ability_check_roll(&self) -> u32 {
+        delegate!(self, creature => creature.unmodified_ability_check_roll())
+    }
+
+    fn stealth_bonus(&self) -> i32 {
+        delegate!(self, creature => creature.stealth_bonus())
+    }
+
+    fn passive_perception(&self) -> u32 {
+        delegate!(self, creature => creature.passive_perception())
+    }
+}
+
+impl HasCreatureType for CreatureEntity<'_> {
+    fn creature_type(&self) -> CreatureType {
+        delegate!(self, creature => creature.creature_type())
+    }
+}
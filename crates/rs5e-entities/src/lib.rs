@@ -1,3 +1,5 @@
 pub mod armor;
+pub mod character;
+pub mod creature;
+pub mod monster;
 pub mod weapon;
-pub mod character;
\ No newline at end of file
@@ -0,0 +1,399 @@
+use crate::{armor::ArmorEntity, character::DeadUnit, weapon::WeaponEntity};
+use rs5e_components::{
+    attacker::Attacker, combatant::Combatant, damages::Damages, defender::Defender,
+    destructible::Destructible, has_abilities::HasAbilities, has_armor::HasArmor,
+    has_class::HasClass, has_concentration::HasConcentration, has_conditions::HasConditions,
+    has_creature_type::HasCreatureType, has_divine_smite::HasDivineSmite,
+    has_fighting_style::HasFightingStyle, has_ki::HasKi,
+    has_legendary_actions::HasLegendaryActions, has_level::HasLevel,
+    has_limited_uses::HasLimitedUses, has_proficiencies::HasProficiencies, has_rage::HasRage,
+    has_reckless_attack::HasRecklessAttack, has_sacred_weapon::HasSacredWeapon,
+    has_saving_throws::HasSavingThrows, has_skills::HasSkills, has_sneak_attack::HasSneakAttack,
+    has_special_abilities::HasSpecialAbilities, has_spell_slots::HasSpellSlots,
+    has_spellcasting::HasSpellcasting, has_subclass::HasSubclass, has_temporary_hp::HasTemporaryHp,
+    identifiable::Identifiable, named::Named,
+};
+use rs5e_concepts::{
+    ability_scores::AbilityScores,
+    ability_type::AbilityType,
+    armor_class::ArmorClass,
+    class_type::ClassType,
+    concentration::Concentration,
+    condition::SpellCondition,
+    cover_state::CoverState,
+    creature_type::CreatureType,
+    damage_source::DamageSource,
+    damage_type::DamageType,
+    divine_smite::SmitePolicy,
+    fighting_style::FightingStyle,
+    hp::Hp,
+    hp_change::HpChange,
+    id::Id,
+    ki::Ki,
+    legendary::{LegendaryActionPoints, LegendaryResistancePolicy},
+    level::Level,
+    monster::MonsterModel,
+    position::Position,
+    proficiency_bonus::ProficiencyBonus,
+    proficiency_type::ProficiencyType,
+    prone_state::ProneState,
+    rage::Rage,
+    sacred_weapon::SacredWeapon,
+    special_ability::SpecialAbility,
+    spell_slots::SpellSlots,
+    subclass_type::SubclassType,
+    usage::LimitedUse,
+};
+use rs5e_dice::{Dice, Die, DieType};
+use rs5e_macro_derive::{Identifiable, Named};
+
+const DEFAULT_UNARMED_DAMAGE_SOURCE: DamageSource = DamageSource::Unarmed;
+const DEFAULT_UNARMED_DAMAGE_TYPE: DamageType = DamageType::Bludgeoning;
+const DEFAULT_UNARMED_DAMAGE_DICE: Dice = Dice::new(1, Die::new(DieType::D1));
+const DEFAULT_ATTACK_ROLL_DICE: Dice = Dice::new(1, Die::new(DieType::D20));
+
+/// A creature built from a stat block rather than a class. It has none of
+/// the class features and gains proficiency with its challenge rating.
+#[derive(Debug, Identifiable, Named)]
+pub struct MonsterEntity<'a> {
+    pub id: Id,
+    pub name: String,
+    pub model: &'a MonsterModel,
+    pub hp: Hp,
+    pub ability_scores: AbilityScores,
+    /// Monsters have no level, this only satisfies level-gated rules
+    pub level: Level,
+
+    pub cover_state: CoverState,
+    pub prone_state: ProneState,
+    pub position: Position,
+    pub conditions: Vec<SpellCondition>,
+    pub concentration: Option<Concentration>,
+    /// Stops Regeneration at the start of the monster's next turn
+    pub regeneration_suppressed_by: Option<DamageType>,
+    /// Uses left of actions such as a breath weapon
+    pub limited_uses: Vec<LimitedUse>,
+    /// `None` for monsters without legendary actions
+    pub legendary_action_points: Option<LegendaryActionPoints>,
+    pub legendary_resistance_policy: LegendaryResistancePolicy,
+}
+
+impl Damages for MonsterEntity<'_> {
+    fn damage_source(&self) -> DamageSource {
+        DEFAULT_UNARMED_DAMAGE_SOURCE
+    }
+
+    fn damage_type(&self) -> DamageType {
+        DEFAULT_UNARMED_DAMAGE_TYPE
+    }
+
+    fn damage_dice(&self) -> Dice {
+        DEFAULT_UNARMED_DAMAGE_DICE
+    }
+}
+
+impl Combatant for MonsterEntity<'_> {
+    fn prone_state(&self) -> &ProneState {
+        &self.prone_state
+    }
+
+    fn cover_state(&self) -> &CoverState {
+        &self.cover_state
+    }
+
+    fn position(&self) -> &Position {
+        &self.position
+    }
+}
+
+impl<'a> HasArmor for MonsterEntity<'a> {
+    type Armor = ArmorEntity<'a>;
+
+    /// The stat block's armor class already includes any armor worn
+    fn equipped_armor(&self) -> Option<&Self::Armor> {
+        None
+    }
+}
+
+impl<'a> Attacker for MonsterEntity<'a> {
+    type Weapon = WeaponEntity<'a>;
+
+    fn unmodified_attack_roll(&self) -> u32 {
+        DEFAULT_ATTACK_ROLL_DICE.roll()
+    }
+
+    fn equipped_weapon(&self) -> Option<&Self::Weapon> {
+        None
+    }
+}
+
+impl Defender for MonsterEntity<'_> {
+    fn armor_class(&self) -> &ArmorClass {
+        &self.model.armor_class
+    }
+}
+
+impl<'a> Destructible for MonsterEntity<'a> {
+    type Destroyed = DeadUnit;
+
+    fn hp(&self) -> u32 {
+        self.hp.current
+    }
+
+    fn max_hp(&self) -> u32 {
+        self.hp.max
+    }
+
+    fn take_damage(&mut self, damage: u32) -> HpChange {
+        let temporary_absorbed = damage.min(self.hp.temporary);
+        self.hp.temporary -= temporary_absorbed;
+
+        let before = self.hp();
+        self.hp.current = self.hp.current.saturating_sub(damage - temporary_absorbed);
+        HpChange {
+            before,
+            max: self.max_hp(),
+            after: self.hp(),
+            temporary_absorbed,
+        }
+    }
+
+    fn regain_hp(&mut self, hp: u32) -> HpChange {
+        let before = self.hp();
+        self.hp.current = (self.hp.current + hp).min(self.hp.max);
+        HpChange {
+            before,
+            max: self.max_hp(),
+            after: self.hp(),
+            temporary_absorbed: 0,
+        }
+    }
+
+    fn destroy(self) -> Self::Destroyed {
+        DeadUnit {
+            id: self.id,
+            name: self.name,
+        }
+    }
+}
+
+impl HasAbilities for MonsterEntity<'_> {
+    fn ability_scores(&self) -> &AbilityScores {
+        &self.ability_scores
+    }
+
+    fn ability_scores_mut(&mut self) -> &mut AbilityScores {
+        &mut self.ability_scores
+    }
+}
+
+impl HasClass for MonsterEntity<'_> {
+    fn class(&self) -> Option<&ClassType> {
+        None
+    }
+}
+
+impl HasLevel for MonsterEntity<'_> {
+    fn level(&self) -> &Level {
+        &self.level
+    }
+}
+
+impl HasProficiencies for MonsterEntity<'_> {
+    fn proficiencies(&self) -> &[ProficiencyType] {
+        &self.model.proficiencies
+    }
+
+    fn proficiency_bonus(&self) -> ProficiencyBonus {
+        self.model.challenge_rating.proficiency_bonus()
+    }
+}
+
+impl HasFightingStyle for MonsterEntity<'_> {
+    fn fighting_style(&self) -> Option<&FightingStyle> {
+        None
+    }
+}
+
+impl HasRage for MonsterEntity<'_> {
+    fn rage(&self) -> Option<&Rage> {
+        None
+    }
+
+    fn rage_mut(&mut self) -> Option<&mut Rage> {
+        None
+    }
+}
+
+impl HasSacredWeapon for MonsterEntity<'_> {
+    fn sacred_weapon(&self) -> Option<&SacredWeapon> {
+        None
+    }
+
+    fn sacred_weapon_mut(&mut self) -> Option<&mut SacredWeapon> {
+        None
+    }
+}
+
+impl HasRecklessAttack for MonsterEntity<'_> {
+    fn reckless_attack(&self) -> bool {
+        false
+    }
+
+    fn attacked_recklessly(&self) -> bool {
+        false
+    }
+
+    fn set_attacked_recklessly(&mut self, _attacked_recklessly: bool) {}
+}
+
+impl HasSneakAttack for MonsterEntity<'_> {
+    fn sneak_attack_used(&self) -> bool {
+        false
+    }
+
+    fn set_sneak_attack_used(&mut self, _sneak_attack_used: bool) {}
+}
+
+impl HasSpellSlots for MonsterEntity<'_> {
+    fn spell_slots(&self) -> Option<&SpellSlots> {
+        None
+    }
+
+    fn spell_slots_mut(&mut self) -> Option<&mut SpellSlots> {
+        None
+    }
+}
+
+impl HasSpellcasting for MonsterEntity<'_> {
+    fn spellcasting_ability(&self) -> Option<&AbilityType> {
+        None
+    }
+}
+
+impl HasKi for MonsterEntity<'_> {
+    fn ki(&self) -> Option<&Ki> {
+        None
+    }
+
+    fn ki_mut(&mut self) -> Option<&mut Ki> {
+        None
+    }
+}
+
+impl HasLimitedUses for MonsterEntity<'_> {
+    fn limited_uses(&self) -> &[LimitedUse] {
+        &self.limited_uses
+    }
+
+    fn limited_uses_mut(&mut self) -> &mut [LimitedUse] {
+        &mut self.limited_uses
+    }
+}
+
+impl HasLegendaryActions for MonsterEntity<'_> {
+    fn legendary_action_points(&self) -> Option<&LegendaryActionPoints> {
+        self.legendary_action_points.as_ref()
+    }
+
+    fn legendary_action_points_mut(&mut self) -> Option<&mut LegendaryActionPoints> {
+        self.legendary_action_points.as_mut()
+    }
+
+    fn legendary_resistance_policy(&self) -> &LegendaryResistancePolicy {
+        &self.legendary_resistance_policy
+    }
+}
+
+impl HasDivineSmite for MonsterEntity<'_> {
+    fn smite_policy(&self) -> Option<&SmitePolicy> {
+        None
+    }
+}
+
+impl HasSubclass for MonsterEntity<'_> {
+    fn subclass(&self) -> Option<&SubclassType> {
+        None
+    }
+
+    fn subclass_damage_used(&self) -> bool {
+        false
+    }
+
+    fn set_subclass_damage_used(&mut self, _subclass_damage_used: bool) {}
+}
+
+impl HasTemporaryHp for MonsterEntity<'_> {
+    fn temporary_hp(&self) -> u32 {
+        self.hp.temporary
+    }
+
+    fn gain_temporary_hp(&mut self, temporary_hp: u32) {
+        self.hp.temporary = self.hp.temporary.max(temporary_hp);
+    }
+}
+
+impl HasConditions for MonsterEntity<'_> {
+    fn conditions(&self) -> &[SpellCondition] {
+        &self.conditions
+    }
+
+    fn conditions_mut(&mut self) -> &mut Vec<SpellCondition> {
+        &mut self.conditions
+    }
+}
+
+impl HasConcentration for MonsterEntity<'_> {
+    fn concentration(&self) -> Option<&Concentration> {
+        self.concentration.as_ref()
+    }
+
+    fn concentration_mut(&mut self) -> &mut Option<Concentration> {
+        &mut self.concentration
+    }
+}
+
+impl HasSavingThrows for MonsterEntity<'_> {
+    fn unmodified_saving_throw_roll(&self) -> u32 {
+        DEFAULT_ATTACK_ROLL_DICE.roll()
+    }
+}
+
+impl HasSpecialAbilities for MonsterEntity<'_> {
+    fn special_abilities(&self) -> &[SpecialAbility] {
+        &self.model.special_abilities
+    }
+
+    fn regeneration_suppressed_by(&self) -> Option<&DamageType> {
+        self.regeneration_suppressed_by.as_ref()
+    }
+
+    fn set_regeneration_suppressed_by(&mut self, damage_type: Option<DamageType>) {
+        self.regeneration_suppressed_by = damage_type;
+    }
+}
+
+impl HasSkills for MonsterEntity<'_> {
+    fn unmodified_ability_check_roll(&self) -> u32 {
+        DEFAULT_ATTACK_ROLL_DICE.roll()
+    }
+
+    /// Stat blocks list the total for proficient skills
+    fn stealth_bonus(&self) -> i32 {
+        self.model.stealth.unwrap_or_else(|| {
+            self.ability_score(&AbilityType::Dexterity)
+                .modifier()
+                .value()
+        })
+    }
+
+    fn passive_perception(&self) -> u32 {
+        self.model.senses.passive_perception
+    }
+}
+
+impl HasCreatureType for MonsterEntity<'_> {
+    fn creature_type(&self) -> CreatureType {
+        self.model.creature_type
+    }
+}
@@ -1,7 +1,8 @@
 use rs5e_components::{damages::Damages, identifiable::Identifiable, weapon::Weapon};
 use rs5e_concepts::{
     damage_source::DamageSource, damage_type::DamageType, id::Id, weapon::WeaponModel,
-    weapon_category::WeaponCategory, weapon_range::WeaponRange, weapon_type::WeaponType,
+    weapon_category::WeaponCategory, weapon_property::WeaponProperty, weapon_range::WeaponRange,
+    weapon_type::WeaponType,
 };
 use rs5e_dice::Dice;
 use rs5e_macro_derive::Identifiable;
@@ -38,4 +39,8 @@ impl Weapon for WeaponEntity<'_> {
     fn weapon_type(&self) -> &WeaponType {
         &self.model.weapon_type
     }
+
+    fn properties(&self) -> &[WeaponProperty] {
+        &self.model.properties
+    }
 }
@@ -2,9 +2,38 @@ use rs5e_concepts::{
     advantage_type::{AdvantageType, Disadvantage},
     armor_category::ArmorCategory,
     armor_proficiency_penalty::ArmorProficiencyPenalty,
+    bonus_damage::BonusDamageSource,
+    challenge_rating::ChallengeRating,
     class_type::ClassType,
+    colossus_slayer::ColossusSlayerDice,
+    condition::{Condition, ParalyzedTargetBonus},
     cover_state::{CanBeTargetedCoverBonus, CannotBeTargeted, CoverBonus, CoverState},
+    damage_resistance::DamageResistances,
+    damage_type::DamageType,
+    dark_ones_blessing::DarkOnesBlessing,
+    divine_smite::{DivineSmite, SmitePolicy},
+    divine_strike::DivineStrikeDice,
+    encounter::EncounterDifficulty,
+    fighting_style::{
+        AttackerFightingStyleBonus, DefenderFightingStyleBonus, FightingStyle, ProtectionPenalty,
+    },
+    hide::UnseenAttackerBonus,
+    improved_critical::{CriticalRange, DEFAULT_CRITICAL_RANGE},
+    martial_arts::MartialArts,
+    material::Material,
     prone_state::{ProneContext, ProneState},
+    rage::RageDamageBonus,
+    reckless_attack::{RecklessAttackBonus, RecklessTargetBonus},
+    sacred_weapon::SacredWeaponBonus,
+    sneak_attack::SneakAttackDice,
+    special_ability::{PackTacticsBonus, SpecialAbility, SunlightSensitivityPenalty},
+    spell::{AreaOfEffect, AreaOfEffectType, SpellAttackType},
+    spell_attack::SpellAttack,
+    spell_save::SpellSave,
+    spell_slots::{SpellSlots, MAX_SPELL_LEVEL},
+    spellcasting::Spellcasting,
+    unarmored_defense::{UnarmoredDefense, UnarmoredDefenseFormula},
+    usage::Usage,
     weapon_category::WeaponCategory,
     weapon_proficiency_bonus::WeaponProficiencyBonus,
     weapon_range::WeaponRange,
@@ -110,6 +139,20 @@ impl Describe for ClassType {
     }
 }
 
+impl Describe for Material {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self {
+            Self::Cloth => "cloth",
+            Self::Leather => "leather",
+            Self::Hide => "hide",
+            Self::Wood => "wood",
+            Self::Metal => "metal",
+            Self::Mithral => "mithral",
+            Self::Adamantine => "adamantine",
+        })
+    }
+}
+
 impl Describe for ProneState {
     fn describe(&self) -> Cow<str> {
         Cow::Borrowed(match self {
@@ -145,3 +188,414 @@ impl Describe for WeaponRange {
         })
     }
 }
+
+impl Describe for FightingStyle {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(self.name())
+    }
+}
+
+impl Describe for AttackerFightingStyleBonus {
+    fn describe(&self) -> Cow<str> {
+        match self {
+            Self::AttackRoll(bonus) => Cow::from(format!("+{bonus} to attack rolls")),
+            Self::Damage(bonus) => Cow::from(format!("+{bonus} to damage rolls")),
+            Self::DamageDieReroll { at_or_below } => Cow::from(format!(
+                "a reroll of damage dice that land on {at_or_below} or lower"
+            )),
+            Self::None => Cow::Borrowed("no bonus"),
+        }
+    }
+}
+
+impl Describe for DefenderFightingStyleBonus {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            Some(ac) => Cow::from(format!("+{} AC", ac.value())),
+            None => Cow::Borrowed("no bonus"),
+        }
+    }
+}
+
+impl Describe for ProtectionPenalty {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self.deref() {
+            None => "no penalty",
+            Some(Disadvantage) => "disadvantage",
+        })
+    }
+}
+
+impl Describe for DamageType {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self {
+            Self::Acid => "acid",
+            Self::Bludgeoning => "bludgeoning",
+            Self::Cold => "cold",
+            Self::Fire => "fire",
+            Self::Force => "force",
+            Self::Lightning => "lightning",
+            Self::Necrotic => "necrotic",
+            Self::Piercing => "piercing",
+            Self::Poison => "poison",
+            Self::Psychic => "psychic",
+            Self::Radiant => "radiant",
+            Self::Slashing => "slashing",
+            Self::Thunder => "thunder",
+        })
+    }
+}
+
+impl Describe for DamageResistances {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            [] => Cow::Borrowed("no damage resistances"),
+            damage_types => Cow::from(format!(
+                "resistance to {} damage",
+                damage_types
+                    .iter()
+                    .map(Describe::describe)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+}
+
+impl Describe for RageDamageBonus {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            Some(bonus) => Cow::from(format!("+{bonus} damage")),
+            None => Cow::Borrowed("no bonus damage"),
+        }
+    }
+}
+
+impl Describe for RecklessAttackBonus {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self.deref() {
+            Some(_) => "advantage",
+            None => "no bonus",
+        })
+    }
+}
+
+impl Describe for RecklessTargetBonus {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self.deref() {
+            Some(_) => "advantage",
+            None => "no bonus",
+        })
+    }
+}
+
+impl Describe for ParalyzedTargetBonus {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self.deref() {
+            Some(_) => "advantage and critical hits in melee",
+            None => "no bonus",
+        })
+    }
+}
+
+impl Describe for PackTacticsBonus {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self.deref() {
+            Some(_) => "advantage",
+            None => "no bonus",
+        })
+    }
+}
+
+impl Describe for SunlightSensitivityPenalty {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self.deref() {
+            Some(_) => "disadvantage",
+            None => "no penalty",
+        })
+    }
+}
+
+impl Describe for UnseenAttackerBonus {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self.deref() {
+            Some(_) => "advantage",
+            None => "no bonus",
+        })
+    }
+}
+
+impl Describe for SpecialAbility {
+    fn describe(&self) -> Cow<str> {
+        match self {
+            Self::PackTactics => Cow::Borrowed("Pack Tactics"),
+            Self::MagicResistance => Cow::Borrowed("Magic Resistance"),
+            Self::Regeneration(regeneration) => {
+                Cow::Owned(format!("Regeneration of {} HP", regeneration.hit_points))
+            }
+            Self::SunlightSensitivity => Cow::Borrowed("Sunlight Sensitivity"),
+            Self::UndeadFortitude => Cow::Borrowed("Undead Fortitude"),
+            Self::NimbleEscape => Cow::Borrowed("Nimble Escape"),
+            Self::KeenSenses => Cow::Borrowed("Keen Senses"),
+            Self::Brute => Cow::Borrowed("Brute, already included in your attacks"),
+            Self::LegendaryResistance(times) => {
+                Cow::Owned(format!("Legendary Resistance ({times}/Day)"))
+            }
+            Self::NotImplemented(name) => Cow::Borrowed(name),
+        }
+    }
+}
+
+impl Describe for Usage {
+    fn describe(&self) -> Cow<str> {
+        match self {
+            Self::RechargeOnRoll(recharge) if recharge.min_value >= recharge.dice.max_value() => {
+                Cow::Owned(format!("Recharge {}", recharge.min_value))
+            }
+            Self::RechargeOnRoll(recharge) => Cow::Owned(format!(
+                "Recharge {}–{}",
+                recharge.min_value,
+                recharge.dice.max_value()
+            )),
+            Self::PerDay(times) => Cow::Owned(format!("{times}/Day")),
+            Self::RechargeAfterRest => Cow::Borrowed("Recharges after a Rest"),
+        }
+    }
+}
+
+impl Describe for ChallengeRating {
+    fn describe(&self) -> Cow<str> {
+        match self {
+            Self::Eighth => Cow::Borrowed("1/8"),
+            Self::Quarter => Cow::Borrowed("1/4"),
+            Self::Half => Cow::Borrowed("1/2"),
+            Self::Whole(rating) => Cow::Owned(rating.to_string()),
+        }
+    }
+}
+
+impl Describe for EncounterDifficulty {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self {
+            Self::Trivial => "trivial",
+            Self::Easy => "easy",
+            Self::Medium => "medium",
+            Self::Hard => "hard",
+            Self::Deadly => "deadly",
+        })
+    }
+}
+
+impl Describe for Condition {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self {
+            Self::Paralyzed => "paralyzed",
+        })
+    }
+}
+
+impl Describe for SneakAttackDice {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            Some(dice) => Cow::from(format!("{dice} Sneak Attack damage once per turn")),
+            None => Cow::Borrowed("no Sneak Attack damage"),
+        }
+    }
+}
+
+impl Describe for BonusDamageSource {
+    fn describe(&self) -> Cow<str> {
+        match self {
+            Self::SneakAttack => Cow::Borrowed("Sneak Attack"),
+            Self::DivineSmite { slot_level } => Cow::from(format!(
+                "Divine Smite with a {}-level spell slot",
+                ordinal(*slot_level)
+            )),
+            Self::ColossusSlayer => Cow::Borrowed("Colossus Slayer"),
+            Self::DivineStrike => Cow::Borrowed("Divine Strike"),
+            Self::AdditionalDamage => Cow::Borrowed("Additional damage"),
+        }
+    }
+}
+
+impl Describe for DivineSmite {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self.deref() {
+            Some(SmitePolicy::CriticalsOnly) => "Divine Smite on critical hits",
+            Some(SmitePolicy::Always) => "Divine Smite on every hit",
+            Some(SmitePolicy::Never) | None => "no Divine Smite",
+        })
+    }
+}
+
+impl Describe for MartialArts {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            Some(bonus) => Cow::from(format!("a {} martial arts die", bonus.martial_arts_dice)),
+            None => Cow::Borrowed("no martial arts die"),
+        }
+    }
+}
+
+impl Describe for UnarmoredDefenseFormula {
+    fn describe(&self) -> Cow<str> {
+        Cow::Borrowed(match self {
+            Self::Default => "the default unarmored AC",
+            Self::Barbarian => "Barbarian Unarmored Defense (10 + DEX + CON)",
+            Self::Monk => "Monk Unarmored Defense (10 + DEX + WIS)",
+            Self::DraconicResilience => "Draconic Resilience (13 + DEX)",
+        })
+    }
+}
+
+impl Describe for UnarmoredDefense {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            Some(unarmored) => Cow::from(format!(
+                "{} AC from {}, the highest unarmored formula available",
+                unarmored.armor_class.value(),
+                unarmored.formula.describe()
+            )),
+            None => Cow::Borrowed("armor instead of an unarmored AC"),
+        }
+    }
+}
+
+impl Describe for CriticalRange {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            &DEFAULT_CRITICAL_RANGE => Cow::Borrowed("critical hits only on a 20"),
+            critical_range => Cow::from(format!(
+                "critical hits on a roll of {critical_range} or higher from Improved Critical"
+            )),
+        }
+    }
+}
+
+impl Describe for SacredWeaponBonus {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            Some(bonus) => Cow::from(format!("+{bonus} to attack rolls from Sacred Weapon")),
+            None => Cow::Borrowed("no Sacred Weapon"),
+        }
+    }
+}
+
+impl Describe for ColossusSlayerDice {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            Some(dice) => Cow::from(format!("{dice} Colossus Slayer damage once per turn")),
+            None => Cow::Borrowed("no Colossus Slayer damage"),
+        }
+    }
+}
+
+impl Describe for DivineStrikeDice {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            Some(dice) => Cow::from(format!("{dice} Radiant Divine Strike damage once per turn")),
+            None => Cow::Borrowed("no Divine Strike damage"),
+        }
+    }
+}
+
+impl Describe for DarkOnesBlessing {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            Some(temporary_hp) => Cow::from(format!(
+                "{temporary_hp} temporary HP from Dark One's Blessing if your target drops"
+            )),
+            None => Cow::Borrowed("no Dark One's Blessing"),
+        }
+    }
+}
+
+impl Describe for Spellcasting {
+    fn describe(&self) -> Cow<str> {
+        match self.deref() {
+            Some(stats) => Cow::from(format!(
+                "{:?} as your spellcasting ability, a {:+} spell attack bonus and a spell save DC of {}",
+                stats.ability, stats.attack_bonus, stats.save_dc
+            )),
+            None => Cow::Borrowed("no spellcasting ability"),
+        }
+    }
+}
+
+impl Describe for SpellSlots {
+    fn describe(&self) -> Cow<str> {
+        let slots = (1..=MAX_SPELL_LEVEL)
+            .filter(|slot_level| self.maximum(*slot_level) > 0)
+            .map(|slot_level| {
+                format!(
+                    "{} of {} {} level",
+                    self.remaining(slot_level),
+                    self.maximum(slot_level),
+                    ordinal(slot_level)
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if slots.is_empty() {
+            Cow::Borrowed("no spell slots")
+        } else {
+            Cow::from(format!("{} spell slots remaining", slots.join(", ")))
+        }
+    }
+}
+
+impl Describe for SpellAttack<'_> {
+    fn describe(&self) -> Cow<str> {
+        let attack_type = match self.attack_type {
+            SpellAttackType::Melee => "melee",
+            SpellAttackType::Ranged => "ranged",
+        };
+
+        match self.slot_level {
+            Some(slot_level) => Cow::from(format!(
+                "a {attack_type} spell attack using a {} level slot",
+                ordinal(slot_level)
+            )),
+            None => Cow::from(format!("a {attack_type} spell attack")),
+        }
+    }
+}
+
+impl Describe for SpellSave<'_> {
+    fn describe(&self) -> Cow<str> {
+        match self.slot_level {
+            Some(slot_level) => Cow::from(format!(
+                "{} using a {} level slot",
+                self.spell.name,
+                ordinal(slot_level)
+            )),
+            None => Cow::Borrowed(&self.spell.name),
+        }
+    }
+}
+
+impl Describe for AreaOfEffect {
+    fn describe(&self) -> Cow<str> {
+        let area_type = match self.area_type {
+            AreaOfEffectType::Cone => "cone",
+            AreaOfEffectType::Cube => "cube",
+            AreaOfEffectType::Cylinder => "radius cylinder",
+            AreaOfEffectType::Line => "line",
+            AreaOfEffectType::Sphere => "radius sphere",
+        };
+
+        Cow::from(format!("{}-foot {area_type}", self.size))
+    }
+}
+
+fn ordinal(number: u32) -> String {
+    let suffix = match (number % 10, number % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{number}{suffix}")
+}
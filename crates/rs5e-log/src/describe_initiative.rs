@@ -0,0 +1,45 @@
+use rs5e_concepts::roll::Roll;
+use rs5e_systems::initiative::InitiativeOutcome;
+use serde::Serialize;
+use typeshare::typeshare;
+
+/// One entry per combatant, in turn order
+#[typeshare]
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeSummary {
+    name: String,
+    total: u32,
+    roll: String,
+}
+
+impl From<&InitiativeOutcome> for InitiativeSummary {
+    fn from(outcome: &InitiativeOutcome) -> Self {
+        let initiative = &outcome.initiative;
+
+        // Feral Instinct rolls initiative with advantage
+        let advantage_phrase = initiative
+            .discarded_raw_value
+            .map_or(String::new(), |discarded| {
+                format!(" with Feral Instinct, discarding {discarded}")
+            });
+        let tiebreaker_phrase = initiative.tiebreaker.map_or(String::new(), |tiebreaker| {
+            format!(
+                " Tied on initiative and Dexterity, {} rolls {tiebreaker} to break the tie.",
+                outcome.name
+            )
+        });
+
+        Self {
+            name: outcome.name.clone(),
+            total: initiative.total_value(),
+            roll: format!(
+                "{} rolls {} ({} + {}) for initiative{advantage_phrase}.{tiebreaker_phrase}",
+                outcome.name,
+                initiative.total_value(),
+                initiative.raw_value,
+                initiative.dexterity_modifier.value()
+            ),
+        }
+    }
+}
@@ -1,5 +1,11 @@
-use crate::{describe::Describe, describe_physical_attack_outcome::PhysicalAttackOutcomeSummary};
-use rs5e_concepts::weapon_ability_modifier::AbilityModifierContext;
+use crate::{
+    describe::Describe, describe_physical_attack_outcome::PhysicalAttackOutcomeSummary,
+    describe_spell_save_outcome::SpellSaveOutcomeSummary,
+};
+use rs5e_concepts::{
+    condition::Condition, fighting_style::FightingStyle, special_ability::SpecialAbility,
+    weapon_ability_modifier::AbilityModifierContext,
+};
 use rs5e_systems::physical_attack::PhysicalAttackStateAndContext;
 use serde::Serialize;
 use std::{borrow::Cow, ops::Deref};
@@ -14,6 +20,10 @@ pub enum PhysicalAttackAttemptSummary {
         // before: Box<PhysicalAttackSummary>,
         after: Box<PhysicalAttackOutcomeSummary>,
     },
+    /// A spell resisted with saving throws instead of an attack roll
+    SavingThrow {
+        after: Box<SpellSaveOutcomeSummary>,
+    },
 }
 
 #[typeshare]
@@ -25,7 +35,31 @@ pub struct PhysicalAttackSummary {
     attacker_weapon_proficiency_bonus: String,
     attacker_armor_proficiency_penalty: String,
     attacker_weapon_ability_modifier: String,
+    attacker_fighting_style_bonus: String,
+    defender_fighting_style_bonus: String,
+    defender_protecting_ally: String,
     attacker_combined_advantage_type: String,
+    attacker_attacks_per_turn: String,
+    attacker_rage: String,
+    attacker_reckless_attack: String,
+    defender_reckless_attack: String,
+    defender_paralyzed: String,
+    attacker_special_abilities: String,
+    attacker_pack_tactics: String,
+    attacker_sunlight_sensitivity: String,
+    attacker_hidden: String,
+    attacker_sneak_attack: String,
+    attacker_divine_smite: String,
+    attacker_martial_arts: String,
+    attacker_off_hand_weapon: String,
+    attacker_critical_range: String,
+    attacker_sacred_weapon: String,
+    attacker_colossus_slayer: String,
+    attacker_divine_strike: String,
+    attacker_dark_ones_blessing: String,
+    attacker_spellcasting: String,
+    defender_unarmored_defense: String,
+    defender_damage_resistances: String,
 }
 
 impl From<&PhysicalAttackStateAndContext<'_>> for PhysicalAttackSummary {
@@ -57,6 +91,17 @@ impl From<&PhysicalAttackStateAndContext<'_>> for PhysicalAttackSummary {
             )
         }
 
+        fn describe_attacker_class<'a>(
+            state_and_context: &'a PhysicalAttackStateAndContext,
+        ) -> Cow<'a, str> {
+            state_and_context
+                .state
+                .attacker
+                .class
+                .as_ref()
+                .map_or(Cow::from("a monster"), Describe::describe)
+        }
+
         fn describe_attacker_weapon_proficiency_bonus(
             state_and_context: &PhysicalAttackStateAndContext,
         ) -> String {
@@ -64,19 +109,22 @@ impl From<&PhysicalAttackStateAndContext<'_>> for PhysicalAttackSummary {
                 Some(weapon) => Cow::from(format!(
                     "using {} as {}",
                     weapon.weapon_category.describe(),
-                    state_and_context.state.attacker.class.describe()
+                    describe_attacker_class(state_and_context)
                 )),
                 None => Cow::from("attacking unarmed"),
             };
 
-            let level_phrase = match state_and_context
-                .context
-                .attacker
-                .weapon_proficiency_bonus
-                .deref()
-            {
-                None => Cow::from(""),
-                Some(_) => Cow::from(format!(
+            // Monsters gain proficiency with their challenge rating instead
+            let level_phrase = match (
+                state_and_context
+                    .context
+                    .attacker
+                    .weapon_proficiency_bonus
+                    .deref(),
+                state_and_context.state.attacker.class.as_ref(),
+            ) {
+                (None, _) | (_, None) => Cow::from(""),
+                (Some(_), Some(_)) => Cow::from(format!(
                     " at level {}",
                     state_and_context.state.attacker.level.deref()
                 )),
@@ -98,7 +146,7 @@ impl From<&PhysicalAttackStateAndContext<'_>> for PhysicalAttackSummary {
                 Some(armor) => Cow::from(format!(
                     "wearing {} as {}",
                     armor.armor_category.describe(),
-                    state_and_context.state.attacker.class.describe()
+                    describe_attacker_class(state_and_context)
                 )),
                 None => Cow::from("being unarmoured"),
             };
@@ -134,6 +182,61 @@ impl From<&PhysicalAttackStateAndContext<'_>> for PhysicalAttackSummary {
             format!("You have a {sign}{ability_modifier_value} ability modifier for attacking {weapon_phrase} at {ability_score_value} {ability_type:?}.")
         }
 
+        fn describe_attacker_fighting_style_bonus(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let fighting_style_phrase = state_and_context
+                .state
+                .attacker
+                .fighting_style
+                .as_ref()
+                .map_or(Cow::from("a fighting style"), Describe::describe);
+
+            let fighting_style_bonus_phrase = state_and_context
+                .context
+                .attacker
+                .fighting_style_bonus
+                .describe();
+
+            format!("You have {fighting_style_bonus_phrase} from {fighting_style_phrase}.")
+        }
+
+        fn describe_defender_fighting_style_bonus(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let fighting_style_phrase = state_and_context
+                .state
+                .defender
+                .fighting_style
+                .as_ref()
+                .map_or(Cow::from("a fighting style"), Describe::describe);
+
+            let fighting_style_bonus_phrase = state_and_context
+                .context
+                .defender
+                .fighting_style_armor_class_bonus
+                .describe();
+
+            format!("Your target has {fighting_style_bonus_phrase} from {fighting_style_phrase}.")
+        }
+
+        fn describe_defender_protecting_ally(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            match state_and_context.state.defender.protecting_ally.as_ref() {
+                Some(_) => {
+                    let protection_penalty_phrase = state_and_context
+                        .context
+                        .attacker
+                        .protection_penalty
+                        .describe();
+
+                    format!("Your target's ally imposes {protection_penalty_phrase} on you with Protection.")
+                }
+                None => "Your target has no ally close enough to protect them.".to_string(),
+            }
+        }
+
         fn describe_attacker_combined_advantage_type(
             state_and_context: &PhysicalAttackStateAndContext,
         ) -> String {
@@ -146,13 +249,399 @@ impl From<&PhysicalAttackStateAndContext<'_>> for PhysicalAttackSummary {
             format!("You will roll with {advantage_type_phrase}.")
         }
 
+        fn describe_attacker_attacks_per_turn(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            match *state_and_context.context.attacker.attacks_per_turn.deref() {
+                1 => "You make 1 attack per turn.".to_string(),
+                attacks_per_turn => format!(
+                    "You make {attacks_per_turn} attacks per turn from Extra Attack as {} at level {}.",
+                    describe_attacker_class(state_and_context),
+                    state_and_context.state.attacker.level.deref()
+                ),
+            }
+        }
+
+        fn describe_attacker_rage(state_and_context: &PhysicalAttackStateAndContext) -> String {
+            let rage_damage_bonus_phrase = state_and_context
+                .context
+                .attacker
+                .rage_damage_bonus
+                .describe();
+
+            let rage_phrase = if state_and_context.state.attacker.raging {
+                "raging"
+            } else {
+                "not raging"
+            };
+
+            format!("You have {rage_damage_bonus_phrase} for {rage_phrase}.")
+        }
+
+        fn describe_attacker_reckless_attack(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let reckless_attack_bonus_phrase = state_and_context
+                .context
+                .attacker
+                .reckless_attack_bonus
+                .describe();
+
+            let reckless_attack_phrase = if state_and_context.state.attacker.reckless_attack {
+                "attacking recklessly"
+            } else {
+                "attacking carefully"
+            };
+
+            format!("You have {reckless_attack_bonus_phrase} for {reckless_attack_phrase}.")
+        }
+
+        fn describe_defender_reckless_attack(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let reckless_target_bonus_phrase = state_and_context
+                .context
+                .attacker
+                .reckless_target_bonus
+                .describe();
+
+            let reckless_target_phrase = if state_and_context.state.defender.attacked_recklessly {
+                "your target attacking recklessly"
+            } else {
+                "your target attacking carefully"
+            };
+
+            format!("You have {reckless_target_bonus_phrase} for {reckless_target_phrase}.")
+        }
+
+        fn describe_defender_paralyzed(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let paralyzed_target_bonus_phrase = state_and_context
+                .context
+                .attacker
+                .paralyzed_target_bonus
+                .describe();
+
+            let paralyzed_phrase = if state_and_context
+                .state
+                .defender
+                .conditions
+                .contains(&Condition::Paralyzed)
+            {
+                "your target being paralyzed"
+            } else {
+                "your target being able to move"
+            };
+
+            format!("You have {paralyzed_target_bonus_phrase} for {paralyzed_phrase}.")
+        }
+
+        fn describe_attacker_special_abilities(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let (implemented, not_implemented): (Vec<_>, Vec<_>) = state_and_context
+                .state
+                .attacker
+                .special_abilities
+                .iter()
+                .partition(|special_ability| special_ability.is_implemented());
+
+            let join = |special_abilities: Vec<&SpecialAbility>| {
+                special_abilities
+                    .iter()
+                    .map(|special_ability| special_ability.describe())
+                    .collect::<Vec<_>>()
+                    .join(" and ")
+            };
+
+            let implemented_phrase = if implemented.is_empty() {
+                "You have no special traits.".to_string()
+            } else {
+                format!("You have {}.", join(implemented))
+            };
+
+            match not_implemented.len() {
+                0 => implemented_phrase,
+                1 => format!(
+                    "{implemented_phrase} {} is not implemented.",
+                    join(not_implemented)
+                ),
+                _ => format!(
+                    "{implemented_phrase} {} are not implemented.",
+                    join(not_implemented)
+                ),
+            }
+        }
+
+        fn describe_attacker_pack_tactics(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let pack_tactics_bonus_phrase = state_and_context
+                .context
+                .attacker
+                .pack_tactics_bonus
+                .describe();
+
+            let ally_phrase = if state_and_context.state.attacker.ally_adjacent_to_target {
+                "an ally beside your target"
+            } else {
+                "no ally beside your target"
+            };
+
+            format!(
+                "You have {pack_tactics_bonus_phrase} from Pack Tactics for having {ally_phrase}."
+            )
+        }
+
+        fn describe_attacker_sunlight_sensitivity(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let sunlight_sensitivity_penalty_phrase = state_and_context
+                .context
+                .attacker
+                .sunlight_sensitivity_penalty
+                .describe();
+
+            let sunlight_phrase = if state_and_context.state.attacker.in_sunlight {
+                "fighting in sunlight"
+            } else {
+                "fighting out of sunlight"
+            };
+
+            format!("You have {sunlight_sensitivity_penalty_phrase} from Sunlight Sensitivity for {sunlight_phrase}.")
+        }
+
+        fn describe_attacker_hidden(state_and_context: &PhysicalAttackStateAndContext) -> String {
+            let unseen_attacker_bonus_phrase = state_and_context
+                .context
+                .attacker
+                .unseen_attacker_bonus
+                .describe();
+
+            let hidden_phrase = if state_and_context.state.attacker.hidden {
+                "being hidden from your target"
+            } else {
+                "being seen by your target"
+            };
+
+            format!("You have {unseen_attacker_bonus_phrase} for {hidden_phrase}.")
+        }
+
+        fn describe_attacker_sneak_attack(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let sneak_attack_dice_phrase = state_and_context
+                .context
+                .attacker
+                .sneak_attack_dice
+                .describe();
+
+            let ally_phrase = if state_and_context.state.attacker.ally_adjacent_to_target {
+                " with an ally beside your target"
+            } else {
+                ""
+            };
+
+            format!("You deal {sneak_attack_dice_phrase}{ally_phrase}.")
+        }
+
+        fn describe_attacker_divine_smite(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let divine_smite_phrase = state_and_context.context.attacker.divine_smite.describe();
+
+            match state_and_context.state.attacker.spell_slots.as_ref() {
+                Some(spell_slots) if state_and_context.context.attacker.divine_smite.is_some() => {
+                    format!(
+                        "You use {divine_smite_phrase} with {} spell slots remaining.",
+                        spell_slots.total_remaining()
+                    )
+                }
+                _ => format!("You use {divine_smite_phrase}."),
+            }
+        }
+
+        fn describe_attacker_martial_arts(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let martial_arts_phrase = state_and_context.context.attacker.martial_arts.describe();
+
+            match state_and_context.state.attacker.ki_points {
+                Some(ki_points) => format!(
+                    "You have {martial_arts_phrase} using {:?} with {ki_points} ki points remaining.",
+                    state_and_context
+                        .context
+                        .attacker
+                        .ability_modifier_context
+                        .ability_type
+                ),
+                None => format!("You have {martial_arts_phrase}."),
+            }
+        }
+
+        fn describe_attacker_off_hand_weapon(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            match state_and_context.state.attacker.off_hand_weapon {
+                Some(off_hand_weapon) => {
+                    let damage_phrase = if state_and_context.state.attacker.fighting_style
+                        == Some(FightingStyle::TwoWeaponFighting)
+                    {
+                        "adding your ability modifier to its damage with the Two-Weapon Fighting style"
+                    } else {
+                        "leaving a positive ability modifier off its damage"
+                    };
+
+                    format!(
+                        "You hold {} in your off hand for a bonus action attack, {damage_phrase}.",
+                        off_hand_weapon.weapon_type.name()
+                    )
+                }
+                None => "You have no off-hand weapon.".to_string(),
+            }
+        }
+
+        fn describe_attacker_critical_range(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let critical_range_phrase =
+                state_and_context.context.attacker.critical_range.describe();
+
+            format!("You score {critical_range_phrase}.")
+        }
+
+        fn describe_attacker_sacred_weapon(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let sacred_weapon_phrase = state_and_context
+                .context
+                .attacker
+                .sacred_weapon_bonus
+                .describe();
+
+            format!("You have {sacred_weapon_phrase}.")
+        }
+
+        fn describe_attacker_colossus_slayer(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let colossus_slayer_phrase = state_and_context
+                .context
+                .attacker
+                .colossus_slayer_dice
+                .describe();
+
+            let wounded_phrase = if state_and_context.state.defender.below_hp_max {
+                " against your wounded target"
+            } else {
+                ""
+            };
+
+            format!("You deal {colossus_slayer_phrase}{wounded_phrase}.")
+        }
+
+        fn describe_attacker_divine_strike(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let divine_strike_phrase = state_and_context
+                .context
+                .attacker
+                .divine_strike_dice
+                .describe();
+
+            format!("You deal {divine_strike_phrase}.")
+        }
+
+        fn describe_attacker_dark_ones_blessing(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let dark_ones_blessing_phrase = state_and_context
+                .context
+                .attacker
+                .dark_ones_blessing
+                .describe();
+
+            format!("You gain {dark_ones_blessing_phrase}.")
+        }
+
+        fn describe_attacker_spellcasting(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let spellcasting_phrase = state_and_context.context.attacker.spellcasting.describe();
+
+            match state_and_context.state.attacker.spell_slots.as_ref() {
+                Some(spell_slots) if state_and_context.context.attacker.spellcasting.is_some() => {
+                    format!(
+                        "You have {spellcasting_phrase}, with {}.",
+                        spell_slots.describe()
+                    )
+                }
+                _ => format!("You have {spellcasting_phrase}."),
+            }
+        }
+
+        fn describe_defender_unarmored_defense(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let unarmored_defense_phrase = state_and_context
+                .context
+                .defender
+                .unarmored_defense
+                .describe();
+
+            format!("Your target has {unarmored_defense_phrase}.")
+        }
+
+        fn describe_defender_damage_resistances(
+            state_and_context: &PhysicalAttackStateAndContext,
+        ) -> String {
+            let damage_resistances_phrase = state_and_context
+                .context
+                .defender
+                .damage_resistances
+                .describe();
+
+            let rage_phrase = if state_and_context.state.defender.raging {
+                " for raging"
+            } else {
+                ""
+            };
+
+            format!("Your target has {damage_resistances_phrase}{rage_phrase}.")
+        }
+
         Self {
             attacker_prone_context_bonus: describe_attacker_prone_context_bonus(value),
             defender_bonus_fom_cover_state: describe_defender_bonus_fom_cover_state(value),
             attacker_weapon_proficiency_bonus: describe_attacker_weapon_proficiency_bonus(value),
             attacker_armor_proficiency_penalty: describe_attacker_armor_proficiency_penalty(value),
             attacker_weapon_ability_modifier: describe_attacker_weapon_ability_modifier(value),
+            attacker_fighting_style_bonus: describe_attacker_fighting_style_bonus(value),
+            defender_fighting_style_bonus: describe_defender_fighting_style_bonus(value),
+            defender_protecting_ally: describe_defender_protecting_ally(value),
             attacker_combined_advantage_type: describe_attacker_combined_advantage_type(value),
+            attacker_attacks_per_turn: describe_attacker_attacks_per_turn(value),
+            attacker_rage: describe_attacker_rage(value),
+            attacker_reckless_attack: describe_attacker_reckless_attack(value),
+            defender_reckless_attack: describe_defender_reckless_attack(value),
+            defender_paralyzed: describe_defender_paralyzed(value),
+            attacker_special_abilities: describe_attacker_special_abilities(value),
+            attacker_pack_tactics: describe_attacker_pack_tactics(value),
+            attacker_sunlight_sensitivity: describe_attacker_sunlight_sensitivity(value),
+            attacker_hidden: describe_attacker_hidden(value),
+            attacker_sneak_attack: describe_attacker_sneak_attack(value),
+            attacker_divine_smite: describe_attacker_divine_smite(value),
+            attacker_martial_arts: describe_attacker_martial_arts(value),
+            attacker_off_hand_weapon: describe_attacker_off_hand_weapon(value),
+            attacker_critical_range: describe_attacker_critical_range(value),
+            attacker_sacred_weapon: describe_attacker_sacred_weapon(value),
+            attacker_colossus_slayer: describe_attacker_colossus_slayer(value),
+            attacker_divine_strike: describe_attacker_divine_strike(value),
+            attacker_dark_ones_blessing: describe_attacker_dark_ones_blessing(value),
+            attacker_spellcasting: describe_attacker_spellcasting(value),
+            defender_unarmored_defense: describe_defender_unarmored_defense(value),
+            defender_damage_resistances: describe_defender_damage_resistances(value),
         }
     }
 }
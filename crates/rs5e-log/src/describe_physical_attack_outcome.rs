@@ -1,4 +1,9 @@
-use rs5e_concepts::{damage_roll::DamageRollType, hit::Hit, roll::Roll, roll_type::RollType};
+use crate::describe::Describe;
+use rs5e_concepts::{
+    bonus_damage::BonusDamageRoll, damage_roll::DamageRollType, hit::Hit,
+    improved_critical::DEFAULT_CRITICAL_RANGE, roll::Roll, roll_type::RollType,
+    two_weapon_fighting::WeaponHand,
+};
 use rs5e_systems::physical_attack::PhysicalAttackOutcome;
 use serde::Serialize;
 use std::ops::Deref;
@@ -12,6 +17,8 @@ pub struct PhysicalAttackOutcomeSummary {
     attack_roll: String,
     target_ac: String,
     damage_roll: Option<String>,
+    /// One line per source of extra dice, such as Sneak Attack
+    bonus_damage_rolls: Vec<String>,
     hit: String,
     hp_change: Option<String>,
 }
@@ -19,37 +26,101 @@ pub struct PhysicalAttackOutcomeSummary {
 impl From<&PhysicalAttackOutcome<'_>> for PhysicalAttackOutcomeSummary {
     fn from(outcome: &PhysicalAttackOutcome<'_>) -> Self {
         // Attack description (weapon vs unarmed)
-        let attack = match outcome.state_and_context.state.attacker.weapon {
-            Some(attacker_weapon) => {
+        let hand_phrase = match outcome.state_and_context.state.attacker.hand {
+            WeaponHand::MainHand => "",
+            WeaponHand::OffHand => " in the off hand",
+        };
+
+        let attack = match (
+            outcome.spell_attack,
+            outcome.monster_attack,
+            outcome.state_and_context.state.attacker.weapon,
+        ) {
+            (_, Some(monster_attack), _) => format!(
+                "{} attacks {} with {}.",
+                "Attacker", "Defender", monster_attack.name
+            ),
+            (Some(spell_attack), _, _) => format!(
+                "{} casts {} at {} as {}.",
+                "Attacker",
+                spell_attack.spell.name,
+                "Defender",
+                spell_attack.describe()
+            ),
+            (None, None, Some(attacker_weapon)) => {
                 format!(
-                    "{} attacks {} with {}.",
+                    "{} attacks {} with {}{hand_phrase}.",
                     "Attacker",
                     "Defender",
                     attacker_weapon.weapon_type.name()
                 )
             }
-            None => {
+            (None, None, None) => {
                 format!("{} attacks {} unarmed.", "Attacker", "Defender",)
             }
         };
 
-        let weapon_proficiency_bonus = outcome
-            .state_and_context
-            .context
-            .attacker
-            .weapon_proficiency_bonus
-            .deref()
-            .as_ref()
-            .map(|b| b.value())
-            .unwrap_or(0);
+        // Spell attacks use the spellcasting ability and always add proficiency
+        let spellcasting = outcome.spell_attack.and(
+            outcome
+                .state_and_context
+                .context
+                .attacker
+                .spellcasting
+                .as_ref(),
+        );
 
-        let ability_modifier = outcome
-            .state_and_context
-            .context
-            .attacker
-            .ability_modifier_context
-            .ability_modifier
-            .value();
+        let weapon_proficiency_bonus = match spellcasting {
+            Some(stats) => stats.proficiency_bonus.value(),
+            None => outcome
+                .state_and_context
+                .context
+                .attacker
+                .weapon_proficiency_bonus
+                .deref()
+                .as_ref()
+                .map(|b| b.value())
+                .unwrap_or(0),
+        };
+
+        let ability_modifier = match spellcasting {
+            Some(stats) => stats.ability_modifier.value(),
+            None => outcome
+                .state_and_context
+                .context
+                .attacker
+                .ability_modifier_context
+                .ability_modifier
+                .value(),
+        };
+
+        let attack_bonus_phrase = [
+            outcome
+                .state_and_context
+                .context
+                .attacker
+                .fighting_style_bonus
+                .attack_roll_bonus(),
+            outcome
+                .state_and_context
+                .context
+                .attacker
+                .sacred_weapon_bonus
+                .unwrap_or(0),
+        ]
+        .into_iter()
+        .filter(|bonus| *bonus != 0 && outcome.spell_attack.is_none())
+        .map(|bonus| format!(" + {bonus}"))
+        .collect::<String>();
+
+        // A monster's to-hit bonus already includes its ability modifier and
+        // proficiency bonus
+        let roll_breakdown = |raw_value| match outcome.monster_attack {
+            Some(monster_attack) => format!("{raw_value} + {}", monster_attack.attack_bonus),
+            None => format!(
+                "{raw_value} + {ability_modifier} + {weapon_proficiency_bonus}{attack_bonus_phrase}"
+            ),
+        };
 
         let attack_roll = match &outcome.attack_roll_type {
             RollType::Advantage {
@@ -57,25 +128,19 @@ impl From<&PhysicalAttackOutcome<'_>> for PhysicalAttackOutcomeSummary {
                 discarded_roll,
             } => {
                 format!(
-                     "Attack rolled with advantage lands on {} ({} + {} + {}) and {} ({} + {} + {}). Higher roll {} is taken.",
+                     "Attack rolled with advantage lands on {} ({}) and {} ({}). Higher roll {} is taken.",
                      chosen_roll.total_value(),
-                     chosen_roll.raw_value(),
-                     ability_modifier,
-                     weapon_proficiency_bonus,
+                     roll_breakdown(chosen_roll.raw_value()),
                      discarded_roll.total_value(),
-                     discarded_roll.raw_value(),
-                     ability_modifier,
-                     weapon_proficiency_bonus,
+                     roll_breakdown(discarded_roll.raw_value()),
                      chosen_roll.total_value(),
                  )
             }
             RollType::Normal { roll } => {
                 format!(
-                    "Attack roll lands on {} ({} + {} + {}).",
+                    "Attack roll lands on {} ({}).",
                     roll.total_value(),
-                    roll.raw_value(),
-                    ability_modifier,
-                    weapon_proficiency_bonus
+                    roll_breakdown(roll.raw_value())
                 )
             }
             RollType::Disadvantage {
@@ -83,26 +148,49 @@ impl From<&PhysicalAttackOutcome<'_>> for PhysicalAttackOutcomeSummary {
                 discarded_roll,
             } => {
                 format!(
-                    "Attack rolled with disadvantage lands on {} ({} + {} + {}) and {} ({} + {} + {}). Lower roll {} is taken.",
+                    "Attack rolled with disadvantage lands on {} ({}) and {} ({}). Lower roll {} is taken.",
                     chosen_roll.total_value(),
-                    chosen_roll.raw_value(),
-                    ability_modifier,
-                    weapon_proficiency_bonus,
+                    roll_breakdown(chosen_roll.raw_value()),
                     discarded_roll.total_value(),
-                    discarded_roll.raw_value(),
-                    ability_modifier,
-                    weapon_proficiency_bonus,
+                    roll_breakdown(discarded_roll.raw_value()),
                     chosen_roll.total_value()
                 )
             }
         };
 
+        let defense_bonus_phrase = match outcome
+            .state_and_context
+            .context
+            .defender
+            .fighting_style_armor_class_bonus
+            .deref()
+        {
+            Some(ac) => format!("+{}", ac.value()),
+            None => String::new(),
+        };
+
+        let unarmored_defense_phrase = match outcome
+            .state_and_context
+            .context
+            .defender
+            .unarmored_defense
+            .deref()
+        {
+            Some(unarmored) => format!(
+                ", with {} from {}",
+                unarmored.armor_class.value(),
+                unarmored.formula.describe()
+            ),
+            None => String::new(),
+        };
+
         // Target AC
         let target_ac = match outcome.state_and_context.state.defender.equipped_armor {
             Some(armor) => {
                 format!(
-                    "Target's AC wearing {} is {} ({}+{}).",
+                    "Target's AC wearing {} ({}) is {} ({}+{}{}){}.",
                     armor.armor_type.name(),
+                    armor.primary_material.describe(),
                     outcome
                         .state_and_context
                         .context
@@ -121,11 +209,13 @@ impl From<&PhysicalAttackOutcome<'_>> for PhysicalAttackOutcomeSummary {
                         .defender
                         .cover_state_armor_class_bonus
                         .value(),
+                    defense_bonus_phrase,
+                    unarmored_defense_phrase,
                 )
             }
             None => {
                 format!(
-                    "Target's AC with no armor is {} ({}+{}).",
+                    "Target's AC with no armor is {} ({}+{}{}){}.",
                     outcome
                         .state_and_context
                         .context
@@ -136,7 +226,7 @@ impl From<&PhysicalAttackOutcome<'_>> for PhysicalAttackOutcomeSummary {
                         .state_and_context
                         .context
                         .defender
-                        .cover_state_armor_class_bonus
+                        .base_armor_class
                         .value(),
                     outcome
                         .state_and_context
@@ -144,6 +234,8 @@ impl From<&PhysicalAttackOutcome<'_>> for PhysicalAttackOutcomeSummary {
                         .defender
                         .cover_state_armor_class_bonus
                         .value(),
+                    defense_bonus_phrase,
+                    unarmored_defense_phrase,
                 )
             }
         };
@@ -163,60 +255,174 @@ impl From<&PhysicalAttackOutcome<'_>> for PhysicalAttackOutcomeSummary {
         //         )
         //     }
 
-        let (damage_roll, hit, hp_change) = match &outcome.hit {
+        let damage_bonus_phrase = [
+            outcome
+                .state_and_context
+                .context
+                .attacker
+                .fighting_style_bonus
+                .damage_bonus(),
+            outcome
+                .state_and_context
+                .context
+                .attacker
+                .rage_damage_bonus
+                .unwrap_or(0),
+            outcome
+                .monster_attack
+                .and_then(|monster_attack| monster_attack.damage.first())
+                .map_or(0, |damage| damage.damage_bonus),
+        ]
+        .into_iter()
+        .filter(|bonus| *bonus != 0 && outcome.spell_attack.is_none())
+        .map(|bonus| format!("{bonus:+}"))
+        .collect::<String>();
+
+        let (damage_roll, bonus_damage_rolls, hit, hp_change) = match &outcome.hit {
             Hit::Success {
                 damage_roll,
+                bonus_damage_rolls,
+                damage_dealt,
                 hp_change,
                 damage_type,
-            } => match damage_roll.damage_roll_type() {
-                DamageRollType::Normal => {
-                    let roll = Some(format!(
-                        "Damage roll lands on {} ({}+{}).",
-                        damage_roll.total_value(),
-                        damage_roll.raw_value(),
-                        ability_modifier
-                    ));
-
-                    let hit = format!(
-                        "Attack hits for {} {:?} damage.",
-                        damage_roll.total_value(),
-                        damage_type
-                    );
-
-                    let hp_change = Some(format!(
-                        "Target's HP changes from {} to {}.",
-                        hp_change.before, hp_change.after
-                    ));
-
-                    (roll, hit, hp_change)
+                temporary_hp_gained,
+            } => {
+                let total_damage = damage_roll.total_value()
+                    + bonus_damage_rolls
+                        .iter()
+                        .map(BonusDamageRoll::total_value)
+                        .sum::<u32>();
+
+                // Bonus damage of another type is listed separately, e.g. "10 Slashing and 9 Radiant"
+                let mut damage_by_type = vec![(*damage_type, damage_roll.total_value())];
+                for bonus_damage_roll in bonus_damage_rolls {
+                    match damage_by_type
+                        .iter_mut()
+                        .find(|(damage_type, _)| *damage_type == bonus_damage_roll.damage_type)
+                    {
+                        Some((_, damage)) => *damage += bonus_damage_roll.total_value(),
+                        None => damage_by_type.push((
+                            bonus_damage_roll.damage_type,
+                            bonus_damage_roll.total_value(),
+                        )),
+                    }
                 }
-                DamageRollType::Critical { bonus_roll_value } => {
-                    let roll = Some(format!(
-                        "Critical damage roll lands on {} ({}+{}+{}).",
-                        damage_roll.total_value(),
-                        damage_roll.raw_value(),
-                        bonus_roll_value,
-                        ability_modifier
-                    ));
-
-                    let hit = format!(
-                        "Attack critically hits for {} {:?} damage!",
-                        damage_roll.total_value(),
-                        damage_type
-                    );
-
-                    let hp_change = Some(format!(
-                        "Target's HP changes from {} to {}.",
-                        hp_change.before, hp_change.after
-                    ));
-
-                    (roll, hit, hp_change)
+                let damage_phrase = damage_by_type
+                    .iter()
+                    .map(|(damage_type, damage)| format!("{damage} {damage_type:?}"))
+                    .collect::<Vec<_>>()
+                    .join(" and ");
+
+                let bonus_damage_rolls = bonus_damage_rolls
+                    .iter()
+                    .map(|bonus_damage_roll| match bonus_damage_roll.critical_roll_value {
+                        Some(critical_roll_value) => format!(
+                            "{} rolls {} twice for the critical hit, landing on {} ({}+{}{}) {} damage.",
+                            bonus_damage_roll.source.describe(),
+                            bonus_damage_roll.dice,
+                            bonus_damage_roll.total_value(),
+                            bonus_damage_roll.roll_value,
+                            critical_roll_value,
+                            bonus_damage_bonus_phrase(bonus_damage_roll),
+                            bonus_damage_roll.damage_type.describe()
+                        ),
+                        None => format!(
+                            "{} rolls {}{}, landing on {} {} damage.",
+                            bonus_damage_roll.source.describe(),
+                            bonus_damage_roll.dice,
+                            bonus_damage_bonus_phrase(bonus_damage_roll),
+                            bonus_damage_roll.total_value(),
+                            bonus_damage_roll.damage_type.describe()
+                        ),
+                    })
+                    .collect();
+
+                let resistance_phrase = if *damage_dealt < total_damage {
+                    format!("Target resists {damage_type:?} damage and only takes {damage_dealt}. ")
+                } else {
+                    String::new()
+                };
+
+                let temporary_absorbed_phrase = match hp_change.temporary_absorbed {
+                    0 => String::new(),
+                    absorbed => format!("Target's temporary HP absorb {absorbed} damage. "),
+                };
+
+                let temporary_hp_gained_phrase = match temporary_hp_gained {
+                    Some(temporary_hp) => format!(
+                        " Attacker gains {temporary_hp} temporary HP from Dark One's Blessing."
+                    ),
+                    None => String::new(),
+                };
+
+                let hp_change = Some(format!(
+                    "{resistance_phrase}{temporary_absorbed_phrase}Target's HP changes from {} to {}.{temporary_hp_gained_phrase}",
+                    hp_change.before, hp_change.after
+                ));
+
+                let chosen_roll = outcome.attack_roll_type.chosen_roll();
+                let critical_range = match (outcome.spell_attack, outcome.monster_attack) {
+                    (None, None) => *outcome.state_and_context.context.attacker.critical_range,
+                    _ => DEFAULT_CRITICAL_RANGE,
+                };
+
+                match damage_roll.damage_roll_type() {
+                    DamageRollType::Normal => {
+                        let roll = Some(format!(
+                            "Damage roll lands on {} ({}+{}{}).",
+                            damage_roll.total_value(),
+                            damage_roll.raw_value(),
+                            damage_roll.ability_modifier().value(),
+                            damage_bonus_phrase
+                        ));
+
+                        let hit = if chosen_roll
+                            .critical_state_in_range(critical_range)
+                            .is_critical()
+                        {
+                            format!(
+                                "Attack would critically hit but the target's adamantine armor turns it into a normal hit for {damage_phrase} damage."
+                            )
+                        } else {
+                            format!("Attack hits for {damage_phrase} damage.")
+                        };
+
+                        (roll, bonus_damage_rolls, hit, hp_change)
+                    }
+                    DamageRollType::Critical { bonus_roll_value } => {
+                        let roll = Some(format!(
+                            "Critical damage roll lands on {} ({}+{}+{}{}).",
+                            damage_roll.total_value(),
+                            damage_roll.raw_value(),
+                            bonus_roll_value,
+                            damage_roll.ability_modifier().value(),
+                            damage_bonus_phrase
+                        ));
+
+                        let hit = if chosen_roll.is_critical() {
+                            format!("Attack critically hits for {damage_phrase} damage!")
+                        } else if chosen_roll
+                            .critical_state_in_range(critical_range)
+                            .is_critical()
+                        {
+                            format!(
+                                "Attack critically hits on a {} with Improved Critical for {damage_phrase} damage!",
+                                chosen_roll.raw_value()
+                            )
+                        } else {
+                            format!(
+                                "Attack critically hits the paralyzed target from within 5 feet for {damage_phrase} damage!"
+                            )
+                        };
+
+                        (roll, bonus_damage_rolls, hit, hp_change)
+                    }
                 }
-            },
+            }
             Hit::Miss => {
                 let hit = format!("Attack misses!");
 
-                (None, hit, None)
+                (None, Vec::new(), hit, None)
             }
         };
 
@@ -225,8 +431,17 @@ impl From<&PhysicalAttackOutcome<'_>> for PhysicalAttackOutcomeSummary {
             attack_roll,
             target_ac,
             damage_roll,
+            bonus_damage_rolls,
             hit,
             hp_change,
         }
     }
 }
+
+/// Only monster attacks add a flat bonus to their additional damage
+fn bonus_damage_bonus_phrase(bonus_damage_roll: &BonusDamageRoll) -> String {
+    match bonus_damage_roll.damage_bonus {
+        0 => String::new(),
+        damage_bonus => format!("{damage_bonus:+}"),
+    }
+}
@@ -0,0 +1,303 @@
+use crate::describe::Describe;
+use rs5e_concepts::{
+    ability_type::AbilityType,
+    condition::Condition,
+    damage_type::DamageType,
+    spell::{SpellDc, SpellDcSuccess},
+    spell_save::SaveDamage,
+};
+use rs5e_systems::{
+    monster_save_action::MonsterSaveActionOutcome,
+    spell_save::{SpellSaveOutcome, SpellSaveTargetOutcome},
+};
+use serde::Serialize;
+use typeshare::typeshare;
+
+#[typeshare]
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellSaveOutcomeSummary {
+    cast: String,
+    damage_roll: Option<String>,
+    /// One entry per creature caught by the spell
+    targets: Vec<SpellSaveTargetSummary>,
+}
+
+#[typeshare]
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SpellSaveTargetSummary {
+    saving_throw: String,
+    effect: String,
+    hp_change: Option<String>,
+}
+
+impl From<&SpellSaveOutcome<'_>> for SpellSaveOutcomeSummary {
+    fn from(outcome: &SpellSaveOutcome<'_>) -> Self {
+        let spell_save = outcome.spell_save;
+
+        let area_phrase =
+            spell_save
+                .spell
+                .area_of_effect
+                .as_ref()
+                .map_or(String::new(), |area_of_effect| {
+                    let names = outcome
+                        .targets
+                        .iter()
+                        .map(|target| target.name.as_str())
+                        .collect::<Vec<_>>();
+
+                    format!(
+                        ", a {} catching {}",
+                        area_of_effect.describe(),
+                        names.join(" and ")
+                    )
+                });
+
+        let cast = format!(
+            "{} casts {}{area_phrase}, forcing a {:?} saving throw against DC {}.",
+            "Attacker",
+            spell_save.describe(),
+            spell_save.dc.dc_type,
+            outcome.save_dc
+        );
+
+        let damage_roll = outcome
+            .damage_roll
+            .zip(spell_save.damage_dice)
+            .map(|(damage, dice)| {
+                let damage_type_phrase =
+                    spell_save.damage_type.map_or(String::new(), |damage_type| {
+                        format!(" {}", damage_type.describe())
+                    });
+
+                format!("Damage roll of {dice} lands on {damage}{damage_type_phrase} damage.")
+            });
+
+        Self {
+            cast,
+            damage_roll,
+            targets: outcome
+                .targets
+                .iter()
+                .map(|target| {
+                    describe_target(
+                        target,
+                        &SaveEffect {
+                            source_name: &spell_save.spell.name,
+                            dc: spell_save.dc,
+                            damage_roll: outcome.damage_roll,
+                            damage_type: spell_save.damage_type,
+                            condition: spell_save.condition,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A monster's action is described like a spell, though only the target
+/// is caught by it
+impl From<&MonsterSaveActionOutcome<'_>> for SpellSaveOutcomeSummary {
+    fn from(outcome: &MonsterSaveActionOutcome<'_>) -> Self {
+        let save_action = outcome.save_action;
+
+        let usage_phrase = save_action
+            .usage
+            .as_ref()
+            .map_or(String::new(), |usage| format!(" ({})", usage.describe()));
+
+        let cast = format!(
+            "{} uses {}{usage_phrase}, forcing a {:?} saving throw against DC {}.",
+            "Attacker", save_action.name, save_action.dc.dc_type, save_action.dc_value
+        );
+
+        let damage_roll = save_action
+            .damage
+            .iter()
+            .zip(&outcome.damage_rolls)
+            .map(|(damage, damage_roll)| {
+                let bonus_phrase = match damage.damage_bonus {
+                    0 => String::new(),
+                    bonus if bonus > 0 => format!(" + {bonus}"),
+                    bonus => format!(" - {}", bonus.unsigned_abs()),
+                };
+
+                format!(
+                    "Damage roll of {}{bonus_phrase} lands on {damage_roll} {} damage.",
+                    damage.damage_dice,
+                    damage.damage_type.describe()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Damage of several types is described as one total
+        let damage_type = match save_action.damage.as_slice() {
+            [damage] => Some(damage.damage_type),
+            _ => None,
+        };
+
+        Self {
+            cast,
+            damage_roll: (!damage_roll.is_empty()).then(|| damage_roll.join(" ")),
+            targets: Vec::from([describe_target(
+                &outcome.target,
+                &SaveEffect {
+                    source_name: &save_action.name,
+                    dc: &save_action.dc,
+                    damage_roll: Some(outcome.damage_rolls.iter().sum()),
+                    damage_type,
+                    condition: None,
+                },
+            )]),
+        }
+    }
+}
+
+/// What a target is saving against, from either a spell or a monster's
+/// action
+struct SaveEffect<'a> {
+    source_name: &'a str,
+    dc: &'a SpellDc,
+    damage_roll: Option<u32>,
+    damage_type: Option<DamageType>,
+    condition: Option<Condition>,
+}
+
+fn describe_target(
+    target: &SpellSaveTargetOutcome,
+    &SaveEffect {
+        source_name,
+        dc,
+        damage_roll,
+        damage_type,
+        condition,
+    }: &SaveEffect,
+) -> SpellSaveTargetSummary {
+    let saving_throw = &target.saving_throw;
+    let succeeded = saving_throw.succeeded();
+    // Legendary Resistance succeeds after the roll has failed
+    let result_phrase = if succeeded && !saving_throw.legendary_resistance {
+        "succeeds"
+    } else {
+        "fails"
+    };
+    let legendary_resistance_phrase = if saving_throw.legendary_resistance {
+        format!(
+            " {} succeeds instead with Legendary Resistance.",
+            target.name
+        )
+    } else {
+        String::new()
+    };
+
+    let saving_throw_phrase = if saving_throw.automatic_failure {
+        format!(
+            "{} automatically fails the {:?} saving throw.{legendary_resistance_phrase}",
+            target.name, saving_throw.ability
+        )
+    } else {
+        let proficiency_phrase = saving_throw
+            .bonus
+            .proficiency_bonus
+            .as_ref()
+            .map_or(String::new(), |bonus| format!(" + {}", bonus.value()));
+        let cover_phrase = if saving_throw.bonus.cover_bonus > 0 {
+            format!(" + {} cover", saving_throw.bonus.cover_bonus)
+        } else {
+            String::new()
+        };
+        // Magic Resistance rolls with advantage against spells
+        let advantage_phrase = saving_throw
+            .discarded_raw_value
+            .map_or(String::new(), |discarded| {
+                format!(" with Magic Resistance, discarding {discarded}")
+            });
+
+        format!(
+            "{} rolls {} ({} + {}{proficiency_phrase}{cover_phrase}) on the {:?} saving throw{advantage_phrase} and {result_phrase}.{legendary_resistance_phrase}",
+            target.name,
+            saving_throw.total_value(),
+            saving_throw.raw_value,
+            saving_throw.bonus.ability_modifier.value(),
+            saving_throw.ability,
+        )
+    };
+
+    // Evasion only changes the damage of Dexterity saves for half damage
+    let evasion_applies = target.evasion
+        && dc.dc_type == AbilityType::Dexterity
+        && dc.dc_success == SpellDcSuccess::Half;
+
+    let damage_phrase = damage_roll.map(|damage| {
+        let damage_type_phrase = damage_type.map_or(String::new(), |damage_type| {
+            format!(" {}", damage_type.describe())
+        });
+        let resistance_phrase = if target.damage_dealt < target.save_damage.apply(damage) {
+            " after resistance"
+        } else {
+            ""
+        };
+
+        match (target.save_damage, evasion_applies) {
+            (SaveDamage::Full, _) => format!(
+                "{} takes the full {}{damage_type_phrase} damage{resistance_phrase}.",
+                target.name, target.damage_dealt
+            ),
+            (SaveDamage::Half, true) => format!(
+                "Evasion halves the damage, {} takes {}{damage_type_phrase} damage{resistance_phrase}.",
+                target.name, target.damage_dealt
+            ),
+            (SaveDamage::Half, false) => format!(
+                "{} takes half, {}{damage_type_phrase} damage{resistance_phrase}.",
+                target.name, target.damage_dealt
+            ),
+            (SaveDamage::None, true) => {
+                format!("Evasion lets {} avoid the damage entirely.", target.name)
+            }
+            (SaveDamage::None, false) => format!("{} takes no damage.", target.name),
+        }
+    });
+
+    let condition_phrase = match (target.condition_imposed, condition) {
+        (Some(condition), _) => Some(format!(
+            "{} is {} by {source_name}, repeating the saving throw at the end of each of their turns.",
+            target.name,
+            condition.describe(),
+        )),
+        (None, Some(condition)) => Some(format!(
+            "{} is not {} by {source_name}.",
+            target.name,
+            condition.describe(),
+        )),
+        (None, None) => None,
+    };
+
+    let rage_phrase = if target.exhaustion_gained {
+        Some(format!(
+            "{0}'s frenzy ends and {0} suffers a level of exhaustion.",
+            target.name
+        ))
+    } else {
+        target
+            .rage_ended
+            .then(|| format!("{}'s rage ends.", target.name))
+    };
+
+    SpellSaveTargetSummary {
+        saving_throw: saving_throw_phrase,
+        effect: [damage_phrase, condition_phrase, rage_phrase]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" "),
+        hp_change: target.hp_change.as_ref().map(|hp_change| {
+            format!(
+                "{}'s HP changes from {} to {}.",
+                target.name, hp_change.before, hp_change.after
+            )
+        }),
+    }
+}
@@ -1,3 +1,5 @@
 pub mod describe;
+pub mod describe_initiative;
 pub mod describe_physical_attack_context;
 pub mod describe_physical_attack_outcome;
+pub mod describe_spell_save_outcome;
@@ -55,16 +55,31 @@ pub fn named_macro_derive(input: TokenStream) -> TokenStream {
 
 fn impl_named_macro(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
-    let gen = quote! {
-        impl Named for #name {
-            fn name(&self) -> &str {
-                self.name.as_str()
+    let lifetimes = ast.generics.lifetimes().collect::<Vec<_>>();
+
+    let gen = match lifetimes.is_empty().not() {
+        true => quote! {
+            impl <#(#lifetimes)*> Named for #name <#(#lifetimes)*> {
+                fn name(&self) -> &str {
+                    self.name.as_str()
+                }
+
+                fn set_name(&mut self, new_name: String) {
+                    self.name = new_name;
+                }
             }
+        },
+        false => quote! {
+            impl Named for #name {
+                fn name(&self) -> &str {
+                    self.name.as_str()
+                }
 
-            fn set_name(&mut self, new_name: String) {
-                self.name = new_name;
+                fn set_name(&mut self, new_name: String) {
+                    self.name = new_name;
+                }
             }
-        }
+        },
     };
     gen.into()
 }
@@ -0,0 +1,23 @@
+use crate::equipment::RelationSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct ClassSpellcastingSchema<'a> {
+    pub level: u32,
+    #[serde(borrow)]
+    pub spellcasting_ability: RelationSchema<'a>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ClassSchema<'a> {
+    pub index: &'a str,
+    pub name: &'a str,
+    pub url: &'a str,
+    pub hit_die: u32,
+    #[serde(borrow)]
+    pub proficiencies: Vec<RelationSchema<'a>>,
+    #[serde(borrow)]
+    pub saving_throws: Vec<RelationSchema<'a>>,
+    #[serde(borrow, default)]
+    pub spellcasting: Option<ClassSpellcastingSchema<'a>>,
+}
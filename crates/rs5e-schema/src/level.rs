@@ -0,0 +1,35 @@
+use crate::equipment::RelationSchema;
+use serde::Deserialize;
+
+/// Slot counts past the 5th level are left out for half casters
+#[derive(Debug, Deserialize)]
+pub struct LevelSpellcastingSchema {
+    pub cantrips_known: Option<u32>,
+    pub spells_known: Option<u32>,
+    pub spell_slots_level_1: u32,
+    pub spell_slots_level_2: u32,
+    pub spell_slots_level_3: u32,
+    pub spell_slots_level_4: u32,
+    pub spell_slots_level_5: u32,
+    #[serde(default)]
+    pub spell_slots_level_6: u32,
+    #[serde(default)]
+    pub spell_slots_level_7: u32,
+    #[serde(default)]
+    pub spell_slots_level_8: u32,
+    #[serde(default)]
+    pub spell_slots_level_9: u32,
+}
+
+/// Subclass levels share the file with class levels and carry a subclass
+#[derive(Debug, Deserialize)]
+pub struct LevelSchema<'a> {
+    pub index: &'a str,
+    pub level: u32,
+    #[serde(borrow)]
+    pub class: RelationSchema<'a>,
+    #[serde(borrow, default)]
+    pub subclass: Option<RelationSchema<'a>>,
+    #[serde(default)]
+    pub spellcasting: Option<LevelSpellcastingSchema>,
+}
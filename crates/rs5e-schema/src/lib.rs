@@ -1,3 +1,8 @@
 pub mod armor;
+pub mod class;
 pub mod equipment;
+pub mod level;
+pub mod monster;
+pub mod proficiency;
+pub mod spell;
 pub mod weapon;
\ No newline at end of file
@@ -0,0 +1,203 @@
+use crate::equipment::RelationSchema;
+use serde::Deserialize;
+
+/// Several ways of reaching the same AC may be listed, e.g. natural armor
+/// and mage armor
+#[derive(Debug, Deserialize)]
+pub struct MonsterArmorClassSchema<'a> {
+    #[serde(rename = "type")]
+    pub armor_class_type: &'a str,
+    pub value: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MonsterProficiencySchema<'a> {
+    /// The total bonus, e.g. to a saving throw
+    pub value: i32,
+    #[serde(borrow)]
+    pub proficiency: RelationSchema<'a>,
+}
+
+/// Ranges are written out, e.g. "60 ft." or "10 ft. (blind beyond this
+/// radius)"
+#[derive(Debug, Deserialize)]
+pub struct MonsterSensesSchema<'a> {
+    #[serde(default)]
+    pub blindsight: Option<&'a str>,
+    #[serde(default)]
+    pub darkvision: Option<&'a str>,
+    #[serde(default)]
+    pub tremorsense: Option<&'a str>,
+    #[serde(default)]
+    pub truesight: Option<&'a str>,
+    pub passive_perception: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MonsterDcSchema<'a> {
+    #[serde(borrow)]
+    pub dc_type: RelationSchema<'a>,
+    pub dc_value: u32,
+    pub success_type: &'a str,
+}
+
+/// Dice may carry a bonus, e.g. "2d10+8", or be flat damage, e.g. "1"
+#[derive(Debug, Deserialize)]
+pub struct MonsterDamageRollSchema<'a> {
+    #[serde(borrow)]
+    pub damage_type: RelationSchema<'a>,
+    pub damage_dice: &'a str,
+    /// Only dealt on a failed saving throw, e.g. an assassin's poison
+    #[serde(borrow, default)]
+    pub dc: Option<MonsterDcSchema<'a>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MonsterDamageOptionsSchema<'a> {
+    #[serde(borrow)]
+    pub options: Vec<MonsterDamageRollSchema<'a>>,
+}
+
+/// Versatile weapons list their one-handed and two-handed damage as options
+#[derive(Debug, Deserialize)]
+pub struct MonsterDamageChoiceSchema<'a> {
+    pub choose: u32,
+    #[serde(borrow)]
+    pub from: MonsterDamageOptionsSchema<'a>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum MonsterDamageSchema<'a> {
+    #[serde(borrow)]
+    Roll(MonsterDamageRollSchema<'a>),
+    #[serde(borrow)]
+    Choice(MonsterDamageChoiceSchema<'a>),
+}
+
+/// Usually a number, but a few stat blocks describe it, e.g. "1d4" or
+/// "Number of Heads"
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum MultiattackCountSchema<'a> {
+    Fixed(u32),
+    Described(&'a str),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MultiattackActionSchema<'a> {
+    pub action_name: &'a str,
+    #[serde(borrow)]
+    pub count: MultiattackCountSchema<'a>,
+    /// "melee", "ranged" or "ability"
+    #[serde(rename = "type")]
+    pub action_type: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum MultiattackOptionSchema<'a> {
+    Multiple {
+        #[serde(borrow)]
+        items: Vec<MultiattackActionSchema<'a>>,
+    },
+    #[serde(borrow)]
+    Single(MultiattackActionSchema<'a>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MultiattackOptionsSchema<'a> {
+    #[serde(borrow)]
+    pub options: Vec<MultiattackOptionSchema<'a>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MultiattackChoiceSchema<'a> {
+    pub choose: u32,
+    #[serde(borrow)]
+    pub from: MultiattackOptionsSchema<'a>,
+}
+
+/// "recharge on roll" lists dice and a minimum, e.g. 1d6 and 5, and "per day"
+/// the number of times
+#[derive(Debug, Deserialize)]
+pub struct MonsterUsageSchema<'a> {
+    #[serde(rename = "type")]
+    pub usage_type: &'a str,
+    #[serde(default)]
+    pub dice: Option<&'a str>,
+    #[serde(default)]
+    pub min_value: Option<u32>,
+    #[serde(default)]
+    pub times: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MonsterActionSchema<'a> {
+    pub name: &'a str,
+    /// Owned as descriptions may contain escaped characters
+    pub desc: String,
+    #[serde(default)]
+    pub attack_bonus: Option<i32>,
+    #[serde(borrow, default)]
+    pub damage: Vec<MonsterDamageSchema<'a>>,
+    #[serde(borrow, default)]
+    pub dc: Option<MonsterDcSchema<'a>>,
+    /// "actions" or "action_options" for Multiattack
+    #[serde(default)]
+    pub multiattack_type: Option<&'a str>,
+    #[serde(borrow, default)]
+    pub actions: Vec<MultiattackActionSchema<'a>>,
+    #[serde(borrow, default)]
+    pub action_options: Option<MultiattackChoiceSchema<'a>>,
+    #[serde(borrow, default)]
+    pub usage: Option<MonsterUsageSchema<'a>>,
+}
+
+/// Special traits are mostly described in text, e.g. Pack Tactics
+#[derive(Debug, Deserialize)]
+pub struct MonsterSpecialAbilitySchema<'a> {
+    pub name: &'a str,
+    /// Owned as descriptions may contain escaped characters
+    pub desc: String,
+    /// e.g. Legendary Resistance's 3 uses a day
+    #[serde(borrow, default)]
+    pub usage: Option<MonsterUsageSchema<'a>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MonsterSchema<'a> {
+    pub index: &'a str,
+    pub name: &'a str,
+    pub size: &'a str,
+    /// Usually a creature type, e.g. "beast", but swarms are "swarm of Tiny
+    /// beasts"
+    #[serde(rename = "type")]
+    pub creature_type: &'a str,
+    #[serde(borrow)]
+    pub armor_class: Vec<MonsterArmorClassSchema<'a>>,
+    pub hit_points: u32,
+    pub hit_dice: &'a str,
+    pub strength: u32,
+    pub dexterity: u32,
+    pub constitution: u32,
+    pub intelligence: u32,
+    pub wisdom: u32,
+    pub charisma: u32,
+    #[serde(borrow)]
+    pub proficiencies: Vec<MonsterProficiencySchema<'a>>,
+    #[serde(borrow)]
+    pub senses: MonsterSensesSchema<'a>,
+    /// Fractions are written as decimals, e.g. 0.25
+    pub challenge_rating: f64,
+    pub proficiency_bonus: u32,
+    pub xp: u32,
+    #[serde(borrow, default)]
+    pub special_abilities: Vec<MonsterSpecialAbilitySchema<'a>>,
+    #[serde(borrow, default)]
+    pub actions: Vec<MonsterActionSchema<'a>>,
+    /// Described like actions, with any cost above 1 in the name, e.g.
+    /// "Wing Attack (Costs 2 Actions)"
+    #[serde(borrow, default)]
+    pub legendary_actions: Vec<MonsterActionSchema<'a>>,
+}
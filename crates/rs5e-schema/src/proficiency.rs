@@ -0,0 +1,15 @@
+use crate::equipment::RelationSchema;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct ProficiencySchema<'a> {
+    pub index: &'a str,
+    pub name: &'a str,
+    pub url: &'a str,
+    #[serde(rename = "type")]
+    pub proficiency_type: &'a str,
+    #[serde(borrow)]
+    pub classes: Vec<RelationSchema<'a>>,
+    #[serde(borrow)]
+    pub reference: RelationSchema<'a>,
+}
@@ -0,0 +1,47 @@
+use crate::equipment::RelationSchema;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Damage dice are keyed by slot level or, for cantrips, by character level
+#[derive(Debug, Deserialize)]
+pub struct SpellDamageSchema<'a> {
+    #[serde(borrow, default)]
+    pub damage_type: Option<RelationSchema<'a>>,
+    #[serde(borrow, default)]
+    pub damage_at_slot_level: HashMap<&'a str, &'a str>,
+    #[serde(borrow, default)]
+    pub damage_at_character_level: HashMap<&'a str, &'a str>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpellDcSchema<'a> {
+    #[serde(borrow)]
+    pub dc_type: RelationSchema<'a>,
+    pub dc_success: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AreaOfEffectSchema<'a> {
+    #[serde(rename = "type")]
+    pub area_type: &'a str,
+    pub size: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpellSchema<'a> {
+    pub index: &'a str,
+    pub name: &'a str,
+    pub level: u32,
+    pub range: &'a str,
+    pub concentration: bool,
+    #[serde(default)]
+    pub attack_type: Option<&'a str>,
+    #[serde(borrow, default)]
+    pub dc: Option<SpellDcSchema<'a>>,
+    #[serde(borrow, default)]
+    pub damage: Option<SpellDamageSchema<'a>>,
+    #[serde(borrow, default)]
+    pub area_of_effect: Option<AreaOfEffectSchema<'a>>,
+    #[serde(borrow)]
+    pub classes: Vec<RelationSchema<'a>>,
+}
@@ -0,0 +1,48 @@
+use rs5e_components::{combatant::Combatant, destructible::Destructible};
+use rs5e_concepts::{
+    area_of_effect::{AreaOfEffectContext, AreaOfEffectTemplate},
+    cover_state::CoverState,
+    spell::SpellModel,
+};
+
+#[derive(Debug)]
+pub struct AreaOfEffectTargets<'t, T> {
+    /// None for spells that only affect their target
+    pub template: Option<AreaOfEffectTemplate>,
+    /// The target first, then everyone else caught in the area
+    pub targets: Vec<&'t mut T>,
+}
+
+/// Places the spell's area aimed at the target. The target is always
+/// affected, others only when still standing, inside the area and not behind
+/// total cover. The caster keeps clear of their own spell
+pub fn area_of_effect_targets_system<'t, C, T>(
+    caster: &C,
+    spell: &SpellModel,
+    target: &'t mut T,
+    others: Vec<&'t mut T>,
+) -> AreaOfEffectTargets<'t, T>
+where
+    C: Combatant,
+    T: Combatant + Destructible,
+{
+    let template = spell.area_of_effect.as_ref().map(|area_of_effect| {
+        AreaOfEffectTemplate::from(AreaOfEffectContext {
+            area_of_effect,
+            range: &spell.range,
+            caster: *caster.position(),
+            target: *target.position(),
+        })
+    });
+
+    let mut targets = Vec::from([target]);
+    if let Some(template) = template.as_ref() {
+        targets.extend(others.into_iter().filter(|other| {
+            other.hp() > 0
+                && other.cover_state() != &CoverState::Total
+                && template.contains(other.position())
+        }));
+    }
+
+    AreaOfEffectTargets { template, targets }
+}
@@ -0,0 +1,421 @@
+use crate::legendary::legendary_resistance_system;
+use rs5e_components::{
+    has_concentration::HasConcentration, has_conditions::HasConditions,
+    has_legendary_actions::HasLegendaryActions, has_saving_throws::HasSavingThrows,
+    identifiable::Identifiable, named::Named,
+};
+use rs5e_concepts::{
+    ability_type::AbilityType,
+    concentration::{Concentration, ConcentrationDc, ConcentrationDcContext},
+    saving_throw::SavingThrow,
+    spell::SpellModel,
+};
+
+#[derive(Debug)]
+pub struct ConcentrationCheck {
+    pub concentration: Concentration,
+    pub damage: u32,
+    pub saving_throw: SavingThrow,
+}
+
+impl ConcentrationCheck {
+    #[must_use]
+    pub fn maintained(&self) -> bool {
+        self.saving_throw.succeeded()
+    }
+}
+
+/// Starts concentrating on the spell if it requires it, returning the
+/// spell the caster stops concentrating on
+pub fn begin_concentration_system<C>(caster: &mut C, spell: &SpellModel) -> Option<Concentration>
+where
+    C: HasConcentration + Identifiable,
+{
+    if !spell.concentration {
+        return None;
+    }
+
+    let concentration = Concentration {
+        caster: caster.id(),
+        spell_name: spell.name.clone(),
+    };
+
+    caster.concentration_mut().replace(concentration)
+}
+
+/// Taking damage while concentrating forces a Constitution saving throw,
+/// failing it ends the concentration
+pub fn concentration_check_system<C>(combatant: &mut C, damage: u32) -> Option<ConcentrationCheck>
+where
+    C: HasConcentration + HasSavingThrows + HasLegendaryActions,
+{
+    if damage == 0 {
+        return None;
+    }
+
+    let concentration = combatant.concentration()?.clone();
+    let dc = ConcentrationDc::from(ConcentrationDcContext { damage });
+    let saving_throw = combatant.roll_saving_throw(&AbilityType::Constitution, *dc);
+    let saving_throw = legendary_resistance_system(combatant, saving_throw, false);
+
+    if !saving_throw.succeeded() {
+        *combatant.concentration_mut() = None;
+    }
+
+    Some(ConcentrationCheck {
+        concentration,
+        damage,
+        saving_throw,
+    })
+}
+
+/// Incapacitated creatures lose their concentration
+pub fn incapacitated_concentration_system<C>(combatant: &mut C) -> Option<Concentration>
+where
+    C: HasConcentration + HasConditions,
+{
+    if combatant.is_incapacitated() {
+        combatant.concentration_mut().take()
+    } else {
+        None
+    }
+}
+
+/// Removes the conditions the spell imposed, returning the names of the
+/// creatures freed from them
+pub fn end_concentration_system<T>(
+    concentration: &Concentration,
+    creatures: &mut [&mut T],
+) -> Vec<String>
+where
+    T: HasConditions + Named,
+{
+    creatures
+        .iter_mut()
+        .filter_map(|creature| {
+            let conditions = creature.conditions_mut();
+            let before = conditions.len();
+            conditions.retain(|spell_condition| {
+                spell_condition.caster != concentration.caster
+                    || spell_condition.spell_name != concentration.spell_name
+            });
+
+            (conditions.len() < before).then(|| creature.name().to_string())
+        })
+        .collect()
+}
+
+/// Frees every creature in the fight, bystanders included, from the
+/// conditions of spells their caster no longer concentrates on. Returns
+/// each ended spell with the names of the creatures freed from it
+pub fn lapsed_concentration_system<T>(creatures: &mut [&mut T]) -> Vec<(String, Vec<String>)>
+where
+    T: HasConcentration + HasConditions + Named,
+{
+    let concentrating = creatures
+        .iter()
+        .filter_map(|creature| creature.concentration().cloned())
+        .collect::<Vec<_>>();
+
+    // The conditions each record the spell and caster that imposed them
+    let mut lapsed = Vec::new();
+    for spell_condition in creatures.iter().flat_map(|creature| creature.conditions()) {
+        let concentration = Concentration {
+            caster: spell_condition.caster,
+            spell_name: spell_condition.spell_name.clone(),
+        };
+        if !concentrating.contains(&concentration) && !lapsed.contains(&concentration) {
+            lapsed.push(concentration);
+        }
+    }
+
+    lapsed
+        .into_iter()
+        .map(|concentration| {
+            let freed = end_concentration_system(&concentration, creatures);
+            (concentration.spell_name, freed)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rs5e_components::{
+        combatant::Combatant, has_abilities::HasAbilities, has_level::HasLevel,
+        has_limited_uses::HasLimitedUses, has_proficiencies::HasProficiencies,
+        has_special_abilities::HasSpecialAbilities,
+    };
+    use rs5e_concepts::{
+        ability_scores::AbilityScores,
+        condition::{Condition, SpellCondition},
+        cover_state::CoverState,
+        damage_type::DamageType,
+        id::Id,
+        legendary::{LegendaryActionPoints, LegendaryResistancePolicy},
+        level::Level,
+        position::Position,
+        proficiency_bonus::ProficiencyBonus,
+        proficiency_type::ProficiencyType,
+        prone_state::ProneState,
+        special_ability::SpecialAbility,
+        usage::LimitedUse,
+    };
+
+    /// A creature with average abilities whose saving throws always roll the
+    /// same number
+    #[derive(Debug)]
+    struct Concentrator {
+        id: Id,
+        name: String,
+        saving_throw_roll: u32,
+        concentration: Option<Concentration>,
+        conditions: Vec<SpellCondition>,
+        ability_scores: AbilityScores,
+        level: Level,
+        position: Position,
+    }
+
+    impl Concentrator {
+        fn new(name: &str, saving_throw_roll: u32) -> Self {
+            Self {
+                id: Id::new_incremental(),
+                name: name.to_string(),
+                saving_throw_roll,
+                concentration: None,
+                conditions: Vec::new(),
+                ability_scores: AbilityScores::default(),
+                level: Level::try_from(1).unwrap(),
+                position: Position::default(),
+            }
+        }
+
+        fn concentrating_on(mut self, spell_name: &str) -> Self {
+            self.concentration = Some(Concentration {
+                caster: self.id,
+                spell_name: spell_name.to_string(),
+            });
+            self
+        }
+    }
+
+    impl Identifiable for Concentrator {
+        fn id(&self) -> Id {
+            self.id
+        }
+
+        fn set_id(&mut self, new_id: Id) {
+            self.id = new_id;
+        }
+    }
+
+    impl Named for Concentrator {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn set_name(&mut self, new_name: String) {
+            self.name = new_name;
+        }
+    }
+
+    impl HasConcentration for Concentrator {
+        fn concentration(&self) -> Option<&Concentration> {
+            self.concentration.as_ref()
+        }
+
+        fn concentration_mut(&mut self) -> &mut Option<Concentration> {
+            &mut self.concentration
+        }
+    }
+
+    impl HasConditions for Concentrator {
+        fn conditions(&self) -> &[SpellCondition] {
+            &self.conditions
+        }
+
+        fn conditions_mut(&mut self) -> &mut Vec<SpellCondition> {
+            &mut self.conditions
+        }
+    }
+
+    impl Combatant for Concentrator {
+        fn prone_state(&self) -> &ProneState {
+            &ProneState::Upright
+        }
+
+        fn cover_state(&self) -> &CoverState {
+            &CoverState::None
+        }
+
+        fn position(&self) -> &Position {
+            &self.position
+        }
+    }
+
+    impl HasAbilities for Concentrator {
+        fn ability_scores(&self) -> &AbilityScores {
+            &self.ability_scores
+        }
+
+        fn ability_scores_mut(&mut self) -> &mut AbilityScores {
+            &mut self.ability_scores
+        }
+    }
+
+    impl HasProficiencies for Concentrator {
+        fn proficiencies(&self) -> &[ProficiencyType] {
+            &[]
+        }
+
+        fn proficiency_bonus(&self) -> ProficiencyBonus {
+            ProficiencyBonus::from(&self.level)
+        }
+    }
+
+    impl HasLevel for Concentrator {
+        fn level(&self) -> &Level {
+            &self.level
+        }
+    }
+
+    impl HasSpecialAbilities for Concentrator {
+        fn special_abilities(&self) -> &[SpecialAbility] {
+            &[]
+        }
+
+        fn regeneration_suppressed_by(&self) -> Option<&DamageType> {
+            None
+        }
+
+        fn set_regeneration_suppressed_by(&mut self, _damage_type: Option<DamageType>) {}
+    }
+
+    impl HasSavingThrows for Concentrator {
+        fn unmodified_saving_throw_roll(&self) -> u32 {
+            self.saving_throw_roll
+        }
+    }
+
+    impl HasLimitedUses for Concentrator {
+        fn limited_uses(&self) -> &[LimitedUse] {
+            &[]
+        }
+
+        fn limited_uses_mut(&mut self) -> &mut [LimitedUse] {
+            &mut []
+        }
+    }
+
+    impl HasLegendaryActions for Concentrator {
+        fn legendary_action_points(&self) -> Option<&LegendaryActionPoints> {
+            None
+        }
+
+        fn legendary_action_points_mut(&mut self) -> Option<&mut LegendaryActionPoints> {
+            None
+        }
+
+        fn legendary_resistance_policy(&self) -> &LegendaryResistancePolicy {
+            &LegendaryResistancePolicy::Always
+        }
+    }
+
+    fn paralyzed_by(caster: &Concentrator, spell_name: &str) -> SpellCondition {
+        SpellCondition {
+            condition: Condition::Paralyzed,
+            caster: caster.id(),
+            spell_name: spell_name.to_string(),
+            dc_type: AbilityType::Wisdom,
+            save_dc: 13,
+        }
+    }
+
+    #[test]
+    fn taking_damage_rolls_to_keep_concentrating() {
+        let mut steady = Concentrator::new("Steady", 15).concentrating_on("Bless");
+        assert!(concentration_check_system(&mut steady, 0).is_none());
+
+        // Half of 30 damage raises the DC to 15, which a roll of 15 meets
+        let check = concentration_check_system(&mut steady, 30).unwrap();
+        assert_eq!(check.saving_throw.dc, 15);
+        assert!(check.maintained());
+        assert!(steady.concentration().is_some());
+
+        let check = concentration_check_system(&mut steady, 32).unwrap();
+        assert_eq!(check.saving_throw.dc, 16);
+        assert!(!check.maintained());
+        assert_eq!(check.concentration.spell_name, "Bless");
+        assert!(steady.concentration().is_none());
+
+        // Nothing to lose without concentrating on a spell
+        assert!(concentration_check_system(&mut steady, 30).is_none());
+    }
+
+    #[test]
+    fn incapacitated_creatures_lose_concentration() {
+        let mut caster = Concentrator::new("Caster", 10).concentrating_on("Bless");
+        assert!(incapacitated_concentration_system(&mut caster).is_none());
+        assert!(caster.concentration().is_some());
+
+        let paralysis = paralyzed_by(&Concentrator::new("Enemy", 10), "Hold Person");
+        caster.conditions_mut().push(paralysis);
+        let concentration = incapacitated_concentration_system(&mut caster).unwrap();
+        assert_eq!(concentration.spell_name, "Bless");
+        assert!(caster.concentration().is_none());
+    }
+
+    #[test]
+    fn ending_concentration_only_removes_that_spells_conditions() {
+        let caster = Concentrator::new("Caster", 10).concentrating_on("Hold Person");
+        let other_caster = Concentrator::new("Other caster", 10);
+
+        let mut same_spell = Concentrator::new("Same spell", 10);
+        same_spell.conditions = vec![
+            paralyzed_by(&caster, "Hold Person"),
+            paralyzed_by(&caster, "Hold Monster"),
+        ];
+        let mut other_spell = Concentrator::new("Other spell", 10);
+        other_spell.conditions = vec![paralyzed_by(&caster, "Hold Monster")];
+        let mut other_caster_spell = Concentrator::new("Other caster's spell", 10);
+        other_caster_spell.conditions = vec![paralyzed_by(&other_caster, "Hold Person")];
+
+        let freed = end_concentration_system(
+            caster.concentration().unwrap(),
+            &mut [&mut same_spell, &mut other_spell, &mut other_caster_spell],
+        );
+
+        assert_eq!(freed, ["Same spell"]);
+        assert_eq!(
+            same_spell.conditions,
+            [paralyzed_by(&caster, "Hold Monster")]
+        );
+        assert_eq!(other_spell.conditions.len(), 1);
+        assert_eq!(other_caster_spell.conditions.len(), 1);
+    }
+
+    #[test]
+    fn bystanders_are_freed_once_concentration_lapses() {
+        let mut caster = Concentrator::new("Caster", 10).concentrating_on("Hold Person");
+        let mut target = Concentrator::new("Target", 10);
+        let mut bystander = Concentrator::new("Bystander", 10);
+        target.conditions = vec![paralyzed_by(&caster, "Hold Person")];
+        bystander.conditions = vec![paralyzed_by(&caster, "Hold Person")];
+
+        let mut creatures = [&mut caster, &mut target, &mut bystander];
+        assert!(lapsed_concentration_system(&mut creatures).is_empty());
+        assert_eq!(creatures[2].conditions.len(), 1);
+
+        // Only the target is at hand when the caster loses concentration
+        let concentration = creatures[0].concentration_mut().take().unwrap();
+        end_concentration_system(&concentration, &mut [&mut *creatures[1]]);
+        assert_eq!(creatures[2].conditions.len(), 1);
+
+        let lapsed = lapsed_concentration_system(&mut creatures);
+        assert_eq!(
+            lapsed,
+            [("Hold Person".to_string(), vec!["Bystander".to_string()])]
+        );
+        assert!(creatures[2].conditions.is_empty());
+    }
+}
@@ -0,0 +1,26 @@
+use rs5e_components::has_level::HasLevel;
+use rs5e_concepts::{
+    encounter::{Encounter, EncounterContext},
+    monster::MonsterModel,
+};
+
+/// Budgets an encounter from the levels of the party and the XP of the
+/// monsters they would face
+pub fn encounter_system<C>(party: &[&C], monsters: &[&MonsterModel]) -> Encounter
+where
+    C: HasLevel,
+{
+    let party_levels = party
+        .iter()
+        .map(|character| character.level())
+        .collect::<Vec<_>>();
+    let monster_xp = monsters
+        .iter()
+        .map(|monster| monster.xp)
+        .collect::<Vec<_>>();
+
+    Encounter::from(EncounterContext {
+        party_levels: &party_levels,
+        monster_xp: &monster_xp,
+    })
+}
@@ -0,0 +1,53 @@
+use rs5e_components::{has_skills::HasSkills, identifiable::Identifiable, named::Named};
+use rs5e_concepts::{id::Id, initiative::Initiative};
+
+#[derive(Debug)]
+pub struct InitiativeOutcome {
+    pub id: Id,
+    pub name: String,
+    pub initiative: Initiative,
+}
+
+/// Every combatant rolls initiative once, giving the turn order for the
+/// whole encounter, first to act first. Combatants tied on both the total
+/// and Dexterity roll off until the tie is broken
+pub fn initiative_system<C>(combatants: &[&C]) -> Vec<InitiativeOutcome>
+where
+    C: HasSkills + Identifiable + Named,
+{
+    let mut initiatives = combatants
+        .iter()
+        .map(|combatant| combatant.roll_initiative())
+        .collect::<Vec<_>>();
+
+    loop {
+        let tied = (0..initiatives.len())
+            .filter(|index| {
+                initiatives.iter().enumerate().any(|(other, initiative)| {
+                    other != *index
+                        && initiative.turn_order_key() == initiatives[*index].turn_order_key()
+                })
+            })
+            .collect::<Vec<_>>();
+        if tied.is_empty() {
+            break;
+        }
+
+        for index in tied {
+            initiatives[index].tiebreaker = Some(combatants[index].unmodified_ability_check_roll());
+        }
+    }
+
+    let mut outcomes = combatants
+        .iter()
+        .zip(initiatives)
+        .map(|(combatant, initiative)| InitiativeOutcome {
+            id: combatant.id(),
+            name: combatant.name().to_string(),
+            initiative,
+        })
+        .collect::<Vec<_>>();
+    outcomes.sort_by_key(|outcome| outcome.initiative.turn_order_key());
+
+    outcomes
+}
@@ -0,0 +1,58 @@
+use rs5e_components::{
+    destructible::Destructible, has_conditions::HasConditions,
+    has_legendary_actions::HasLegendaryActions,
+};
+use rs5e_concepts::{
+    legendary::{LegendaryAction, LEGENDARY_RESISTANCE},
+    saving_throw::SavingThrow,
+};
+
+/// Picks the legendary action to take at the end of another creature's
+/// turn and spends its cost. A creature that has fallen or cannot act
+/// takes none
+pub fn legendary_action_system<'a, C>(
+    monster: &mut C,
+    legendary_actions: &'a [LegendaryAction],
+) -> Option<&'a LegendaryAction>
+where
+    C: HasLegendaryActions + HasConditions + Destructible,
+{
+    if monster.hp() == 0 || monster.is_incapacitated() {
+        return None;
+    }
+
+    let legendary_action_points = monster.legendary_action_points_mut()?;
+    let legendary_action = legendary_action_points.choose(legendary_actions)?;
+    legendary_action_points.spend(legendary_action.cost).ok()?;
+
+    Some(legendary_action)
+}
+
+/// Legendary Resistance turns a failed saving throw into a success while
+/// uses are left, when the monster's policy calls for it
+pub fn legendary_resistance_system<C>(
+    combatant: &mut C,
+    saving_throw: SavingThrow,
+    imposes_condition: bool,
+) -> SavingThrow
+where
+    C: HasLegendaryActions,
+{
+    if saving_throw.succeeded()
+        || !combatant
+            .legendary_resistance_policy()
+            .resists(imposes_condition)
+    {
+        return saving_throw;
+    }
+
+    let resisted = combatant
+        .limited_use_mut(LEGENDARY_RESISTANCE)
+        .is_some_and(|legendary_resistance| legendary_resistance.spend().is_ok());
+
+    if resisted {
+        saving_throw.with_legendary_resistance()
+    } else {
+        saving_throw
+    }
+}
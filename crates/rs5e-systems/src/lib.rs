@@ -3,5 +3,16 @@
 #![allow(clippy::cargo_common_metadata)]
 #![allow(clippy::module_name_repetitions)]
 
+pub mod area_of_effect;
+pub mod concentration;
+pub mod encounter;
+pub mod initiative;
+pub mod legendary;
+pub mod monster_attack;
+pub mod monster_save_action;
 pub mod physical_attack;
-pub mod snapshots;
\ No newline at end of file
+pub mod snapshots;
+pub mod special_ability;
+pub mod spell_attack;
+pub mod spell_save;
+pub mod turn;
\ No newline at end of file
@@ -0,0 +1,119 @@
+use crate::physical_attack::{
+    final_critical_state, PhysicalAttackOutcome, PhysicalAttackStateAndContext,
+};
+use rs5e_components::{
+    attacker::Attacker, combatant::Combatant, destructible::Destructible, has_armor::HasArmor,
+};
+use rs5e_concepts::{
+    ability_modifier::AbilityModifier,
+    attack_roll::AttackRoll,
+    bonus_damage::{BonusDamageRoll, BonusDamageSource},
+    damage_roll::DamageRoll,
+    hit::Hit,
+    monster_action::MonsterAttack,
+    roll::Roll,
+    roll_type::RollType,
+    weapon_range::WeaponRange,
+};
+
+/// One attack from a monster's stat block, rolled with its fixed to-hit
+/// bonus in place of an ability modifier and proficiency bonus
+pub fn monster_attack_system<'a, A, D>(
+    attacker: &mut A,
+    defender: &mut D,
+    state_and_context: &'a PhysicalAttackStateAndContext<'a>,
+    monster_attack: &'a MonsterAttack,
+) -> PhysicalAttackOutcome<'a>
+where
+    A: Attacker,
+    D: Destructible + Combatant + HasArmor,
+{
+    let PhysicalAttackStateAndContext { context, .. } = &state_and_context;
+
+    let attack_roller =
+        || attacker.roll_attack(AbilityModifier::new(0), None, monster_attack.attack_bonus);
+
+    let attack_roll_type: RollType<AttackRoll> = (
+        context.attacker.computed_advantage_type.clone(),
+        attack_roller,
+    )
+        .into();
+
+    let chosen_roll = attack_roll_type.chosen_roll();
+    let hits = chosen_roll.total_value() >= context.defender.computed_armor_class.value();
+
+    let critical_state = final_critical_state(
+        chosen_roll.critical_state(),
+        hits,
+        monster_attack.attack_range == WeaponRange::Melee,
+        context,
+    );
+
+    PhysicalAttackOutcome {
+        hit: match (hits, monster_attack.damage.split_first()) {
+            (true, Some((damage, additional_damage))) => {
+                let damage_roll = DamageRoll::from_critical_state(
+                    AbilityModifier::new(0),
+                    critical_state,
+                    &|| damage.damage_dice.roll(),
+                )
+                .with_damage_bonus(damage.damage_bonus);
+                let damage_type = damage.damage_type;
+
+                let bonus_damage_rolls = additional_damage
+                    .iter()
+                    .map(|additional_damage| {
+                        BonusDamageRoll::roll(
+                            BonusDamageSource::AdditionalDamage,
+                            additional_damage.damage_dice,
+                            additional_damage.damage_type,
+                            critical_state,
+                        )
+                        .with_damage_bonus(additional_damage.damage_bonus)
+                    })
+                    .collect::<Vec<_>>();
+
+                // Resistance applies to the total damage of each type
+                let mut damage_by_type = vec![(damage_type, damage_roll.total_value())];
+                for bonus_damage_roll in &bonus_damage_rolls {
+                    match damage_by_type
+                        .iter_mut()
+                        .find(|(damage_type, _)| *damage_type == bonus_damage_roll.damage_type)
+                    {
+                        Some((_, damage)) => *damage += bonus_damage_roll.total_value(),
+                        None => damage_by_type.push((
+                            bonus_damage_roll.damage_type,
+                            bonus_damage_roll.total_value(),
+                        )),
+                    }
+                }
+
+                let damage_dealt = damage_by_type
+                    .iter()
+                    .map(|(damage_type, damage)| {
+                        context
+                            .defender
+                            .damage_resistances
+                            .apply(damage_type, *damage)
+                    })
+                    .sum();
+                let hp_change = defender.take_damage(damage_dealt);
+
+                Hit::Success {
+                    damage_roll,
+                    bonus_damage_rolls,
+                    damage_dealt,
+                    hp_change,
+                    damage_type,
+                    temporary_hp_gained: None,
+                }
+            }
+            _ => Hit::Miss,
+        },
+        attack_roll_type,
+        critical_state,
+        state_and_context,
+        spell_attack: None,
+        monster_attack: Some(monster_attack),
+    }
+}
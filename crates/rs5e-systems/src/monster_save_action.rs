@@ -0,0 +1,115 @@
+use crate::{legendary::legendary_resistance_system, spell_save::SpellSaveTargetOutcome};
+use rs5e_components::{
+    destructible::Destructible, has_class::HasClass, has_legendary_actions::HasLegendaryActions,
+    has_limited_uses::HasLimitedUses, has_rage::HasRage, has_saving_throws::HasSavingThrows,
+    named::Named,
+};
+use rs5e_concepts::{
+    damage_resistance::{DamageResistanceContext, DamageResistances},
+    evasion::{Evasion, EvasionContext},
+    monster_action::MonsterSaveAction,
+    spell_save::{SaveDamage, SaveDamageContext},
+    usage::LimitedUse,
+};
+
+#[derive(Debug)]
+pub struct MonsterSaveActionOutcome<'a> {
+    pub save_action: &'a MonsterSaveAction,
+    /// One roll per damage of the action, in the order they are listed
+    pub damage_rolls: Vec<u32>,
+    pub target: SpellSaveTargetOutcome,
+}
+
+/// A monster takes a limited action whenever it has a use of it left, so a
+/// recharged breath weapon is always preferred to its attacks. Actions it
+/// could take every turn are left to its attacks
+pub fn choose_monster_save_action<'a, C>(
+    monster: &C,
+    save_actions: &[&'a MonsterSaveAction],
+) -> Option<&'a MonsterSaveAction>
+where
+    C: HasLimitedUses,
+{
+    save_actions.iter().copied().find(|save_action| {
+        monster
+            .limited_use(&save_action.name)
+            .is_some_and(LimitedUse::is_available)
+    })
+}
+
+/// Spends a use of the action and the target saves against its DC. Magic
+/// Resistance does not help, as the action is not a spell
+///
+/// # Panics
+///
+/// When the action was chosen without a use left
+pub fn monster_save_action_system<'a, A, T>(
+    attacker: &mut A,
+    target: &mut T,
+    save_action: &'a MonsterSaveAction,
+) -> MonsterSaveActionOutcome<'a>
+where
+    A: HasLimitedUses,
+    T: Destructible + HasSavingThrows + HasClass + HasRage + HasLegendaryActions + Named,
+{
+    if let Some(limited_use) = attacker.limited_use_mut(&save_action.name) {
+        limited_use
+            .spend()
+            .expect("only actions with a use left are chosen");
+    }
+
+    let damage_rolls = save_action
+        .damage
+        .iter()
+        .map(|damage| {
+            damage
+                .damage_dice
+                .roll()
+                .saturating_add_signed(damage.damage_bonus)
+        })
+        .collect::<Vec<_>>();
+
+    let saving_throw = target.roll_saving_throw(&save_action.dc.dc_type, save_action.dc_value);
+    let saving_throw = legendary_resistance_system(target, saving_throw, false);
+    let saved = saving_throw.succeeded();
+
+    let evasion = *Evasion::from(EvasionContext {
+        class: target.class(),
+        level: target.level(),
+    });
+    let save_damage = SaveDamage::from(SaveDamageContext {
+        dc: &save_action.dc,
+        saved,
+        evasion,
+    });
+
+    let damage_resistances = DamageResistances::from(DamageResistanceContext {
+        raging: target.is_raging(),
+    });
+    let damage_dealt = save_action
+        .damage
+        .iter()
+        .zip(&damage_rolls)
+        .map(|(damage, damage_roll)| {
+            damage_resistances.apply(&damage.damage_type, save_damage.apply(*damage_roll))
+        })
+        .sum();
+    let hp_change = target.take_damage(damage_dealt);
+
+    MonsterSaveActionOutcome {
+        save_action,
+        damage_rolls,
+        target: SpellSaveTargetOutcome {
+            id: target.id(),
+            name: target.name().to_string(),
+            saving_throw,
+            evasion,
+            save_damage,
+            damage_dealt,
+            hp_change: Some(hp_change),
+            condition_imposed: None,
+            rage_ended: false,
+            exhaustion_gained: false,
+        },
+    }
+}
@@ -5,17 +5,52 @@ use rs5e_components::{
 };
 use rs5e_concepts::{
     advantage_type::AdvantageType,
+    armor_category::ArmorCategory,
     armor_class::ArmorClass,
     armor_proficiency_penalty::{ArmorProficiencyContext, ArmorProficiencyPenalty},
     attack_roll::AttackRoll,
+    bonus_damage::{BonusDamageRoll, BonusDamageSource},
+    colossus_slayer::{ColossusSlayerContext, ColossusSlayerDice},
+    condition::{Condition, ParalyzedTargetBonus, ParalyzedTargetContext},
     cover_state::CoverBonus,
     cover_state::{CanBeTargetedCoverBonus, CannotBeTargeted},
+    critical_state::CriticalState,
+    damage_resistance::{DamageResistanceContext, DamageResistances},
+    damage_roll::DamageRollContext,
+    damage_type::DamageType,
+    dark_ones_blessing::{DarkOnesBlessing, DarkOnesBlessingContext},
+    divine_smite::{DivineSmite, DivineSmiteContext, DivineSmiteDice, DivineSmiteDiceContext},
+    divine_strike::{DivineStrikeContext, DivineStrikeDice},
+    extra_attack::{AttacksPerTurn, ExtraAttackContext},
+    fighting_style::{
+        AttackerFightingStyleBonus, AttackerFightingStyleContext, DefenderFightingStyleBonus,
+        DefenderFightingStyleContext, ProtectionContext, ProtectionPenalty,
+    },
+    hide::{UnseenAttackerBonus, UnseenAttackerContext},
     hit::Hit,
+    improved_critical::{CriticalRange, ImprovedCriticalContext},
+    martial_arts::{MartialArts, MartialArtsContext},
+    monster_action::MonsterAttack,
     prone_state::ProneContext,
+    rage::{RageDamageBonus, RageDamageContext},
+    reckless_attack::{
+        RecklessAttackBonus, RecklessAttackContext, RecklessTargetBonus, RecklessTargetContext,
+    },
     roll::Roll,
     roll_type::RollType,
+    sacred_weapon::{SacredWeaponBonus, SacredWeaponContext},
+    sneak_attack::{SneakAttackContext, SneakAttackDice},
+    special_ability::{
+        PackTacticsBonus, PackTacticsContext, SunlightSensitivityContext,
+        SunlightSensitivityPenalty,
+    },
+    spell_attack::SpellAttack,
+    spellcasting::{Spellcasting, SpellcastingContext},
+    two_weapon_fighting::{DamageAbilityModifier, DamageAbilityModifierContext, WeaponHand},
+    unarmored_defense::{UnarmoredDefense, UnarmoredDefenseContext},
     weapon_ability_modifier::{AbilityModifierContext, WeaponAbilityModifierContext},
     weapon_proficiency_bonus::{WeaponProficiencyBonus, WeaponProficiencyContext},
+    weapon_range::WeaponRange,
 };
 use std::ops::Deref;
 
@@ -23,7 +58,14 @@ use std::ops::Deref;
 pub struct PhysicalAttackOutcome<'a> {
     pub hit: Hit,
     pub attack_roll_type: RollType<AttackRoll>,
+    /// May differ from the chosen attack roll, e.g. adamantine armor turns
+    /// critical hits into normal hits
+    pub critical_state: CriticalState,
     pub state_and_context: &'a PhysicalAttackStateAndContext<'a>,
+    /// Set when a spell is cast in place of a weapon attack
+    pub spell_attack: Option<&'a SpellAttack<'a>>,
+    /// Set when a monster attacks from its stat block
+    pub monster_attack: Option<&'a MonsterAttack>,
 }
 
 #[derive(Debug)]
@@ -44,7 +86,37 @@ pub struct AttackerPhysicalAttackContext {
     pub weapon_proficiency_bonus: WeaponProficiencyBonus,
     pub armor_proficiency_penalty: ArmorProficiencyPenalty,
     pub ability_modifier_context: AbilityModifierContext,
+    pub martial_arts: MartialArts,
+    /// Off-hand attacks may leave the ability modifier off the damage
+    pub damage_ability_modifier: DamageAbilityModifier,
+    pub fighting_style_bonus: AttackerFightingStyleBonus,
+    /// Imposed by an ally of the defender with the Protection fighting style
+    pub protection_penalty: ProtectionPenalty,
+    pub rage_damage_bonus: RageDamageBonus,
+    pub reckless_attack_bonus: RecklessAttackBonus,
+    /// The defender attacked recklessly on their last turn
+    pub reckless_target_bonus: RecklessTargetBonus,
+    pub paralyzed_target_bonus: ParalyzedTargetBonus,
+    pub pack_tactics_bonus: PackTacticsBonus,
+    pub sunlight_sensitivity_penalty: SunlightSensitivityPenalty,
+    pub unseen_attacker_bonus: UnseenAttackerBonus,
     pub computed_advantage_type: AdvantageType,
+    pub sacred_weapon_bonus: SacredWeaponBonus,
+    pub critical_range: CriticalRange,
+    /// Only dealt on the first hit of the turn that qualifies
+    pub sneak_attack_dice: SneakAttackDice,
+    /// Whether a slot is spent also depends on the hit and the slots left
+    pub divine_smite: DivineSmite,
+    /// Colossus Slayer and Divine Strike share the once per turn limit, as a
+    /// character only has one subclass. Worked out as the turn starts, each
+    /// hit checks the target's hit points again
+    pub colossus_slayer_dice: ColossusSlayerDice,
+    pub divine_strike_dice: DivineStrikeDice,
+    /// Temporary hit points gained if the hit drops the defender
+    pub dark_ones_blessing: DarkOnesBlessing,
+    pub attacks_per_turn: AttacksPerTurn,
+    /// Spell attack bonus and save DC, for casters
+    pub spellcasting: Spellcasting,
 }
 
 #[derive(Debug)]
@@ -52,7 +124,11 @@ pub struct DefenderPhysicalAttackContext {
     pub cover_state_bonus: CanBeTargetedCoverBonus,
     pub cover_state_armor_class_bonus: ArmorClass,
     pub base_armor_class: ArmorClass,
+    pub unarmored_defense: UnarmoredDefense,
+    pub fighting_style_armor_class_bonus: DefenderFightingStyleBonus,
     pub computed_armor_class: ArmorClass,
+    pub critical_hits_negated: bool,
+    pub damage_resistances: DamageResistances,
 }
 
 #[derive(Debug)]
@@ -65,6 +141,7 @@ pub struct PhysicalAttackContext {
 impl TryFrom<&PhysicalAttackState<'_>> for PhysicalAttackContext {
     type Error = CannotBeTargeted;
 
+    #[allow(clippy::too_many_lines)]
     fn try_from(state: &PhysicalAttackState<'_>) -> Result<Self, Self::Error> {
         let prone_context =
             ProneContext::from((&state.attacker.prone_state, &state.defender.prone_state));
@@ -77,33 +154,197 @@ impl TryFrom<&PhysicalAttackState<'_>> for PhysicalAttackContext {
 
         let attacker_weapon_proficiency_bonus =
             WeaponProficiencyBonus::from(WeaponProficiencyContext {
-                class: &state.attacker.class,
-                level: &state.attacker.level,
+                proficiencies: &state.attacker.proficiencies,
+                proficiency_bonus: &state.attacker.proficiency_bonus,
                 weapon_category: state.attacker.weapon.as_ref().map(|w| &w.weapon_category),
                 weapon_type: state.attacker.weapon.as_ref().map(|w| &w.weapon_type),
             });
 
         let attacker_armor_proficiency_penalty =
             ArmorProficiencyPenalty::from(ArmorProficiencyContext {
-                class: &state.attacker.class,
+                proficiencies: &state.attacker.proficiencies,
                 armor_category: state.attacker.armor.as_ref().map(|a| &a.armor_category),
                 armor_primary_material: state.attacker.armor.as_ref().map(|a| &a.primary_material),
             });
 
+        let attacker_martial_arts = MartialArts::from(MartialArtsContext {
+            class: state.attacker.class.as_ref(),
+            level: &state.attacker.level,
+            weapon: state.attacker.weapon,
+        });
+
         let attacker_weapon_ability_modifier_context =
             AbilityModifierContext::from(WeaponAbilityModifierContext {
                 weapon_range: state.attacker.weapon.as_ref().map(|w| &w.weapon_range),
                 ability_scores: &state.attacker.ability_scores,
+                may_use_dexterity: attacker_martial_arts.is_some(),
+            });
+
+        let attacker_fighting_style_bonus =
+            AttackerFightingStyleBonus::from(AttackerFightingStyleContext {
+                fighting_style: state.attacker.fighting_style.as_ref(),
+                weapon_range: state.attacker.weapon.as_ref().map(|w| &w.weapon_range),
+                weapon_properties: state
+                    .attacker
+                    .weapon
+                    .as_ref()
+                    .map(|w| w.properties.as_slice()),
+                wielding_shield: state
+                    .attacker
+                    .armor
+                    .is_some_and(|a| a.armor_category == ArmorCategory::Shield),
+                wielding_off_hand_weapon: state.attacker.off_hand_weapon.is_some(),
+            });
+
+        let attacker_damage_ability_modifier =
+            DamageAbilityModifier::from(DamageAbilityModifierContext {
+                hand: state.attacker.hand,
+                fighting_style: state.attacker.fighting_style.as_ref(),
+                ability_modifier: &attacker_weapon_ability_modifier_context.ability_modifier,
+            });
+
+        let attacker_protection_penalty =
+            ProtectionPenalty::from(state.defender.protecting_ally.as_ref().map(|ally| {
+                ProtectionContext {
+                    ally_fighting_style: ally.fighting_style.as_ref(),
+                    ally_wielding_shield: ally.wielding_shield,
+                }
+            }));
+
+        let attacker_rage_damage_bonus = RageDamageBonus::from(RageDamageContext {
+            raging: state.attacker.raging,
+            level: &state.attacker.level,
+            ability_type: &attacker_weapon_ability_modifier_context.ability_type,
+            weapon_range: state.attacker.weapon.as_ref().map(|w| &w.weapon_range),
+        });
+
+        let attacker_reckless_attack_bonus = RecklessAttackBonus::from(RecklessAttackContext {
+            reckless_attack: state.attacker.reckless_attack,
+            ability_type: &attacker_weapon_ability_modifier_context.ability_type,
+            weapon_range: state.attacker.weapon.as_ref().map(|w| &w.weapon_range),
+        });
+
+        let attacker_reckless_target_bonus = RecklessTargetBonus::from(RecklessTargetContext {
+            attacked_recklessly: state.defender.attacked_recklessly,
+        });
+
+        let attacker_paralyzed_target_bonus = ParalyzedTargetBonus::from(ParalyzedTargetContext {
+            paralyzed: state.defender.conditions.contains(&Condition::Paralyzed),
+        });
+
+        let attacker_pack_tactics_bonus = PackTacticsBonus::from(PackTacticsContext {
+            special_abilities: &state.attacker.special_abilities,
+            ally_adjacent_to_target: state.attacker.ally_adjacent_to_target,
+        });
+
+        let attacker_sunlight_sensitivity_penalty =
+            SunlightSensitivityPenalty::from(SunlightSensitivityContext {
+                special_abilities: &state.attacker.special_abilities,
+                in_sunlight: state.attacker.in_sunlight,
             });
 
+        let attacker_unseen_attacker_bonus = UnseenAttackerBonus::from(UnseenAttackerContext {
+            hidden: state.attacker.hidden,
+        });
+
         let attacker_computed_advantage_type = AdvantageType::from_all_sources(&[
             &attacker_prone_context_bonus,
             &AdvantageType::from(&attacker_armor_proficiency_penalty),
+            &AdvantageType::from(&attacker_protection_penalty),
+            &AdvantageType::from(&attacker_reckless_attack_bonus),
+            &AdvantageType::from(&attacker_reckless_target_bonus),
+            &AdvantageType::from(&attacker_paralyzed_target_bonus),
+            &AdvantageType::from(&attacker_pack_tactics_bonus),
+            &AdvantageType::from(&attacker_sunlight_sensitivity_penalty),
+            &AdvantageType::from(&attacker_unseen_attacker_bonus),
         ]);
 
-        let defender_base_armor_class = state.defender.equipped_armor.map_or_else(
-            || state.defender.armor_class.clone(),
-            |armor| armor.armor_class.clone(),
+        let attacker_sneak_attack_dice = SneakAttackDice::from(SneakAttackContext {
+            class: state.attacker.class.as_ref(),
+            level: &state.attacker.level,
+            weapon_range: state.attacker.weapon.as_ref().map(|w| &w.weapon_range),
+            weapon_properties: state
+                .attacker
+                .weapon
+                .as_ref()
+                .map(|w| w.properties.as_slice()),
+            advantage_type: &attacker_computed_advantage_type,
+            ally_adjacent_to_target: state.attacker.ally_adjacent_to_target,
+        });
+
+        let attacker_divine_smite = DivineSmite::from(DivineSmiteContext {
+            class: state.attacker.class.as_ref(),
+            level: &state.attacker.level,
+            smite_policy: state.attacker.smite_policy.as_ref(),
+            weapon_range: state.attacker.weapon.as_ref().map(|w| &w.weapon_range),
+        });
+
+        let attacker_sacred_weapon_bonus = SacredWeaponBonus::from(SacredWeaponContext {
+            active: state.attacker.sacred_weapon_active,
+            subclass: state.attacker.subclass.as_ref(),
+            level: &state.attacker.level,
+            ability_scores: &state.attacker.ability_scores,
+            weapon: state.attacker.weapon,
+        });
+
+        let attacker_critical_range = CriticalRange::from(ImprovedCriticalContext {
+            subclass: state.attacker.subclass.as_ref(),
+            level: &state.attacker.level,
+        });
+
+        let attacker_colossus_slayer_dice = ColossusSlayerDice::from(ColossusSlayerContext {
+            subclass: state.attacker.subclass.as_ref(),
+            level: &state.attacker.level,
+            weapon: state.attacker.weapon,
+            target_below_hp_max: state.defender.below_hp_max,
+        });
+
+        let attacker_divine_strike_dice = DivineStrikeDice::from(DivineStrikeContext {
+            subclass: state.attacker.subclass.as_ref(),
+            level: &state.attacker.level,
+            weapon: state.attacker.weapon,
+        });
+
+        let attacker_dark_ones_blessing = DarkOnesBlessing::from(DarkOnesBlessingContext {
+            subclass: state.attacker.subclass.as_ref(),
+            level: &state.attacker.level,
+            ability_scores: &state.attacker.ability_scores,
+        });
+
+        let attacker_attacks_per_turn = AttacksPerTurn::from(ExtraAttackContext {
+            class: state.attacker.class.as_ref(),
+            level: &state.attacker.level,
+        });
+
+        let attacker_spellcasting = Spellcasting::from(SpellcastingContext {
+            spellcasting_ability: state.attacker.spellcasting_ability.as_ref(),
+            ability_scores: &state.attacker.ability_scores,
+            proficiency_bonus: &state.attacker.proficiency_bonus,
+        });
+
+        let defender_unarmored_defense = UnarmoredDefense::from(UnarmoredDefenseContext {
+            class: state.defender.class.as_ref(),
+            subclass: state.defender.subclass.as_ref(),
+            ability_scores: &state.defender.ability_scores,
+            armor_category: state.defender.equipped_armor.map(|a| &a.armor_category),
+            default_armor_class: &state.defender.armor_class,
+        });
+
+        // A shield adds its bonus on top of the unarmored formula
+        let defender_base_armor_class = defender_unarmored_defense.as_ref().map_or_else(
+            || {
+                state.defender.equipped_armor.map_or_else(
+                    || state.defender.armor_class.clone(),
+                    |armor| armor.armor_class.clone(),
+                )
+            },
+            |unarmored| {
+                let shield_bonus = state
+                    .defender
+                    .equipped_armor
+                    .map_or(0, |shield| shield.armor_class.value());
+                ArmorClass::new(unarmored.armor_class.value() + shield_bonus)
+            },
         );
 
         let defender_cover_state_armor_class_bonus = defender_bonus_from_cover_state
@@ -111,8 +352,27 @@ impl TryFrom<&PhysicalAttackState<'_>> for PhysicalAttackContext {
             .cloned()
             .unwrap_or(ArmorClass::new(0));
 
+        let defender_critical_hits_negated = state
+            .defender
+            .equipped_armor
+            .is_some_and(|armor| armor.primary_material.negates_critical_hits());
+
+        let defender_fighting_style_armor_class_bonus =
+            DefenderFightingStyleBonus::from(DefenderFightingStyleContext {
+                fighting_style: state.defender.fighting_style.as_ref(),
+                armor_category: state.defender.equipped_armor.map(|a| &a.armor_category),
+            });
+
+        let defender_damage_resistances = DamageResistances::from(DamageResistanceContext {
+            raging: state.defender.raging,
+        });
+
         let defender_total_armor_class = ArmorClass::new(
-            defender_base_armor_class.value() + defender_cover_state_armor_class_bonus.value(),
+            defender_base_armor_class.value()
+                + defender_cover_state_armor_class_bonus.value()
+                + defender_fighting_style_armor_class_bonus
+                    .as_ref()
+                    .map_or(0, ArmorClass::value),
         );
 
         Ok(Self {
@@ -122,20 +382,45 @@ impl TryFrom<&PhysicalAttackState<'_>> for PhysicalAttackContext {
                 weapon_proficiency_bonus: attacker_weapon_proficiency_bonus,
                 armor_proficiency_penalty: attacker_armor_proficiency_penalty,
                 ability_modifier_context: attacker_weapon_ability_modifier_context,
+                martial_arts: attacker_martial_arts,
+                damage_ability_modifier: attacker_damage_ability_modifier,
+                fighting_style_bonus: attacker_fighting_style_bonus,
+                protection_penalty: attacker_protection_penalty,
+                rage_damage_bonus: attacker_rage_damage_bonus,
+                reckless_attack_bonus: attacker_reckless_attack_bonus,
+                reckless_target_bonus: attacker_reckless_target_bonus,
+                paralyzed_target_bonus: attacker_paralyzed_target_bonus,
+                pack_tactics_bonus: attacker_pack_tactics_bonus,
+                sunlight_sensitivity_penalty: attacker_sunlight_sensitivity_penalty,
+                unseen_attacker_bonus: attacker_unseen_attacker_bonus,
                 computed_advantage_type: attacker_computed_advantage_type,
+                sacred_weapon_bonus: attacker_sacred_weapon_bonus,
+                critical_range: attacker_critical_range,
+                sneak_attack_dice: attacker_sneak_attack_dice,
+                divine_smite: attacker_divine_smite,
+                colossus_slayer_dice: attacker_colossus_slayer_dice,
+                divine_strike_dice: attacker_divine_strike_dice,
+                dark_ones_blessing: attacker_dark_ones_blessing,
+                attacks_per_turn: attacker_attacks_per_turn,
+                spellcasting: attacker_spellcasting,
             },
             defender: DefenderPhysicalAttackContext {
                 base_armor_class: defender_base_armor_class,
+                unarmored_defense: defender_unarmored_defense,
                 cover_state_bonus: defender_bonus_from_cover_state,
                 cover_state_armor_class_bonus: defender_cover_state_armor_class_bonus,
+                fighting_style_armor_class_bonus: defender_fighting_style_armor_class_bonus,
                 computed_armor_class: defender_total_armor_class,
+                critical_hits_negated: defender_critical_hits_negated,
+                damage_resistances: defender_damage_resistances,
             },
         })
     }
 }
 
+#[allow(clippy::too_many_lines)]
 pub fn physical_attack_system<'a, A, D>(
-    attacker: &A,
+    attacker: &mut A,
     defender: &mut D,
     state_and_context: &'a PhysicalAttackStateAndContext<'a>,
 ) -> PhysicalAttackOutcome<'a>
@@ -143,7 +428,7 @@ where
     A: Attacker,
     D: Destructible + Combatant + HasArmor,
 {
-    let PhysicalAttackStateAndContext { context, .. } = &state_and_context;
+    let PhysicalAttackStateAndContext { state, context } = &state_and_context;
 
     let attack_roller = || {
         attacker.roll_attack(
@@ -153,6 +438,8 @@ where
                 .ability_modifier
                 .clone(),
             context.attacker.weapon_proficiency_bonus.deref().clone(),
+            context.attacker.fighting_style_bonus.attack_roll_bonus()
+                + context.attacker.sacred_weapon_bonus.unwrap_or(0),
         )
     };
 
@@ -163,29 +450,221 @@ where
         .into();
 
     let chosen_roll = attack_roll_type.chosen_roll();
+    let hits = chosen_roll.total_value() >= context.defender.computed_armor_class.value();
+
+    // An unarmed strike is a melee attack
+    let melee = state
+        .attacker
+        .weapon
+        .is_none_or(|weapon| weapon.weapon_range == WeaponRange::Melee);
+
+    let critical_state = final_critical_state(
+        chosen_roll.critical_state_in_range(*context.attacker.critical_range),
+        hits,
+        melee,
+        context,
+    );
+
+    let damage_roll_context = DamageRollContext {
+        critical_state,
+        reroll_at_or_below: context
+            .attacker
+            .fighting_style_bonus
+            .damage_die_reroll_at_or_below(),
+        damage_bonus: context.attacker.fighting_style_bonus.damage_bonus()
+            + context.attacker.rage_damage_bonus.unwrap_or(0),
+        ability_modifier: context.attacker.damage_ability_modifier.deref().clone(),
+        damage_dice: context
+            .attacker
+            .martial_arts
+            .as_ref()
+            .and_then(|martial_arts| martial_arts.damage_dice),
+    };
 
     PhysicalAttackOutcome {
-        hit: if chosen_roll.total_value() >= context.defender.computed_armor_class.value() {
-            let (damage_roll, damage_type) = attacker.equipped_weapon().map_or_else(
+        hit: if hits {
+            // An unarmed strike ignores the equipped weapons
+            let weapon = state
+                .attacker
+                .weapon
+                .and_then(|_| match state.attacker.hand {
+                    WeaponHand::MainHand => attacker.equipped_weapon(),
+                    WeaponHand::OffHand => attacker.equipped_off_hand_weapon(),
+                });
+            let (damage_roll, damage_type) = weapon.map_or_else(
                 || {
                     (
-                        attacker.roll_damage(Some(chosen_roll)),
+                        attacker.roll_damage_with_context(&damage_roll_context),
                         attacker.damage_type(),
                     )
                 },
-                |weapon| (weapon.roll_damage(Some(chosen_roll)), weapon.damage_type()),
+                |weapon| {
+                    (
+                        weapon.roll_damage_with_context(&damage_roll_context),
+                        weapon.damage_type(),
+                    )
+                },
             );
 
-            let hp_change = defender.take_damage(damage_roll.total_value());
+            // An earlier attack this turn may already have wounded the target
+            let target_below_hp_max = defender.hp() < defender.max_hp();
+            let bonus_damage_rolls = roll_bonus_damage(
+                attacker,
+                state_and_context,
+                damage_type,
+                critical_state,
+                target_below_hp_max,
+            );
+
+            // Resistance applies to the total damage of each type
+            let mut damage_by_type = vec![(damage_type, damage_roll.total_value())];
+            for bonus_damage_roll in &bonus_damage_rolls {
+                match damage_by_type
+                    .iter_mut()
+                    .find(|(damage_type, _)| *damage_type == bonus_damage_roll.damage_type)
+                {
+                    Some((_, damage)) => *damage += bonus_damage_roll.total_value(),
+                    None => damage_by_type.push((
+                        bonus_damage_roll.damage_type,
+                        bonus_damage_roll.total_value(),
+                    )),
+                }
+            }
+
+            let damage_dealt = damage_by_type
+                .iter()
+                .map(|(damage_type, damage)| {
+                    context
+                        .defender
+                        .damage_resistances
+                        .apply(damage_type, *damage)
+                })
+                .sum();
+            let hp_change = defender.take_damage(damage_dealt);
+
+            let temporary_hp_gained = context
+                .attacker
+                .dark_ones_blessing
+                .filter(|_| hp_change.before > 0 && hp_change.after == 0);
+            if let Some(temporary_hp) = temporary_hp_gained {
+                attacker.gain_temporary_hp(temporary_hp);
+            }
+
             Hit::Success {
                 damage_roll,
+                bonus_damage_rolls,
+                damage_dealt,
                 hp_change,
                 damage_type,
+                temporary_hp_gained,
             }
         } else {
             Hit::Miss
         },
         attack_roll_type,
+        critical_state,
         state_and_context,
+        spell_attack: None,
+        monster_attack: None,
     }
 }
+
+/// Adamantine armor turns critical hits into normal hits, while any melee
+/// hit on a paralyzed target is a critical hit
+pub(crate) const fn final_critical_state(
+    rolled_critical_state: CriticalState,
+    hits: bool,
+    melee: bool,
+    context: &PhysicalAttackContext,
+) -> CriticalState {
+    match rolled_critical_state {
+        CriticalState::Critical if context.defender.critical_hits_negated => CriticalState::Normal,
+        CriticalState::Normal
+            if hits
+                && melee
+                && context
+                    .attacker
+                    .paralyzed_target_bonus
+                    .melee_hits_critical()
+                && !context.defender.critical_hits_negated =>
+        {
+            CriticalState::Critical
+        }
+        critical_state => critical_state,
+    }
+}
+
+/// Extra dice from class features, rolled once the attack has hit
+fn roll_bonus_damage<A>(
+    attacker: &mut A,
+    state_and_context: &PhysicalAttackStateAndContext,
+    damage_type: DamageType,
+    critical_state: CriticalState,
+    target_below_hp_max: bool,
+) -> Vec<BonusDamageRoll>
+where
+    A: Attacker,
+{
+    let PhysicalAttackStateAndContext { state, context } = state_and_context;
+
+    let mut bonus_damage_rolls = Vec::new();
+
+    if let Some(dice) = *context.attacker.sneak_attack_dice {
+        if !attacker.sneak_attack_used() {
+            attacker.set_sneak_attack_used(true);
+            bonus_damage_rolls.push(BonusDamageRoll::roll(
+                BonusDamageSource::SneakAttack,
+                dice,
+                damage_type,
+                critical_state,
+            ));
+        }
+    }
+
+    let colossus_slayer_dice = ColossusSlayerDice::from(ColossusSlayerContext {
+        subclass: state.attacker.subclass.as_ref(),
+        level: &state.attacker.level,
+        weapon: state.attacker.weapon,
+        target_below_hp_max,
+    });
+    let subclass_damage = colossus_slayer_dice
+        .map(|dice| (BonusDamageSource::ColossusSlayer, dice, damage_type))
+        .or_else(|| {
+            context
+                .attacker
+                .divine_strike_dice
+                .map(|dice| (BonusDamageSource::DivineStrike, dice, DamageType::Radiant))
+        });
+
+    if let Some((source, dice, damage_type)) = subclass_damage {
+        if !attacker.subclass_damage_used() {
+            attacker.set_subclass_damage_used(true);
+            bonus_damage_rolls.push(BonusDamageRoll::roll(
+                source,
+                dice,
+                damage_type,
+                critical_state,
+            ));
+        }
+    }
+
+    let smite_slot_level = context.attacker.divine_smite.and_then(|smite_policy| {
+        let spell_slots = attacker.spell_slots_mut()?;
+        let slot_level = smite_policy.choose_slot(critical_state, spell_slots)?;
+        spell_slots.expend(slot_level).ok().map(|()| slot_level)
+    });
+
+    if let Some(slot_level) = smite_slot_level {
+        bonus_damage_rolls.push(BonusDamageRoll::roll(
+            BonusDamageSource::DivineSmite { slot_level },
+            *DivineSmiteDice::from(DivineSmiteDiceContext {
+                slot_level,
+                target_creature_type: &state.defender.creature_type,
+            }),
+            DamageType::Radiant,
+            critical_state,
+        ));
+    }
+
+    bonus_damage_rolls
+}
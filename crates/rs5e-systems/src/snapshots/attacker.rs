@@ -1,26 +1,52 @@
 use rs5e_components::{armor::Armor, attacker::Attacker, weapon::Weapon};
 use rs5e_concepts::{
-    ability_scores::AbilityScores, armor::ArmorModel, armor_type::ArmorType, class_type::ClassType,
-    id::Id, level::Level, prone_state::ProneState, weapon::WeaponModel, weapon_type::WeaponType,
+    ability_scores::AbilityScores, ability_type::AbilityType, armor::ArmorModel,
+    armor_type::ArmorType, class_type::ClassType, divine_smite::SmitePolicy,
+    fighting_style::FightingStyle, id::Id, ki::Ki, level::Level, material::Material,
+    proficiency_bonus::ProficiencyBonus, proficiency_type::ProficiencyType,
+    prone_state::ProneState, special_ability::SpecialAbility, spell_slots::SpellSlots,
+    subclass_type::SubclassType, two_weapon_fighting::WeaponHand, weapon::WeaponModel,
+    weapon_type::WeaponType,
 };
 use std::collections::HashMap;
 
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug)]
 pub struct AttackerSnapshot<'a> {
     pub id: Id,
     pub prone_state: ProneState,
     pub level: Level,
-    pub class: ClassType,
+    pub class: Option<ClassType>,
+    pub subclass: Option<SubclassType>,
     pub ability_scores: AbilityScores,
+    pub proficiencies: Vec<ProficiencyType>,
+    pub proficiency_bonus: ProficiencyBonus,
+    pub fighting_style: Option<FightingStyle>,
+    pub raging: bool,
+    pub sacred_weapon_active: bool,
+    pub reckless_attack: bool,
+    /// An ally of the attacker is within 5 feet of the defender
+    pub ally_adjacent_to_target: bool,
+    pub smite_policy: Option<SmitePolicy>,
+    pub spell_slots: Option<SpellSlots>,
+    pub spellcasting_ability: Option<AbilityType>,
+    pub ki_points: Option<u32>,
+    /// The weapon this attack is made with
     pub weapon: Option<&'a WeaponModel>,
+    pub off_hand_weapon: Option<&'a WeaponModel>,
+    pub hand: WeaponHand,
     pub armor: Option<&'a ArmorModel>,
+    pub special_abilities: Vec<SpecialAbility>,
+    pub in_sunlight: bool,
+    /// Hidden from the defender, e.g. with Nimble Escape
+    pub hidden: bool,
 }
 
 impl<'a, 'b> AttackerSnapshot<'a> {
     pub fn from_attacker<A>(
         attacker: &'b A,
         weapon_model_map: &'a HashMap<WeaponType, WeaponModel>,
-        armor_model_map: &'a HashMap<ArmorType, ArmorModel>,
+        armor_model_map: &'a HashMap<(ArmorType, Material), ArmorModel>,
     ) -> Self
     where
         A: Attacker,
@@ -29,14 +55,68 @@ impl<'a, 'b> AttackerSnapshot<'a> {
             id: attacker.id(),
             prone_state: attacker.prone_state().clone(),
             level: attacker.level().clone(),
-            class: attacker.class().clone(),
+            class: attacker.class().cloned(),
+            subclass: attacker.subclass().copied(),
             ability_scores: attacker.ability_scores().clone(),
+            proficiencies: attacker.proficiencies().to_vec(),
+            proficiency_bonus: attacker.proficiency_bonus(),
+            fighting_style: attacker.fighting_style().cloned(),
+            raging: attacker.is_raging(),
+            sacred_weapon_active: attacker.is_sacred_weapon_active(),
+            reckless_attack: attacker.reckless_attack(),
+            ally_adjacent_to_target: false,
+            smite_policy: attacker.smite_policy().copied(),
+            spell_slots: attacker.spell_slots().cloned(),
+            spellcasting_ability: attacker.spellcasting_ability().cloned(),
+            ki_points: attacker.ki().map(Ki::points_remaining),
             weapon: attacker
                 .equipped_weapon()
                 .map(|w| weapon_model_map.get(w.weapon_type()).unwrap()),
-            armor: attacker
-                .equipped_armor()
-                .map(|a| armor_model_map.get(a.armor_type()).unwrap()),
+            off_hand_weapon: attacker
+                .equipped_off_hand_weapon()
+                .map(|w| weapon_model_map.get(w.weapon_type()).unwrap()),
+            hand: WeaponHand::MainHand,
+            armor: attacker.equipped_armor().map(|a| {
+                armor_model_map
+                    .get(&(a.armor_type().clone(), a.primary_material().clone()))
+                    .unwrap()
+            }),
+            special_abilities: attacker.special_abilities().to_vec(),
+            in_sunlight: false,
+            hidden: false,
         }
     }
+
+    #[must_use]
+    pub const fn with_ally_adjacent_to_target(mut self) -> Self {
+        self.ally_adjacent_to_target = true;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_sunlight(mut self) -> Self {
+        self.in_sunlight = true;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Strikes unarmed even with a weapon equipped, e.g. Flurry of Blows
+    #[must_use]
+    pub const fn with_unarmed_strike(mut self) -> Self {
+        self.weapon = None;
+        self
+    }
+
+    /// Attacks with the off-hand weapon as a bonus action
+    #[must_use]
+    pub const fn with_off_hand_attack(mut self) -> Self {
+        self.weapon = self.off_hand_weapon;
+        self.hand = WeaponHand::OffHand;
+        self
+    }
 }
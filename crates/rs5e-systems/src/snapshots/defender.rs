@@ -1,10 +1,14 @@
 use rs5e_components::{
-    armor::Armor, combatant::Combatant, defender::Defender, has_armor::HasArmor,
-    identifiable::Identifiable,
+    armor::Armor, combatant::Combatant, destructible::Destructible, has_abilities::HasAbilities,
+    has_armor::HasArmor, has_class::HasClass, has_conditions::HasConditions,
+    has_creature_type::HasCreatureType, has_fighting_style::HasFightingStyle, has_rage::HasRage,
+    has_reckless_attack::HasRecklessAttack, has_subclass::HasSubclass, identifiable::Identifiable,
 };
 use rs5e_concepts::{
-    armor::ArmorModel, armor_class::ArmorClass, armor_type::ArmorType, cover_state::CoverState,
-    id::Id, prone_state::ProneState,
+    ability_scores::AbilityScores, armor::ArmorModel, armor_category::ArmorCategory,
+    armor_class::ArmorClass, armor_type::ArmorType, class_type::ClassType, condition::Condition,
+    cover_state::CoverState, creature_type::CreatureType, fighting_style::FightingStyle, id::Id,
+    material::Material, prone_state::ProneState, subclass_type::SubclassType,
 };
 use std::collections::HashMap;
 
@@ -13,26 +17,86 @@ pub struct DefenderSnapshot<'a> {
     pub id: Id,
     pub prone_state: ProneState,
     pub cover_state: CoverState,
+    pub creature_type: CreatureType,
+    pub class: Option<ClassType>,
+    pub subclass: Option<SubclassType>,
+    pub ability_scores: AbilityScores,
+    /// Hunters deal Colossus Slayer damage to a wounded defender
+    pub below_hp_max: bool,
     pub armor_class: ArmorClass,
     pub equipped_armor: Option<&'a ArmorModel>,
+    pub fighting_style: Option<FightingStyle>,
+    pub raging: bool,
+    pub attacked_recklessly: bool,
+    /// Conditions imposed on the defender by spells
+    pub conditions: Vec<Condition>,
+    pub protecting_ally: Option<ProtectingAllySnapshot>,
+}
+
+/// An ally within 5 feet of the defender who can use their reaction
+/// on the defender's behalf
+#[derive(Debug)]
+pub struct ProtectingAllySnapshot {
+    pub id: Id,
+    pub fighting_style: Option<FightingStyle>,
+    pub wielding_shield: bool,
 }
 
 impl<'a, 'b> DefenderSnapshot<'a> {
     pub fn from_defender<D>(
         defender: &'b D,
-        armor_model_map: &'a HashMap<ArmorType, ArmorModel>,
+        armor_model_map: &'a HashMap<(ArmorType, Material), ArmorModel>,
     ) -> Self
     where
-        D: Defender + Combatant + HasArmor + Identifiable,
+        D: Destructible
+            + Combatant
+            + HasAbilities
+            + HasArmor
+            + HasClass
+            + HasConditions
+            + HasCreatureType
+            + HasFightingStyle
+            + HasRage
+            + HasRecklessAttack
+            + HasSubclass,
     {
         Self {
             id: defender.id(),
             prone_state: defender.prone_state().clone(),
             cover_state: defender.cover_state().clone(),
+            creature_type: defender.creature_type(),
+            class: defender.class().cloned(),
+            subclass: defender.subclass().copied(),
+            ability_scores: defender.ability_scores().clone(),
+            below_hp_max: defender.hp() < defender.max_hp(),
             armor_class: defender.armor_class().clone(),
-            equipped_armor: defender
-                .equipped_armor()
-                .and_then(|a| armor_model_map.get(a.armor_type())),
+            equipped_armor: defender.equipped_armor().and_then(|a| {
+                armor_model_map.get(&(a.armor_type().clone(), a.primary_material().clone()))
+            }),
+            fighting_style: defender.fighting_style().cloned(),
+            raging: defender.is_raging(),
+            attacked_recklessly: defender.attacked_recklessly(),
+            conditions: defender
+                .conditions()
+                .iter()
+                .map(|spell_condition| spell_condition.condition)
+                .collect(),
+            protecting_ally: None,
         }
     }
+
+    #[must_use]
+    pub fn with_protecting_ally<P>(mut self, ally: &'b P) -> Self
+    where
+        P: HasArmor + HasFightingStyle + Identifiable,
+    {
+        self.protecting_ally = Some(ProtectingAllySnapshot {
+            id: ally.id(),
+            fighting_style: ally.fighting_style().cloned(),
+            wielding_shield: ally
+                .equipped_armor()
+                .is_some_and(|a| a.armor_category() == &ArmorCategory::Shield),
+        });
+        self
+    }
 }
@@ -0,0 +1,95 @@
+use rs5e_components::{
+    destructible::Destructible, has_saving_throws::HasSavingThrows, has_skills::HasSkills,
+    has_special_abilities::HasSpecialAbilities,
+};
+use rs5e_concepts::{
+    ability_type::AbilityType,
+    critical_state::CriticalState,
+    damage_type::DamageType,
+    hide::{Hidden, HideContext},
+    hp_change::HpChange,
+    saving_throw::SavingThrow,
+    special_ability::{
+        regeneration_suppressed_by, SpecialAbility, UndeadFortitudeContext, UndeadFortitudeDc,
+    },
+};
+
+#[derive(Debug)]
+pub struct UndeadFortitudeCheck {
+    pub saving_throw: SavingThrow,
+    /// Set when the saving throw leaves the creature at 1 hit point
+    pub hp_change: Option<HpChange>,
+}
+
+#[derive(Debug)]
+pub struct HideOutcome {
+    pub stealth_check: i32,
+    pub hidden: Hidden,
+}
+
+/// Special traits that react to damage: damage that stops Regeneration is
+/// remembered until the creature's next turn, and Undead Fortitude may keep
+/// a creature dropped to 0 hit points at 1
+pub fn damage_taken_system<C>(
+    combatant: &mut C,
+    damage: u32,
+    damage_types: &[DamageType],
+    critical_state: CriticalState,
+) -> Option<UndeadFortitudeCheck>
+where
+    C: Destructible + HasSavingThrows,
+{
+    if damage == 0 {
+        return None;
+    }
+
+    if let Some(damage_type) =
+        regeneration_suppressed_by(combatant.special_abilities(), damage_types)
+    {
+        combatant.set_regeneration_suppressed_by(Some(damage_type));
+    }
+
+    if combatant.hp() > 0 {
+        return None;
+    }
+
+    let dc = (*UndeadFortitudeDc::from(UndeadFortitudeContext {
+        special_abilities: combatant.special_abilities(),
+        damage,
+        damage_types,
+        critical_state,
+    }))?;
+    let saving_throw = combatant.roll_saving_throw(&AbilityType::Constitution, dc);
+    let hp_change = saving_throw.succeeded().then(|| combatant.regain_hp(1));
+
+    Some(UndeadFortitudeCheck {
+        saving_throw,
+        hp_change,
+    })
+}
+
+/// Nimble Escape lets the hider take the Hide action as a bonus action,
+/// contested by the observer's passive perception
+pub fn nimble_escape_system<H, O>(hider: &H, observer: &O) -> Option<HideOutcome>
+where
+    H: HasSkills + HasSpecialAbilities,
+    O: HasSkills + HasSpecialAbilities,
+{
+    if !hider
+        .special_abilities()
+        .contains(&SpecialAbility::NimbleEscape)
+    {
+        return None;
+    }
+
+    let stealth_check = hider.roll_stealth_check();
+
+    Some(HideOutcome {
+        stealth_check,
+        hidden: Hidden::from(HideContext {
+            stealth_total: stealth_check,
+            passive_perception: observer.passive_perception(),
+            observer_special_abilities: observer.special_abilities(),
+        }),
+    })
+}
@@ -0,0 +1,230 @@
+use crate::physical_attack::{
+    final_critical_state, PhysicalAttackOutcome, PhysicalAttackStateAndContext,
+};
+use rs5e_components::{
+    attacker::Attacker, combatant::Combatant, destructible::Destructible, has_armor::HasArmor,
+    has_level::HasLevel, has_spell_slots::HasSpellSlots,
+};
+use rs5e_concepts::{
+    ability_modifier::AbilityModifier,
+    advantage_type::AdvantageType,
+    attack_roll::AttackRoll,
+    damage_roll::DamageRoll,
+    hit::Hit,
+    roll::Roll,
+    roll_type::RollType,
+    spell::{SpellAttackType, SpellModel},
+    spell_attack::{InvalidAttackSpell, SpellAttack, SpellAttackContext},
+};
+
+/// Casts with the chosen slot, or the next higher one left once those run
+/// out, or the lowest that fits the spell, expending it only once the
+/// target is known to be in range
+///
+/// # Errors
+///
+/// When no slot is left or the target is out of range
+pub fn cast_attack_spell_system<'a, C>(
+    caster: &mut C,
+    spell: &'a SpellModel,
+    chosen_slot_level: Option<u32>,
+    distance: u32,
+) -> Result<SpellAttack<'a>, InvalidAttackSpell>
+where
+    C: HasSpellSlots + HasLevel,
+{
+    let slot_level = choose_spell_slot(caster, spell, chosen_slot_level)?;
+
+    let spell_attack = SpellAttack::try_from(SpellAttackContext {
+        spell,
+        slot_level,
+        level: caster.level(),
+        distance,
+    })?;
+
+    expend_spell_slot(caster, slot_level)?;
+
+    Ok(spell_attack)
+}
+
+/// The chosen slot, or the next higher slot left once those run out. The
+/// lowest slot left that fits the spell without a choice. None for cantrips
+pub(crate) fn choose_spell_slot<C>(
+    caster: &C,
+    spell: &SpellModel,
+    chosen_slot_level: Option<u32>,
+) -> Result<Option<u32>, InvalidAttackSpell>
+where
+    C: HasSpellSlots,
+{
+    if spell.is_cantrip() {
+        return Ok(None);
+    }
+
+    let spell_slots = caster
+        .spell_slots()
+        .ok_or(InvalidAttackSpell::NoSpellcasting)?;
+
+    match chosen_slot_level {
+        Some(slot_level) if slot_level < spell.level => {
+            Err(InvalidAttackSpell::SlotLevelBelowSpellLevel)
+        }
+        slot_level => spell_slots
+            .lowest_available_at_or_above(slot_level.unwrap_or(spell.level))
+            .map(Some)
+            .ok_or(InvalidAttackSpell::NoSpellSlotRemaining),
+    }
+}
+
+pub(crate) fn expend_spell_slot<C>(
+    caster: &mut C,
+    slot_level: Option<u32>,
+) -> Result<(), InvalidAttackSpell>
+where
+    C: HasSpellSlots,
+{
+    if let (Some(slot_level), Some(spell_slots)) = (slot_level, caster.spell_slots_mut()) {
+        spell_slots
+            .expend(slot_level)
+            .map_err(|_| InvalidAttackSpell::NoSpellSlotRemaining)?;
+    }
+
+    Ok(())
+}
+
+/// One attack roll of a spell, using the spellcasting ability in place of
+/// the weapon's. Weapon features such as Sneak Attack do not apply
+pub fn spell_attack_system<'a, A, D>(
+    attacker: &mut A,
+    defender: &mut D,
+    state_and_context: &'a PhysicalAttackStateAndContext<'a>,
+    spell_attack: &'a SpellAttack<'a>,
+) -> PhysicalAttackOutcome<'a>
+where
+    A: Attacker,
+    D: Destructible + Combatant + HasArmor,
+{
+    let PhysicalAttackStateAndContext { context, .. } = &state_and_context;
+
+    let attack_roller = || {
+        context.attacker.spellcasting.as_ref().map_or_else(
+            || attacker.roll_attack(AbilityModifier::new(0), None, 0),
+            |stats| {
+                attacker.roll_attack(
+                    stats.ability_modifier.clone(),
+                    Some(stats.proficiency_bonus.clone()),
+                    0,
+                )
+            },
+        )
+    };
+
+    let advantage_type = AdvantageType::from_all_sources(&[
+        &context.attacker.prone_context_bonus,
+        &AdvantageType::from(&context.attacker.armor_proficiency_penalty),
+        &AdvantageType::from(&context.attacker.protection_penalty),
+        &AdvantageType::from(&context.attacker.reckless_target_bonus),
+        &AdvantageType::from(&context.attacker.paralyzed_target_bonus),
+        &spell_attack.advantage_type,
+    ]);
+
+    let attack_roll_type: RollType<AttackRoll> = (advantage_type, attack_roller).into();
+
+    let chosen_roll = attack_roll_type.chosen_roll();
+    let hits = chosen_roll.total_value() >= context.defender.computed_armor_class.value();
+
+    let critical_state = final_critical_state(
+        chosen_roll.critical_state(),
+        hits,
+        spell_attack.attack_type == SpellAttackType::Melee,
+        context,
+    );
+
+    PhysicalAttackOutcome {
+        hit: if hits {
+            let damage_roll =
+                DamageRoll::from_critical_state(AbilityModifier::new(0), critical_state, &|| {
+                    spell_attack.damage_dice.roll()
+                });
+            let damage_type = spell_attack.damage_type;
+
+            let damage_dealt = context
+                .defender
+                .damage_resistances
+                .apply(&damage_type, damage_roll.total_value());
+            let hp_change = defender.take_damage(damage_dealt);
+
+            let temporary_hp_gained = context
+                .attacker
+                .dark_ones_blessing
+                .filter(|_| hp_change.before > 0 && hp_change.after == 0);
+            if let Some(temporary_hp) = temporary_hp_gained {
+                attacker.gain_temporary_hp(temporary_hp);
+            }
+
+            Hit::Success {
+                damage_roll,
+                bonus_damage_rolls: Vec::new(),
+                damage_dealt,
+                hp_change,
+                damage_type,
+                temporary_hp_gained,
+            }
+        } else {
+            Hit::Miss
+        },
+        attack_roll_type,
+        critical_state,
+        state_and_context,
+        spell_attack: Some(spell_attack),
+        monster_attack: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rs5e_concepts::{class_type::ClassType, spell::SpellRange, spell_slots::SpellSlots};
+
+    struct Caster(SpellSlots);
+
+    impl HasSpellSlots for Caster {
+        fn spell_slots(&self) -> Option<&SpellSlots> {
+            Some(&self.0)
+        }
+
+        fn spell_slots_mut(&mut self) -> Option<&mut SpellSlots> {
+            Some(&mut self.0)
+        }
+    }
+
+    #[test]
+    fn chosen_slot_falls_back_to_the_next_higher_slot_left() {
+        let spell = SpellModel {
+            index: "scorching-ray".to_string(),
+            name: "Scorching Ray".to_string(),
+            level: 2,
+            range: SpellRange::Feet(120),
+            attack_type: Some(SpellAttackType::Ranged),
+            dc: None,
+            damage: None,
+            condition: None,
+            target_creature_type: None,
+            ignores_cover: false,
+            area_of_effect: None,
+            attacks: 3,
+            concentration: false,
+            classes: Vec::from([ClassType::Wizard]),
+        };
+        let mut caster = Caster(SpellSlots::new([4, 1, 0, 1, 0, 0, 0, 0, 0]));
+
+        assert_eq!(choose_spell_slot(&caster, &spell, Some(2)), Ok(Some(2)));
+        expend_spell_slot(&mut caster, Some(2)).unwrap();
+        assert_eq!(choose_spell_slot(&caster, &spell, Some(2)), Ok(Some(4)));
+        expend_spell_slot(&mut caster, Some(4)).unwrap();
+        assert_eq!(
+            choose_spell_slot(&caster, &spell, Some(2)),
+            Err(InvalidAttackSpell::NoSpellSlotRemaining)
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{
+    legendary::legendary_resistance_system,
+    spell_attack::{choose_spell_slot, expend_spell_slot},
+};
+use rs5e_components::{
+    destructible::Destructible, has_abilities::HasAbilities, has_class::HasClass,
+    has_conditions::HasConditions, has_creature_type::HasCreatureType,
+    has_legendary_actions::HasLegendaryActions, has_level::HasLevel,
+    has_proficiencies::HasProficiencies, has_rage::HasRage, has_saving_throws::HasSavingThrows,
+    has_spell_slots::HasSpellSlots, has_spellcasting::HasSpellcasting, identifiable::Identifiable,
+    named::Named,
+};
+use rs5e_concepts::{
+    condition::{Condition, SpellCondition},
+    damage_resistance::{DamageResistanceContext, DamageResistances},
+    evasion::{Evasion, EvasionContext},
+    hp_change::HpChange,
+    id::Id,
+    rage::Rage,
+    saving_throw::SavingThrow,
+    spell::SpellModel,
+    spell_attack::InvalidAttackSpell,
+    spell_save::{SaveDamage, SaveDamageContext, SpellSave, SpellSaveContext},
+    spellcasting::{Spellcasting, SpellcastingContext},
+};
+
+/// Casts with the chosen slot, or the next higher one left once those run
+/// out, or the lowest that fits the spell
+///
+/// Spells which only impose a condition are not cast at a target already
+/// suffering it, nor any spell at a creature type it does not affect
+///
+/// # Errors
+///
+/// When no slot is left, the target is out of range, of the wrong creature
+/// type or already affected
+pub fn cast_save_spell_system<'a, C, T>(
+    caster: &mut C,
+    spell: &'a SpellModel,
+    chosen_slot_level: Option<u32>,
+    distance: u32,
+    target: &T,
+) -> Result<SpellSave<'a>, InvalidAttackSpell>
+where
+    C: HasSpellSlots + HasLevel,
+    T: HasConditions + HasCreatureType,
+{
+    if !spell.affects(target.creature_type()) {
+        return Err(InvalidAttackSpell::WrongCreatureType);
+    }
+
+    let slot_level = choose_spell_slot(caster, spell, chosen_slot_level)?;
+
+    let spell_save = SpellSave::try_from(SpellSaveContext {
+        spell,
+        slot_level,
+        level: caster.level(),
+        distance,
+    })?;
+
+    if spell_save.damage_dice.is_none()
+        && spell_save
+            .condition
+            .is_some_and(|condition| target.has_condition(&condition))
+    {
+        return Err(InvalidAttackSpell::AlreadyAffected);
+    }
+
+    expend_spell_slot(caster, slot_level)?;
+
+    Ok(spell_save)
+}
+
+#[derive(Debug)]
+pub struct SpellSaveTargetOutcome {
+    pub id: Id,
+    pub name: String,
+    pub saving_throw: SavingThrow,
+    pub evasion: bool,
+    pub save_damage: SaveDamage,
+    /// After resistances
+    pub damage_dealt: u32,
+    pub hp_change: Option<HpChange>,
+    pub condition_imposed: Option<Condition>,
+    /// An incapacitating condition ends the target's rage
+    pub rage_ended: bool,
+    /// A frenzied rage ending leaves the berserker exhausted
+    pub exhaustion_gained: bool,
+}
+
+#[derive(Debug)]
+pub struct SpellSaveOutcome<'a> {
+    pub spell_save: &'a SpellSave<'a>,
+    pub save_dc: u32,
+    /// Rolled once for all targets
+    pub damage_roll: Option<u32>,
+    pub targets: Vec<SpellSaveTargetOutcome>,
+}
+
+/// Every target saves against the caster's spell save DC. Damage is rolled
+/// once and each target takes their share of it
+pub fn spell_save_system<'a, C, T>(
+    caster: &C,
+    targets: &mut [&mut T],
+    spell_save: &'a SpellSave<'a>,
+) -> SpellSaveOutcome<'a>
+where
+    C: HasAbilities + HasProficiencies + HasSpellcasting + Identifiable,
+    T: Destructible + HasSavingThrows + HasClass + HasRage + HasLegendaryActions + Named,
+{
+    let save_dc = Spellcasting::from(SpellcastingContext {
+        spellcasting_ability: caster.spellcasting_ability(),
+        ability_scores: caster.ability_scores(),
+        proficiency_bonus: &caster.proficiency_bonus(),
+    })
+    .as_ref()
+    .map_or(0, |stats| stats.save_dc);
+
+    let damage_roll = spell_save.damage_dice.map(|dice| dice.roll());
+
+    let targets = targets
+        .iter_mut()
+        .map(|target| {
+            let saving_throw = target.roll_saving_throw_against_spell(
+                &spell_save.dc.dc_type,
+                save_dc,
+                spell_save.spell.ignores_cover,
+            );
+            let saving_throw =
+                legendary_resistance_system(*target, saving_throw, spell_save.condition.is_some());
+            let saved = saving_throw.succeeded();
+
+            let evasion = *Evasion::from(EvasionContext {
+                class: target.class(),
+                level: target.level(),
+            });
+            let save_damage = SaveDamage::from(SaveDamageContext {
+                dc: spell_save.dc,
+                saved,
+                evasion,
+            });
+
+            let damage_resistances = DamageResistances::from(DamageResistanceContext {
+                raging: target.is_raging(),
+            });
+            let damage_dealt = match (damage_roll, spell_save.damage_type) {
+                (Some(damage), Some(damage_type)) => {
+                    damage_resistances.apply(&damage_type, save_damage.apply(damage))
+                }
+                (Some(damage), None) => save_damage.apply(damage),
+                (None, _) => 0,
+            };
+            let hp_change = damage_roll.map(|_| target.take_damage(damage_dealt));
+
+            let condition_imposed = spell_save.condition.filter(|_| !saved);
+            if let Some(condition) = condition_imposed {
+                target.conditions_mut().push(SpellCondition {
+                    condition,
+                    caster: caster.id(),
+                    spell_name: spell_save.spell.name.clone(),
+                    dc_type: spell_save.dc.dc_type.clone(),
+                    save_dc,
+                });
+            }
+
+            let rage_ended = condition_imposed.is_some_and(|condition| condition.incapacitates())
+                && target.rage_mut().is_some_and(Rage::end);
+            let exhaustion_gained = rage_ended && target.rage().is_some_and(Rage::frenzy);
+
+            SpellSaveTargetOutcome {
+                id: target.id(),
+                name: target.name().to_string(),
+                saving_throw,
+                evasion,
+                save_damage,
+                damage_dealt,
+                hp_change,
+                condition_imposed,
+                rage_ended,
+                exhaustion_gained,
+            }
+        })
+        .collect();
+
+    SpellSaveOutcome {
+        spell_save,
+        save_dc,
+        damage_roll,
+        targets,
+    }
+}
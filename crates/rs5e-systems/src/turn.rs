@@ -0,0 +1,177 @@
+use crate::legendary::legendary_resistance_system;
+use rs5e_components::{
+    destructible::Destructible, has_conditions::HasConditions, has_ki::HasKi,
+    has_legendary_actions::HasLegendaryActions, has_limited_uses::HasLimitedUses,
+    has_rage::HasRage, has_reckless_attack::HasRecklessAttack, has_sacred_weapon::HasSacredWeapon,
+    has_saving_throws::HasSavingThrows, has_sneak_attack::HasSneakAttack,
+    has_special_abilities::HasSpecialAbilities, has_subclass::HasSubclass,
+};
+use rs5e_concepts::{
+    condition::SpellCondition,
+    hp_change::HpChange,
+    ki::{MartialArtsBonusAction, FLURRY_OF_BLOWS_KI_COST},
+    rage::Rage,
+    sacred_weapon::SacredWeapon,
+    saving_throw::SavingThrow,
+    special_ability::{RegenerationContext, RegenerationHitPoints, RegenerationSuppressed},
+    usage::{LimitedUse, RechargeRoll},
+};
+
+#[derive(Debug)]
+pub struct TurnStartOutcome {
+    /// Entering a rage uses the combatant's bonus action
+    pub entered_rage: bool,
+    /// Channelling Sacred Weapon spends the paladin's Channel Divinity
+    pub sacred_weapon_activated: bool,
+    /// `None` for combatants without Regeneration
+    pub regeneration: Option<Result<HpChange, RegenerationSuppressed>>,
+    /// One roll for every spent action that recharges, e.g. a breath weapon
+    pub recharges: Vec<RechargeRoll>,
+}
+
+#[derive(Debug)]
+pub struct TurnEndOutcome {
+    pub rage_ended: bool,
+    pub sacred_weapon_ended: bool,
+    /// A frenzied rage ending leaves the berserker exhausted
+    pub exhaustion_gained: bool,
+}
+
+#[derive(Debug)]
+pub struct ConditionSavingThrow {
+    pub spell_condition: SpellCondition,
+    pub saving_throw: SavingThrow,
+}
+
+/// Bonus actions taken before attacking, which an incapacitated combatant
+/// cannot take, hit points regained through Regeneration, rolls to
+/// recharge spent actions and legendary actions regained
+///
+/// Sacred Weapon is channelled at the start of the first turn, standing in
+/// for the action a paladin spends on it just before the fight
+pub fn start_turn_system<C>(combatant: &mut C, in_sunlight: bool) -> TurnStartOutcome
+where
+    C: Destructible
+        + HasConditions
+        + HasRage
+        + HasSacredWeapon
+        + HasSneakAttack
+        + HasSubclass
+        + HasSpecialAbilities
+        + HasLimitedUses
+        + HasLegendaryActions,
+{
+    let regeneration = *RegenerationHitPoints::from(RegenerationContext {
+        special_abilities: combatant.special_abilities(),
+        suppressed_by: combatant.regeneration_suppressed_by(),
+        in_sunlight,
+    });
+    let regeneration = regeneration
+        .filter(|_| combatant.hp() > 0)
+        .map(|hit_points| hit_points.map(|hit_points| combatant.regain_hp(hit_points)));
+    combatant.set_regeneration_suppressed_by(None);
+
+    let recharges = combatant
+        .limited_uses_mut()
+        .iter_mut()
+        .filter_map(LimitedUse::roll_recharge)
+        .collect();
+    if let Some(legendary_action_points) = combatant.legendary_action_points_mut() {
+        legendary_action_points.refresh();
+    }
+
+    combatant.set_sneak_attack_used(false);
+    combatant.set_subclass_damage_used(false);
+
+    let incapacitated = combatant.is_incapacitated();
+    let entered_rage = match combatant.rage_mut() {
+        Some(rage) if !rage.is_raging() && !incapacitated => rage.enter().is_ok(),
+        _ => false,
+    };
+    let sacred_weapon_activated = match combatant.sacred_weapon_mut() {
+        Some(sacred_weapon) if !sacred_weapon.is_active() && !incapacitated => {
+            sacred_weapon.activate().is_ok()
+        }
+        _ => false,
+    };
+
+    TurnStartOutcome {
+        entered_rage,
+        sacred_weapon_activated,
+        regeneration,
+        recharges,
+    }
+}
+
+/// Monks follow an attack with an unarmed strike or a monk weapon with a
+/// bonus action, spending ki on Flurry of Blows while they have it
+pub fn martial_arts_bonus_action_system<C>(combatant: &mut C) -> MartialArtsBonusAction
+where
+    C: HasKi,
+{
+    match combatant
+        .ki_mut()
+        .map(|ki| ki.spend(FLURRY_OF_BLOWS_KI_COST))
+    {
+        Some(Ok(())) => MartialArtsBonusAction::FlurryOfBlows,
+        _ => MartialArtsBonusAction::UnarmedStrike,
+    }
+}
+
+pub fn end_turn_system<C>(combatant: &mut C, attacks_made: u32) -> TurnEndOutcome
+where
+    C: HasRage + HasSacredWeapon + HasRecklessAttack,
+{
+    let attacked = attacks_made > 0;
+
+    let rage_ended = combatant
+        .rage_mut()
+        .is_some_and(|rage| rage.end_turn(attacked));
+    let sacred_weapon_ended = combatant
+        .sacred_weapon_mut()
+        .is_some_and(SacredWeapon::end_turn);
+
+    combatant.set_attacked_recklessly(attacked && combatant.reckless_attack());
+
+    let exhaustion_gained = rage_ended && combatant.rage().is_some_and(Rage::frenzy);
+
+    TurnEndOutcome {
+        rage_ended,
+        sacred_weapon_ended,
+        exhaustion_gained,
+    }
+}
+
+/// Each condition imposed by a spell allows a new saving throw at the end
+/// of the creature's turn, ending it on a success
+pub fn condition_saving_throws_system<C>(combatant: &mut C) -> Vec<ConditionSavingThrow>
+where
+    C: HasSavingThrows + HasLegendaryActions,
+{
+    let saving_throws: Vec<_> = combatant
+        .conditions()
+        .to_vec()
+        .into_iter()
+        .map(|spell_condition| {
+            let saving_throw = combatant.roll_saving_throw_against_spell(
+                &spell_condition.dc_type,
+                spell_condition.save_dc,
+                false,
+            );
+
+            ConditionSavingThrow {
+                saving_throw: legendary_resistance_system(combatant, saving_throw, true),
+                spell_condition,
+            }
+        })
+        .collect();
+
+    combatant.conditions_mut().retain(|spell_condition| {
+        !saving_throws.iter().any(|condition_saving_throw| {
+            condition_saving_throw.saving_throw.succeeded()
+                && &condition_saving_throw.spell_condition == spell_condition
+        })
+    });
+
+    saving_throws
+}
@@ -1,27 +1,135 @@
 use rs5e_concepts::{
-    armor::ArmorModel, armor_category::ArmorCategory, armor_class::ArmorClass,
-    armor_type::ArmorType, damage_type::DamageType, weapon::WeaponModel,
-    weapon_category::WeaponCategory, weapon_range::WeaponRange, weapon_type::WeaponType,
+    ability_scores::{AbilityScore, AbilityScores},
+    ability_type::AbilityType,
+    armor::ArmorModel,
+    armor_category::ArmorCategory,
+    armor_class::ArmorClass,
+    armor_type::ArmorType,
+    challenge_rating::ChallengeRating,
+    class_type::ClassType,
+    condition::Condition,
+    creature_type::CreatureType,
+    damage_type::DamageType,
+    legendary::{LegendaryAction, LegendaryActionEffect, LEGENDARY_RESISTANCE},
+    level::{Level, MAX_LEVEL},
+    material::MaterialRestriction,
+    monster::MonsterModel,
+    monster_action::{
+        MonsterAttack, MonsterDamage, MonsterSaveAction, Multiattack, MultiattackStep,
+    },
+    proficiency_type::{ProficiencyType, ProficiencyTypeByClassMap},
+    senses::Senses,
+    special_ability::{Regeneration, SpecialAbility},
+    spell::{
+        AreaOfEffect, AreaOfEffectType, SpellAttackType, SpellDamage, SpellDc, SpellDcSuccess,
+        SpellModel, SpellRange,
+    },
+    spell_slots::MAX_SPELL_LEVEL,
+    spellcasting::{ClassSpellcasting, SpellcastingByClassMap},
+    tool_type::ToolType,
+    usage::{Recharge, Usage},
+    weapon::WeaponModel,
+    weapon_category::WeaponCategory,
+    weapon_property::WeaponProperty,
+    weapon_range::WeaponRange,
+    weapon_type::WeaponType,
 };
-use rs5e_dice::Dice;
+use rs5e_dice::{Dice, Die, DieType};
 use rs5e_schema::equipment::EquipmentSchema;
-use rs5e_schema::{armor::ArmorSchema, weapon::WeaponSchema};
+use rs5e_schema::{
+    armor::ArmorSchema,
+    class::ClassSchema,
+    level::LevelSchema,
+    monster::{
+        MonsterActionSchema, MonsterDamageRollSchema, MonsterDamageSchema, MonsterSchema,
+        MonsterSpecialAbilitySchema, MonsterUsageSchema, MultiattackActionSchema,
+        MultiattackCountSchema, MultiattackOptionSchema,
+    },
+    proficiency::ProficiencySchema,
+    spell::SpellSchema,
+    weapon::WeaponSchema,
+};
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use std::str::FromStr;
 
-pub(crate) fn read_equipment_file<P>(data_dir: P) -> String
+fn read_data_file<P>(data_dir: P, file_name: &str) -> String
 where
     P: AsRef<Path>,
 {
-    let file_path = data_dir.as_ref().join("5e-SRD-Equipment.json");
+    let file_path = data_dir.as_ref().join(file_name);
 
     std::fs::read_to_string(file_path).expect("Should have been able to read the file")
 }
 
+pub(crate) fn read_equipment_file<P>(data_dir: P) -> String
+where
+    P: AsRef<Path>,
+{
+    read_data_file(data_dir, "5e-SRD-Equipment.json")
+}
+
+pub(crate) fn read_classes_file<P>(data_dir: P) -> String
+where
+    P: AsRef<Path>,
+{
+    read_data_file(data_dir, "5e-SRD-Classes.json")
+}
+
+pub(crate) fn read_proficiencies_file<P>(data_dir: P) -> String
+where
+    P: AsRef<Path>,
+{
+    read_data_file(data_dir, "5e-SRD-Proficiencies.json")
+}
+
+pub(crate) fn read_levels_file<P>(data_dir: P) -> String
+where
+    P: AsRef<Path>,
+{
+    read_data_file(data_dir, "5e-SRD-Levels.json")
+}
+
+pub(crate) fn read_spells_file<P>(data_dir: P) -> String
+where
+    P: AsRef<Path>,
+{
+    read_data_file(data_dir, "5e-SRD-Spells.json")
+}
+
+pub(crate) fn read_monsters_file<P>(data_dir: P) -> String
+where
+    P: AsRef<Path>,
+{
+    read_data_file(data_dir, "5e-SRD-Monsters.json")
+}
+
 pub(crate) fn deserialize_equipment(equipment_file_string: &str) -> Vec<EquipmentSchema<'_>> {
     serde_json::from_str(equipment_file_string).unwrap()
 }
 
+pub(crate) fn deserialize_classes(classes_file_string: &str) -> Vec<ClassSchema<'_>> {
+    serde_json::from_str(classes_file_string).unwrap()
+}
+
+pub(crate) fn deserialize_proficiencies(
+    proficiencies_file_string: &str,
+) -> Vec<ProficiencySchema<'_>> {
+    serde_json::from_str(proficiencies_file_string).unwrap()
+}
+
+pub(crate) fn deserialize_levels(levels_file_string: &str) -> Vec<LevelSchema<'_>> {
+    serde_json::from_str(levels_file_string).unwrap()
+}
+
+pub(crate) fn deserialize_spells(spells_file_string: &str) -> Vec<SpellSchema<'_>> {
+    serde_json::from_str(spells_file_string).unwrap()
+}
+
+pub(crate) fn deserialize_monsters(monsters_file_string: &str) -> Vec<MonsterSchema<'_>> {
+    serde_json::from_str(monsters_file_string).unwrap()
+}
+
 pub(crate) fn weapon_model_from_weapon_schema(weapon_schema: &WeaponSchema<'_, '_>) -> WeaponModel {
     WeaponModel {
         weapon_type: WeaponType::from_str(weapon_schema.index).unwrap(),
@@ -29,15 +137,933 @@ pub(crate) fn weapon_model_from_weapon_schema(weapon_schema: &WeaponSchema<'_, '
         damage_dice: Dice::from_str(weapon_schema.damage.damage_dice).unwrap(),
         weapon_range: WeaponRange::from_str(weapon_schema.weapon_range).unwrap(),
         weapon_category: WeaponCategory::from_str(weapon_schema.weapon_category).unwrap(),
+        properties: weapon_schema
+            .properties
+            .iter()
+            .map(|property| WeaponProperty::from_str(property.index).unwrap())
+            .collect(),
     }
 }
 
-pub(crate) fn armor_model_from_armor_schema(armor_schema: &ArmorSchema<'_, '_>) -> ArmorModel {
+/// One model for every material variant the armor can be made of
+pub(crate) fn armor_models_from_armor_schema(
+    armor_schema: &ArmorSchema<'_, '_>,
+) -> Vec<ArmorModel> {
     let armor_type = ArmorType::from_str(armor_schema.index).unwrap();
-    ArmorModel {
-        primary_material: armor_type.primary_material(),
-        armor_type,
-        armor_class: ArmorClass::new(armor_schema.armor_class.base),
-        armor_category: ArmorCategory::from_str(armor_schema.armor_category).unwrap(),
+    armor_type
+        .material_variants()
+        .iter()
+        .map(|material| ArmorModel {
+            armor_type: armor_type.clone(),
+            armor_class: ArmorClass::new(armor_schema.armor_class.base),
+            armor_category: ArmorCategory::from_str(armor_schema.armor_category).unwrap(),
+            primary_material: material.clone(),
+            stealth_disadvantage: armor_schema.stealth_disadvantage
+                && !material.removes_stealth_disadvantage(),
+        })
+        .collect()
+}
+
+/// A single SRD proficiency can grant several of ours, e.g. "All Armor"
+pub(crate) fn proficiency_types_from_proficiency_schema(
+    proficiency_schema: &ProficiencySchema<'_>,
+) -> Vec<ProficiencyType> {
+    let reference = proficiency_schema.reference.index;
+
+    match proficiency_schema.proficiency_type {
+        "Armor" => match reference {
+            "armor" => Vec::from([
+                ProficiencyType::ArmorCategory(ArmorCategory::Light),
+                ProficiencyType::ArmorCategory(ArmorCategory::Medium),
+                ProficiencyType::ArmorCategory(ArmorCategory::Heavy),
+            ]),
+            _ => Vec::from([ProficiencyType::ArmorCategory(
+                ArmorCategory::from_str(reference.trim_end_matches("-armor")).unwrap(),
+            )]),
+        },
+        "Weapons" => Vec::from([match reference.strip_suffix("-weapons") {
+            Some(category) => {
+                ProficiencyType::WeaponCategory(WeaponCategory::from_str(category).unwrap())
+            }
+            None => ProficiencyType::WeaponType(WeaponType::from_str(reference).unwrap()),
+        }]),
+        "Saving Throws" => Vec::from([ProficiencyType::SavingThrow(
+            AbilityType::from_str(reference).unwrap(),
+        )]),
+        _ => Vec::from([ProficiencyType::Tool(
+            ToolType::from_str(reference).unwrap(),
+        )]),
+    }
+}
+
+pub(crate) fn proficiency_type_by_class_map_from_schemas(
+    class_schemas: &[ClassSchema<'_>],
+    proficiency_schemas: &[ProficiencySchema<'_>],
+) -> ProficiencyTypeByClassMap {
+    let proficiency_schema_map = proficiency_schemas
+        .iter()
+        .map(|proficiency_schema| (proficiency_schema.index, proficiency_schema))
+        .collect::<HashMap<_, _>>();
+
+    class_schemas
+        .iter()
+        .map(|class_schema| {
+            let class_type = ClassType::from_str(class_schema.index).unwrap();
+
+            let mut proficiencies = class_schema
+                .proficiencies
+                .iter()
+                .flat_map(|relation| {
+                    proficiency_types_from_proficiency_schema(
+                        proficiency_schema_map.get(relation.index).unwrap(),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            // The SRD proficiency data does not encode that druids will not
+            // wear armor or use shields made of metal
+            if class_type == ClassType::Druid {
+                proficiencies.push(ProficiencyType::ArmorMaterialRestriction(
+                    MaterialRestriction::Nonmetal,
+                ));
+            }
+
+            (class_type, proficiencies)
+        })
+        .collect()
+}
+
+/// Spell slot tables come from the class levels, the ability from the class
+pub(crate) fn spellcasting_by_class_map_from_schemas(
+    class_schemas: &[ClassSchema<'_>],
+    level_schemas: &[LevelSchema<'_>],
+) -> SpellcastingByClassMap {
+    class_schemas
+        .iter()
+        .filter_map(|class_schema| {
+            let spellcasting_schema = class_schema.spellcasting.as_ref()?;
+            let mut spell_slots = [[0; MAX_SPELL_LEVEL as usize]; MAX_LEVEL as usize];
+            let mut cantrips_known = [0; MAX_LEVEL as usize];
+            let mut spells_known = [0; MAX_LEVEL as usize];
+            let mut knows_spells = false;
+
+            for level_schema in level_schemas.iter().filter(|level_schema| {
+                level_schema.subclass.is_none() && level_schema.class.index == class_schema.index
+            }) {
+                if let Some(spellcasting) = level_schema.spellcasting.as_ref() {
+                    let index = level_schema.level as usize - 1;
+                    cantrips_known[index] = spellcasting.cantrips_known.unwrap_or(0);
+                    if let Some(known) = spellcasting.spells_known {
+                        spells_known[index] = known;
+                        knows_spells = true;
+                    }
+                    spell_slots[index] = [
+                        spellcasting.spell_slots_level_1,
+                        spellcasting.spell_slots_level_2,
+                        spellcasting.spell_slots_level_3,
+                        spellcasting.spell_slots_level_4,
+                        spellcasting.spell_slots_level_5,
+                        spellcasting.spell_slots_level_6,
+                        spellcasting.spell_slots_level_7,
+                        spellcasting.spell_slots_level_8,
+                        spellcasting.spell_slots_level_9,
+                    ];
+                }
+            }
+
+            Some((
+                ClassType::from_str(class_schema.index).unwrap(),
+                ClassSpellcasting {
+                    ability: AbilityType::from_str(spellcasting_schema.spellcasting_ability.index)
+                        .unwrap(),
+                    level: Level::try_from(spellcasting_schema.level).unwrap(),
+                    spell_slots,
+                    cantrips_known,
+                    // Only classes that know a set number of spells list it
+                    spells_known: knows_spells.then_some(spells_known),
+                },
+            ))
+        })
+        .collect()
+}
+
+// A few damage entries are not plain dice, e.g. "1d4 + 1" for magic missile
+// or "1d8 + MOD" for cure wounds, and are left out
+fn dice_by_level(damage_by_level: &HashMap<&str, &str>) -> BTreeMap<u32, Dice> {
+    damage_by_level
+        .iter()
+        .filter_map(|(level, dice)| Some((level.parse().ok()?, Dice::from_str(dice).ok()?)))
+        .collect()
+}
+
+pub(crate) fn spell_model_from_spell_schema(spell_schema: &SpellSchema<'_>) -> SpellModel {
+    SpellModel {
+        index: spell_schema.index.to_string(),
+        name: spell_schema.name.to_string(),
+        level: spell_schema.level,
+        range: SpellRange::from_str(spell_schema.range).unwrap(),
+        // The SRD spell data leaves out that scorching ray makes three ranged
+        // spell attacks
+        attack_type: match spell_schema.index {
+            "scorching-ray" => Some(SpellAttackType::Ranged),
+            _ => spell_schema
+                .attack_type
+                .map(|attack_type| SpellAttackType::from_str(attack_type).unwrap()),
+        },
+        dc: spell_schema.dc.as_ref().map(|dc| SpellDc {
+            dc_type: AbilityType::from_str(dc.dc_type.index).unwrap(),
+            dc_success: SpellDcSuccess::from_str(dc.dc_success).unwrap(),
+        }),
+        damage: spell_schema.damage.as_ref().map(|damage| SpellDamage {
+            damage_type: damage
+                .damage_type
+                .as_ref()
+                .map(|damage_type| DamageType::from_str(damage_type.index).unwrap()),
+            damage_at_slot_level: dice_by_level(&damage.damage_at_slot_level),
+            damage_at_character_level: dice_by_level(&damage.damage_at_character_level),
+        }),
+        // Conditions are only described in the SRD spell text
+        condition: match spell_schema.index {
+            "hold-person" | "hold-monster" => Some(Condition::Paralyzed),
+            _ => None,
+        },
+        target_creature_type: match spell_schema.index {
+            "hold-person" => Some(CreatureType::Humanoid),
+            _ => None,
+        },
+        ignores_cover: spell_schema.index == "sacred-flame",
+        area_of_effect: spell_schema
+            .area_of_effect
+            .as_ref()
+            .map(|area_of_effect| AreaOfEffect {
+                area_type: AreaOfEffectType::from_str(area_of_effect.area_type).unwrap(),
+                size: area_of_effect.size,
+            }),
+        attacks: if spell_schema.index == "scorching-ray" {
+            3
+        } else {
+            1
+        },
+        concentration: spell_schema.concentration,
+        classes: spell_schema
+            .classes
+            .iter()
+            .map(|class| ClassType::from_str(class.index).unwrap())
+            .collect(),
+    }
+}
+
+/// Reads the range in feet a sense is written with, e.g. "60 ft."
+fn sense_range(sense: Option<&str>) -> Option<u32> {
+    sense.map(|sense| {
+        sense
+            .split_whitespace()
+            .next()
+            .and_then(|feet| feet.parse().ok())
+            .unwrap()
+    })
+}
+
+/// Splits damage such as "2d10+8" into its dice and bonus. Flat damage, e.g.
+/// "1", is rolled as that many 1d1
+fn monster_damage_dice(damage_dice: &str) -> (Dice, i32) {
+    let (dice, damage_bonus) = match damage_dice.find(['+', '-']) {
+        Some(sign) => (
+            &damage_dice[..sign],
+            damage_dice[sign..].trim_start_matches('+').parse().unwrap(),
+        ),
+        None => (damage_dice, 0),
+    };
+
+    match dice.parse() {
+        Ok(flat_damage) => (Dice::new(flat_damage, Die::new(DieType::D1)), damage_bonus),
+        Err(_) => (Dice::from_str(dice).unwrap(), damage_bonus),
+    }
+}
+
+fn monster_damage(damage_roll: &MonsterDamageRollSchema<'_>) -> MonsterDamage {
+    let (damage_dice, damage_bonus) = monster_damage_dice(damage_roll.damage_dice);
+
+    MonsterDamage {
+        damage_type: DamageType::from_str(damage_roll.damage_type.index).unwrap(),
+        damage_dice,
+        damage_bonus,
+    }
+}
+
+/// Actions with an attack roll and damage. Damage that needs a saving throw,
+/// e.g. an assassin's poison, is left out, and a versatile weapon uses its
+/// first listed damage
+fn monster_attack(action: &MonsterActionSchema<'_>) -> Option<MonsterAttack> {
+    let damage = action
+        .damage
+        .iter()
+        .filter_map(|damage| match damage {
+            MonsterDamageSchema::Roll(damage_roll) => Some(damage_roll),
+            MonsterDamageSchema::Choice(damage_choice) => damage_choice.from.options.first(),
+        })
+        .filter(|damage_roll| damage_roll.dc.is_none())
+        .map(monster_damage)
+        .collect::<Vec<_>>();
+
+    if damage.is_empty() {
+        return None;
+    }
+
+    Some(MonsterAttack {
+        name: action.name.to_string(),
+        // "Melee or Ranged Weapon Attack" is made in melee
+        attack_range: if action.desc.contains("Ranged") && !action.desc.contains("Melee") {
+            WeaponRange::Ranged
+        } else {
+            WeaponRange::Melee
+        },
+        attack_bonus: action.attack_bonus?,
+        damage,
+    })
+}
+
+fn usage(usage: &MonsterUsageSchema<'_>) -> Usage {
+    match usage.usage_type {
+        "recharge on roll" => Usage::RechargeOnRoll(Recharge {
+            dice: Dice::from_str(usage.dice.unwrap()).unwrap(),
+            min_value: usage.min_value.unwrap(),
+        }),
+        "per day" => Usage::PerDay(usage.times.unwrap()),
+        "recharge after rest" => Usage::RechargeAfterRest,
+        usage_type => panic!("unknown usage type {usage_type}"),
+    }
+}
+
+/// Actions without an attack roll that deal damage on a failed saving
+/// throw, e.g. a breath weapon
+fn monster_save_action(action: &MonsterActionSchema<'_>) -> Option<MonsterSaveAction> {
+    if action.attack_bonus.is_some() {
+        return None;
+    }
+    let dc = action.dc.as_ref()?;
+
+    let damage = action
+        .damage
+        .iter()
+        .filter_map(|damage| match damage {
+            MonsterDamageSchema::Roll(damage_roll) => Some(monster_damage(damage_roll)),
+            MonsterDamageSchema::Choice(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    if damage.is_empty() {
+        return None;
+    }
+
+    Some(MonsterSaveAction {
+        name: action.name.to_string(),
+        dc: SpellDc {
+            dc_type: AbilityType::from_str(dc.dc_type.index).unwrap(),
+            // A few breath weapons are listed with no effect on a success
+            // while their description halves the damage
+            dc_success: if action.desc.contains("half as much damage") {
+                SpellDcSuccess::Half
+            } else {
+                SpellDcSuccess::from_str(dc.success_type).unwrap()
+            },
+        },
+        dc_value: dc.dc_value,
+        damage,
+        usage: action.usage.as_ref().map(usage),
+    })
+}
+
+/// Multiattack may name an attack slightly differently, e.g. "Claws" for
+/// "Claw" or "Bite (Bat or Vampire Form Only)" for "Bite", so the name is
+/// matched against the monster's attacks
+fn multiattack_step(
+    multiattack_action: &MultiattackActionSchema<'_>,
+    attacks: &[MonsterAttack],
+) -> Option<MultiattackStep> {
+    let action_name = multiattack_action.action_name;
+    let attack = attacks
+        .iter()
+        .find(|attack| attack.name == action_name)
+        .or_else(|| {
+            attacks.iter().find(|attack| {
+                action_name.starts_with(&attack.name)
+                    || attack.name == action_name.trim_end_matches('s')
+            })
+        })?;
+
+    Some(MultiattackStep {
+        attack_name: attack.name.clone(),
+        // Counts that vary, e.g. a hydra's heads or a violet fungus's 1d4,
+        // are taken as a single attack
+        count: match multiattack_action.count {
+            MultiattackCountSchema::Fixed(count) => count,
+            MultiattackCountSchema::Described(_) => 1,
+        },
+    })
+}
+
+/// Where Multiattack offers a choice, the first option is taken
+fn multiattack(action: &MonsterActionSchema<'_>, attacks: &[MonsterAttack]) -> Multiattack {
+    let multiattack_actions = match &action.action_options {
+        Some(action_choice) => match action_choice.from.options.first() {
+            Some(MultiattackOptionSchema::Multiple { items }) => items.iter().collect(),
+            Some(MultiattackOptionSchema::Single(multiattack_action)) => {
+                Vec::from([multiattack_action])
+            }
+            None => Vec::new(),
+        },
+        None => action.actions.iter().collect::<Vec<_>>(),
+    };
+
+    Multiattack {
+        steps: multiattack_actions
+            .into_iter()
+            .filter(|multiattack_action| multiattack_action.action_type != "ability")
+            .filter_map(|multiattack_action| multiattack_step(multiattack_action, attacks))
+            .collect(),
+    }
+}
+
+/// Reads Regeneration's hit points and what stops it from its description,
+/// e.g. "regains 10 hit points ... If the troll takes acid or fire damage"
+fn regeneration(desc: &str) -> Regeneration {
+    let hit_points = desc
+        .split_once("regains ")
+        .and_then(|(_, rest)| rest.split_whitespace().next())
+        .and_then(|hit_points| hit_points.parse().ok())
+        .unwrap();
+
+    Regeneration {
+        hit_points,
+        suppressed_by: desc
+            .split_once("takes ")
+            .and_then(|(_, rest)| rest.split_once(" damage"))
+            .map(|(damage_types, _)| {
+                damage_types
+                    .split(" or ")
+                    .filter_map(|damage_type| DamageType::from_str(damage_type).ok())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        suppressed_in_sunlight: desc.contains("sunlight"),
+    }
+}
+
+/// Keen Senses covers every "Keen" trait, e.g. Keen Smell or Keen Hearing
+/// and Sight
+fn special_ability(special_ability: &MonsterSpecialAbilitySchema<'_>) -> SpecialAbility {
+    match special_ability.name {
+        "Pack Tactics" => SpecialAbility::PackTactics,
+        "Magic Resistance" => SpecialAbility::MagicResistance,
+        "Regeneration" => SpecialAbility::Regeneration(regeneration(&special_ability.desc)),
+        "Sunlight Sensitivity" => SpecialAbility::SunlightSensitivity,
+        "Undead Fortitude" => SpecialAbility::UndeadFortitude,
+        "Nimble Escape" => SpecialAbility::NimbleEscape,
+        "Brute" => SpecialAbility::Brute,
+        LEGENDARY_RESISTANCE => match special_ability.usage.as_ref().map(usage) {
+            Some(Usage::PerDay(times)) => SpecialAbility::LegendaryResistance(times),
+            _ => SpecialAbility::NotImplemented(LEGENDARY_RESISTANCE.to_string()),
+        },
+        name if name.starts_with("Keen ") => SpecialAbility::KeenSenses,
+        name => SpecialAbility::NotImplemented(name.to_string()),
+    }
+}
+
+/// Legendary actions name the attack or action they take, e.g. "Tail
+/// Attack" or "The kraken uses Lightning Storm", or describe their own
+/// saving throw, e.g. a dragon's wing attack
+fn legendary_action(
+    action: &MonsterActionSchema<'_>,
+    attacks: &[MonsterAttack],
+    save_actions: &[MonsterSaveAction],
+) -> LegendaryAction {
+    let (name, cost) = match action.name.split_once(" (Costs ") {
+        Some((name, cost)) => (
+            name,
+            cost.split_whitespace()
+                .next()
+                .and_then(|cost| cost.parse().ok())
+                .unwrap(),
+        ),
+        None => (action.name, 1),
+    };
+
+    // "makes one claw attack or tail attack" takes the first attack named,
+    // "makes one attack with its hooves" falls back to the action's name
+    let named_action = ["makes one ", "makes a ", "uses its "]
+        .into_iter()
+        .find_map(|phrase| action.desc.split_once(phrase))
+        .map(|(_, rest)| {
+            rest.split([' ', '.'])
+                .take_while(|word| !["attack", "or", "and", ""].contains(word))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|named_action| !named_action.is_empty())
+        .unwrap_or_else(|| name.trim_end_matches(" Attack").to_string())
+        .to_lowercase();
+    let named = |action_name: &str| action_name.to_lowercase().starts_with(&named_action);
+
+    let effect = match (
+        attacks.iter().find(|attack| named(&attack.name)),
+        save_actions
+            .iter()
+            .find(|save_action| named(&save_action.name)),
+    ) {
+        (Some(attack), _) => LegendaryActionEffect::Attack(attack.clone()),
+        (None, Some(save_action)) => LegendaryActionEffect::SaveAction(save_action.clone()),
+        (None, None) => monster_save_action(action).map_or(
+            LegendaryActionEffect::NotImplemented,
+            |save_action| {
+                LegendaryActionEffect::SaveAction(MonsterSaveAction {
+                    name: name.to_string(),
+                    ..save_action
+                })
+            },
+        ),
+    };
+
+    LegendaryAction {
+        name: name.to_string(),
+        cost,
+        effect,
+    }
+}
+
+pub(crate) fn monster_model_from_monster_schema(
+    monster_schema: &MonsterSchema<'_>,
+) -> MonsterModel {
+    let ability_score = |score| AbilityScore::try_from(score).unwrap();
+    let attacks = monster_schema
+        .actions
+        .iter()
+        .filter_map(monster_attack)
+        .collect::<Vec<_>>();
+    let multiattack = monster_schema
+        .actions
+        .iter()
+        .find(|action| action.multiattack_type.is_some())
+        .map(|action| multiattack(action, &attacks))
+        .filter(|multiattack| !multiattack.steps.is_empty());
+    let save_actions = monster_schema
+        .actions
+        .iter()
+        .filter_map(monster_save_action)
+        .collect::<Vec<_>>();
+
+    MonsterModel {
+        index: monster_schema.index.to_string(),
+        name: monster_schema.name.to_string(),
+        // A swarm is made up of creatures of one type, e.g. "swarm of Tiny beasts"
+        creature_type: match monster_schema.creature_type.strip_prefix("swarm of ") {
+            Some(swarm) => CreatureType::from_str(
+                swarm
+                    .split_whitespace()
+                    .last()
+                    .unwrap()
+                    .trim_end_matches('s'),
+            )
+            .unwrap(),
+            None => CreatureType::from_str(monster_schema.creature_type).unwrap(),
+        },
+        armor_class: ArmorClass::new(monster_schema.armor_class.first().unwrap().value),
+        hit_points: monster_schema.hit_points,
+        ability_scores: AbilityScores {
+            str: ability_score(monster_schema.strength),
+            dex: ability_score(monster_schema.dexterity),
+            con: ability_score(monster_schema.constitution),
+            int: ability_score(monster_schema.intelligence),
+            wis: ability_score(monster_schema.wisdom),
+            cha: ability_score(monster_schema.charisma),
+        },
+        // Skill proficiencies are left out until skills are supported, apart
+        // from Stealth
+        proficiencies: monster_schema
+            .proficiencies
+            .iter()
+            .filter_map(|proficiency| {
+                proficiency
+                    .proficiency
+                    .index
+                    .strip_prefix("saving-throw-")
+                    .map(|ability| {
+                        ProficiencyType::SavingThrow(AbilityType::from_str(ability).unwrap())
+                    })
+            })
+            .collect(),
+        challenge_rating: ChallengeRating::try_from(monster_schema.challenge_rating).unwrap(),
+        xp: monster_schema.xp,
+        senses: Senses {
+            blindsight: sense_range(monster_schema.senses.blindsight),
+            darkvision: sense_range(monster_schema.senses.darkvision),
+            tremorsense: sense_range(monster_schema.senses.tremorsense),
+            truesight: sense_range(monster_schema.senses.truesight),
+            passive_perception: monster_schema.senses.passive_perception,
+        },
+        stealth: monster_schema
+            .proficiencies
+            .iter()
+            .find(|proficiency| proficiency.proficiency.index == "skill-stealth")
+            .map(|proficiency| proficiency.value),
+        special_abilities: monster_schema
+            .special_abilities
+            .iter()
+            .map(special_ability)
+            .collect(),
+        legendary_actions: monster_schema
+            .legendary_actions
+            .iter()
+            .map(|action| legendary_action(action, &attacks, &save_actions))
+            .collect(),
+        attacks,
+        multiattack,
+        save_actions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rs5e_concepts::material::Material;
+
+    fn data_dir() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("data/src")
+    }
+
+    fn load_proficiency_type_by_class_map() -> ProficiencyTypeByClassMap {
+        let classes_file_string = read_classes_file(data_dir());
+        let classes = deserialize_classes(&classes_file_string);
+        let proficiencies_file_string = read_proficiencies_file(data_dir());
+        let proficiencies = deserialize_proficiencies(&proficiencies_file_string);
+
+        proficiency_type_by_class_map_from_schemas(&classes, &proficiencies)
+    }
+
+    #[test]
+    fn every_class_proficiency_is_loaded_from_data() {
+        let map = load_proficiency_type_by_class_map();
+        assert_eq!(map.len(), 12);
+
+        let wizard = map.get(&ClassType::Wizard).unwrap();
+        let mut expected = vec![
+            ProficiencyType::WeaponType(WeaponType::Dagger),
+            ProficiencyType::WeaponType(WeaponType::Dart),
+            ProficiencyType::WeaponType(WeaponType::Sling),
+            ProficiencyType::WeaponType(WeaponType::Quarterstaff),
+            ProficiencyType::WeaponType(WeaponType::CrossbowLight),
+            ProficiencyType::SavingThrow(AbilityType::Intelligence),
+            ProficiencyType::SavingThrow(AbilityType::Wisdom),
+        ];
+        let mut wizard = wizard.clone();
+        wizard.sort_by_key(|proficiency| format!("{proficiency:?}"));
+        expected.sort_by_key(|proficiency| format!("{proficiency:?}"));
+        assert_eq!(wizard, expected);
+
+        let rogue = map.get(&ClassType::Rogue).unwrap();
+        for proficiency in [
+            ProficiencyType::WeaponCategory(WeaponCategory::Simple),
+            ProficiencyType::WeaponType(WeaponType::CrossbowHand),
+            ProficiencyType::WeaponType(WeaponType::Longsword),
+            ProficiencyType::WeaponType(WeaponType::Rapier),
+            ProficiencyType::WeaponType(WeaponType::Shortsword),
+            ProficiencyType::ArmorCategory(ArmorCategory::Light),
+            ProficiencyType::Tool(ToolType::ThievesTools),
+            ProficiencyType::SavingThrow(AbilityType::Dexterity),
+            ProficiencyType::SavingThrow(AbilityType::Intelligence),
+        ] {
+            assert!(
+                rogue.contains(&proficiency),
+                "rogue should have {proficiency:?}"
+            );
+        }
+        assert!(!rogue.contains(&ProficiencyType::ArmorCategory(ArmorCategory::Medium)));
+        assert!(!rogue.contains(&ProficiencyType::WeaponCategory(WeaponCategory::Martial)));
+    }
+
+    #[test]
+    fn spell_slots_and_abilities_are_loaded_from_data() {
+        let classes_file_string = read_classes_file(data_dir());
+        let classes = deserialize_classes(&classes_file_string);
+        let levels_file_string = read_levels_file(data_dir());
+        let levels = deserialize_levels(&levels_file_string);
+
+        let map = spellcasting_by_class_map_from_schemas(&classes, &levels);
+        let level = |level| Level::try_from(level).unwrap();
+
+        assert_eq!(map.len(), 8);
+        assert!(!map.contains_key(&ClassType::Fighter));
+
+        let wizard = map.get(&ClassType::Wizard).unwrap();
+        assert_eq!(wizard.ability, AbilityType::Intelligence);
+        assert_eq!(wizard.spell_slots(&level(3)).maximum(2), 2);
+        assert_eq!(wizard.spell_slots(&level(17)).maximum(9), 1);
+
+        let paladin = map.get(&ClassType::Paladin).unwrap();
+        assert_eq!(paladin.ability, AbilityType::Charisma);
+        assert!(!paladin.is_available_at(&level(1)));
+        assert_eq!(paladin.spell_slots(&level(5)).maximum(1), 4);
+        assert_eq!(paladin.spell_slots(&level(5)).maximum(2), 2);
+
+        assert_eq!(wizard.cantrips_known(&level(4)), 4);
+        assert_eq!(wizard.spells_known(&level(4)), None);
+
+        let warlock = map.get(&ClassType::Warlock).unwrap();
+        assert_eq!(warlock.spell_slots(&level(5)).maximum(1), 0);
+        assert_eq!(warlock.spell_slots(&level(5)).maximum(3), 2);
+        assert_eq!(warlock.spells_known(&level(5)), Some(6));
+    }
+
+    #[test]
+    fn attack_spells_are_loaded_from_data() {
+        let spells_file_string = read_spells_file(data_dir());
+        let spells = deserialize_spells(&spells_file_string)
+            .iter()
+            .map(|spell_schema| {
+                (
+                    spell_schema.index,
+                    spell_model_from_spell_schema(spell_schema),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        let level = |level| Level::try_from(level).unwrap();
+
+        let fire_bolt = spells.get("fire-bolt").unwrap();
+        assert!(fire_bolt.is_cantrip());
+        assert_eq!(fire_bolt.range, SpellRange::Feet(120));
+        assert_eq!(fire_bolt.attack_type, Some(SpellAttackType::Ranged));
+        assert_eq!(
+            fire_bolt.damage_dice(None, &level(11)),
+            Dice::from_str("3d10").ok()
+        );
+
+        let inflict_wounds = spells.get("inflict-wounds").unwrap();
+        assert_eq!(inflict_wounds.range, SpellRange::Touch);
+        assert_eq!(inflict_wounds.attack_type, Some(SpellAttackType::Melee));
+        assert_eq!(
+            inflict_wounds.damage_dice(Some(2), &level(3)),
+            Dice::from_str("4d10").ok()
+        );
+        assert!(inflict_wounds.classes.contains(&ClassType::Cleric));
+
+        let fireball = spells.get("fireball").unwrap();
+        assert_eq!(
+            fireball.dc,
+            Some(SpellDc {
+                dc_type: AbilityType::Dexterity,
+                dc_success: SpellDcSuccess::Half,
+            })
+        );
+        assert_eq!(
+            fireball.area_of_effect,
+            Some(AreaOfEffect {
+                area_type: AreaOfEffectType::Sphere,
+                size: 20,
+            })
+        );
+        assert_eq!(
+            fireball.damage_dice(Some(5), &level(9)),
+            Dice::from_str("10d6").ok()
+        );
+        assert!(spells.get("sacred-flame").unwrap().ignores_cover);
+        assert!(!fireball.ignores_cover);
+
+        let hold_person = spells.get("hold-person").unwrap();
+        assert_eq!(hold_person.condition, Some(Condition::Paralyzed));
+        assert!(hold_person.affects(CreatureType::Humanoid));
+        assert!(!hold_person.affects(CreatureType::Undead));
+        assert!(spells
+            .get("hold-monster")
+            .unwrap()
+            .affects(CreatureType::Undead));
+
+        let scorching_ray = spells.get("scorching-ray").unwrap();
+        assert_eq!(scorching_ray.attack_type, Some(SpellAttackType::Ranged));
+        assert_eq!(scorching_ray.attacks, 3);
+        assert_eq!(
+            scorching_ray.damage_dice(Some(4), &level(7)),
+            Dice::from_str("2d6").ok()
+        );
+    }
+
+    #[test]
+    fn druid_proficiencies_match_data() {
+        let map = load_proficiency_type_by_class_map();
+        let druid = map.get(&ClassType::Druid).unwrap();
+
+        assert!(druid.contains(&ProficiencyType::WeaponType(WeaponType::Scimitar)));
+        assert!(druid.contains(&ProficiencyType::Tool(ToolType::HerbalismKit)));
+        assert!(druid.contains(&ProficiencyType::ArmorMaterialRestriction(
+            MaterialRestriction::Nonmetal
+        )));
+        assert!(!druid.contains(&ProficiencyType::WeaponCategory(WeaponCategory::Martial)));
+    }
+
+    #[test]
+    fn all_armor_expands_to_every_armor_category() {
+        let map = load_proficiency_type_by_class_map();
+        let fighter = map.get(&ClassType::Fighter).unwrap();
+
+        assert!(fighter.contains(&ProficiencyType::ArmorCategory(ArmorCategory::Heavy)));
+        assert!(fighter.contains(&ProficiencyType::ArmorCategory(ArmorCategory::Shield)));
+    }
+
+    #[test]
+    fn armor_material_variants_carry_rules_effects() {
+        let equipment_file_string = read_equipment_file(data_dir());
+        let equipment = deserialize_equipment(&equipment_file_string);
+
+        let armor_models = equipment
+            .iter()
+            .filter_map(|equip| ArmorSchema::try_from(equip).ok())
+            .flat_map(|armor_schema| armor_models_from_armor_schema(&armor_schema))
+            .collect::<Vec<_>>();
+
+        let plate_variants = armor_models
+            .iter()
+            .filter(|model| model.armor_type == ArmorType::PlateArmor)
+            .collect::<Vec<_>>();
+
+        assert_eq!(plate_variants.len(), 3);
+        assert!(plate_variants
+            .iter()
+            .any(|model| model.primary_material == Material::Metal && model.stealth_disadvantage));
+        assert!(plate_variants.iter().any(
+            |model| model.primary_material == Material::Mithral && !model.stealth_disadvantage
+        ));
+
+        let shield_materials = armor_models
+            .iter()
+            .filter(|model| model.armor_type == ArmorType::Shield)
+            .map(|model| model.primary_material.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            shield_materials,
+            Vec::from([Material::Wood, Material::Metal])
+        );
+    }
+
+    #[test]
+    fn monsters_are_loaded_from_data() {
+        let monsters_file_string = read_monsters_file(data_dir());
+        let monsters = deserialize_monsters(&monsters_file_string)
+            .iter()
+            .map(|monster_schema| {
+                (
+                    monster_schema.index,
+                    monster_model_from_monster_schema(monster_schema),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        let goblin = monsters.get("goblin").unwrap();
+        assert_eq!(goblin.creature_type, CreatureType::Humanoid);
+        assert_eq!(goblin.armor_class, ArmorClass::new(15));
+        assert_eq!(goblin.hit_points, 7);
+        assert_eq!(goblin.challenge_rating, ChallengeRating::Quarter);
+        assert_eq!(goblin.challenge_rating.proficiency_bonus().value(), 2);
+        assert_eq!(goblin.senses.darkvision, Some(60));
+        assert_eq!(goblin.senses.passive_perception, 9);
+        let scimitar = goblin.attack_sequence()[0];
+        assert_eq!(scimitar.name, "Scimitar");
+        assert_eq!(scimitar.attack_bonus, 4);
+        assert_eq!(
+            scimitar.damage,
+            [MonsterDamage {
+                damage_type: DamageType::Slashing,
+                damage_dice: Dice::from_str("1d6").unwrap(),
+                damage_bonus: 2,
+            }]
+        );
+
+        let adult_red_dragon = monsters.get("adult-red-dragon").unwrap();
+        assert_eq!(
+            adult_red_dragon.challenge_rating,
+            ChallengeRating::Whole(17)
+        );
+        assert_eq!(
+            adult_red_dragon
+                .challenge_rating
+                .proficiency_bonus()
+                .value(),
+            6
+        );
+        assert!(adult_red_dragon
+            .proficiencies
+            .contains(&ProficiencyType::SavingThrow(AbilityType::Dexterity)));
+        assert_eq!(
+            adult_red_dragon
+                .attack_sequence()
+                .iter()
+                .map(|attack| attack.name.as_str())
+                .collect::<Vec<_>>(),
+            ["Bite", "Claw", "Claw"]
+        );
+        assert_eq!(
+            adult_red_dragon.attacks[0].damage[1].damage_type,
+            DamageType::Fire
+        );
+
+        let fire_breath = &adult_red_dragon.save_actions[0];
+        assert_eq!(fire_breath.name, "Fire Breath");
+        assert_eq!(fire_breath.dc.dc_type, AbilityType::Dexterity);
+        assert_eq!(fire_breath.dc.dc_success, SpellDcSuccess::Half);
+        assert_eq!(fire_breath.dc_value, 21);
+        assert_eq!(fire_breath.damage[0].damage_type, DamageType::Fire);
+        assert_eq!(
+            fire_breath.usage,
+            Some(Usage::RechargeOnRoll(Recharge {
+                dice: Dice::from_str("1d6").unwrap(),
+                min_value: 5,
+            }))
+        );
+
+        assert_eq!(
+            adult_red_dragon.legendary_actions[2],
+            LegendaryAction {
+                name: "Wing Attack".to_string(),
+                cost: 2,
+                effect: LegendaryActionEffect::SaveAction(MonsterSaveAction {
+                    name: "Wing Attack".to_string(),
+                    dc: SpellDc {
+                        dc_type: AbilityType::Dexterity,
+                        dc_success: SpellDcSuccess::None,
+                    },
+                    dc_value: 22,
+                    damage: Vec::from([MonsterDamage {
+                        damage_type: DamageType::Bludgeoning,
+                        damage_dice: Dice::from_str("2d6").unwrap(),
+                        damage_bonus: 8,
+                    }]),
+                    usage: None,
+                }),
+            }
+        );
+        assert!(adult_red_dragon
+            .special_abilities
+            .contains(&SpecialAbility::LegendaryResistance(3)));
+
+        let swarm_of_rats = monsters.get("swarm-of-rats").unwrap();
+        assert_eq!(swarm_of_rats.creature_type, CreatureType::Beast);
+        assert_eq!(
+            swarm_of_rats.special_abilities,
+            [
+                SpecialAbility::KeenSenses,
+                SpecialAbility::NotImplemented("Swarm".to_string())
+            ]
+        );
+
+        assert_eq!(goblin.stealth, Some(6));
+        assert_eq!(goblin.special_abilities, [SpecialAbility::NimbleEscape]);
+
+        let troll = monsters.get("troll").unwrap();
+        assert!(troll
+            .special_abilities
+            .contains(&SpecialAbility::Regeneration(Regeneration {
+                hit_points: 10,
+                suppressed_by: Vec::from([DamageType::Acid, DamageType::Fire]),
+                suppressed_in_sunlight: false,
+            })));
     }
 }
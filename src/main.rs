@@ -4,21 +4,60 @@ use axum::{
     Router,
 };
 use data_model::{
-    armor_model_from_armor_schema, deserialize_equipment, read_equipment_file,
-    weapon_model_from_weapon_schema,
+    armor_models_from_armor_schema, deserialize_classes, deserialize_equipment, deserialize_levels,
+    deserialize_monsters, deserialize_proficiencies, deserialize_spells,
+    monster_model_from_monster_schema, proficiency_type_by_class_map_from_schemas,
+    read_classes_file, read_equipment_file, read_levels_file, read_monsters_file,
+    read_proficiencies_file, read_spells_file, spell_model_from_spell_schema,
+    spellcasting_by_class_map_from_schemas, weapon_model_from_weapon_schema,
+};
+use routes::{
+    get_constants::get_constants, get_monsters::get_monsters, get_spells::get_spells,
+    get_weapons::get_weapons, post_attack::post_attack, post_encounter::post_encounter,
 };
-use routes::{get_constants::get_constants, get_weapons::get_weapons, post_attack::post_attack};
 use rs5e_concepts::{
-    ability_scores::AbilityScores, armor::ArmorModel, armor_type::ArmorType, class_type::ClassType,
-    cover_state::CoverState, hp::Hp, id::Id, level::Level, prone_state::ProneState,
-    weapon::WeaponModel, weapon_type::WeaponType,
+    ability_scores::AbilityScores,
+    armor::ArmorModel,
+    armor_category::ArmorCategory,
+    armor_type::ArmorType,
+    class_type::ClassType,
+    cover_state::CoverState,
+    divine_smite::SmitePolicy,
+    fighting_style::FightingStyle,
+    hp::Hp,
+    id::Id,
+    ki::Ki,
+    legendary::{LegendaryActionPoints, LegendaryResistancePolicy},
+    level::{Level, MAX_LEVEL, MIN_LEVEL},
+    material::Material,
+    monster::MonsterModelMap,
+    position::Position,
+    proficiency_type::ProficiencyTypeByClassMap,
+    prone_state::ProneState,
+    rage::Rage,
+    sacred_weapon::SacredWeapon,
+    spell::{SpellModel, SpellModelMap},
+    spell_attack::{validate_attack_spell, InvalidAttackSpell},
+    spell_selection::{
+        validate_spell_selection, InvalidSpellSelection, SpellLimits, SpellLimitsContext,
+    },
+    spellcasting::SpellcastingByClassMap,
+    subclass_type::SubclassType,
+    two_weapon_fighting::{validate_off_hand_weapon, InvalidOffHandWeapon},
+    weapon::WeaponModel,
+    weapon_type::WeaponType,
+};
+use rs5e_entities::{
+    armor::ArmorEntity, character::CharacterEntity, creature::CreatureEntity,
+    monster::MonsterEntity, weapon::WeaponEntity,
 };
-use rs5e_entities::{armor::ArmorEntity, character::CharacterEntity, weapon::WeaponEntity};
+use rs5e_log::describe::Describe;
 use rs5e_schema::{armor::ArmorSchema, weapon::WeaponSchema};
 use serde::Deserialize;
 use std::{
     collections::HashMap,
     env,
+    fmt::Display,
     net::{IpAddr, Ipv4Addr, SocketAddr},
     path::Path,
     sync::Arc,
@@ -41,7 +80,11 @@ pub(crate) const STATIC_CLIENT_DIR: &str = "client/dist";
 #[derive(Clone)]
 pub(crate) struct AppState {
     pub weapon_model_map: Arc<HashMap<WeaponType, WeaponModel>>,
-    pub armor_model_map: Arc<HashMap<ArmorType, ArmorModel>>,
+    pub armor_model_map: Arc<HashMap<(ArmorType, Material), ArmorModel>>,
+    pub proficiency_type_by_class_map: Arc<ProficiencyTypeByClassMap>,
+    pub spellcasting_by_class_map: Arc<SpellcastingByClassMap>,
+    pub spell_model_map: Arc<SpellModelMap>,
+    pub monster_model_map: Arc<MonsterModelMap>,
 }
 
 #[typeshare]
@@ -52,43 +95,506 @@ struct CharacterBuilder {
     level: u32,
     hp: u32,
     class: ClassType,
+    /// Must belong to the class and be available at the level
+    subclass: Option<SubclassType>,
     ability_scores: AbilityScores,
     weapon_type: Option<WeaponType>,
+    /// A light weapon held alongside a light main-hand weapon, attacking as a
+    /// bonus action
+    off_hand_weapon_type: Option<WeaponType>,
     armor_type: Option<ArmorType>,
+    /// Defaults to the primary material of the armor type
+    armor_material: Option<Material>,
+    fighting_style: Option<FightingStyle>,
+    /// Barbarians enter a rage on their first turn
+    #[serde(default)]
+    rage: bool,
+    /// Berserkers rage into a frenzy, attacking as a bonus action
+    #[serde(default)]
+    frenzy: bool,
+    /// Barbarians of 2nd level or higher attack recklessly on every turn
+    #[serde(default)]
+    reckless_attack: bool,
+    /// Paladins of 2nd level or higher decide when to spend a spell slot on
+    /// Divine Smite
+    smite_policy: Option<SmitePolicy>,
+    /// SRD index of a spell cast instead of attacking with a weapon, e.g.
+    /// "fire-bolt"
+    attack_spell: Option<String>,
+    /// Slot level to cast the attack spell with, moving up to the next
+    /// higher slot left once those run out. The lowest left that fits the
+    /// spell if left out
+    attack_spell_slot_level: Option<u32>,
+    /// SRD indexes of the known or prepared spells, cantrips included. The
+    /// attack spell is always among them
+    #[serde(default)]
+    spells: Vec<String>,
     // These are not properties of a unit but rather of circumstance
     prone_state: ProneState,
     cover_state: CoverState,
+    /// Placed from the distance between the attacker and the target if left out
+    position: Option<Position>,
+}
+
+#[typeshare]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct MonsterBuilder {
+    /// SRD index of the monster's stat block, e.g. "goblin"
+    monster: String,
+    /// Defaults to the stat block's name
+    name: Option<String>,
+    /// When Legendary Resistance turns a failed saving throw into a success,
+    /// for monsters that have it
+    legendary_resistance_policy: Option<LegendaryResistancePolicy>,
+    prone_state: ProneState,
+    cover_state: CoverState,
+    /// Placed from the distance between the attacker and the target if left out
+    position: Option<Position>,
+}
+
+/// A combatant is either built up as a character or taken from a monster's
+/// stat block
+#[typeshare]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase", tag = "t", content = "c")]
+enum CombatantBuilder {
+    Character(CharacterBuilder),
+    Monster(MonsterBuilder),
+}
+
+impl CombatantBuilder {
+    fn position_mut(&mut self) -> &mut Option<Position> {
+        match self {
+            Self::Character(builder) => &mut builder.position,
+            Self::Monster(builder) => &mut builder.position,
+        }
+    }
+}
+
+fn monster_from_builder(
+    builder: MonsterBuilder,
+    monster_model_map: &MonsterModelMap,
+) -> Result<MonsterEntity<'_>, InvalidCharacterBuilder> {
+    let Some(model) = monster_model_map.get(&builder.monster) else {
+        return Err(InvalidCharacterBuilder::UnknownMonster {
+            monster: builder.monster,
+        });
+    };
+
+    Ok(MonsterEntity {
+        id: Id::new_incremental(),
+        name: builder.name.unwrap_or_else(|| model.name.clone()),
+        model,
+        hp: Hp::new(model.hit_points),
+        ability_scores: model.ability_scores.clone(),
+        level: Level::default(),
+        prone_state: builder.prone_state,
+        cover_state: builder.cover_state,
+        position: builder.position.unwrap_or_default(),
+        conditions: Vec::new(),
+        concentration: None,
+        regeneration_suppressed_by: None,
+        limited_uses: model.limited_uses(),
+        legendary_action_points: (!model.legendary_actions.is_empty())
+            .then(LegendaryActionPoints::default),
+        legendary_resistance_policy: builder.legendary_resistance_policy.unwrap_or_default(),
+    })
+}
+
+fn creature_from_builder(
+    builder: CombatantBuilder,
+    state: &AppState,
+) -> Result<CreatureEntity<'_>, InvalidCharacterBuilder> {
+    match builder {
+        CombatantBuilder::Character(builder) => character_from_builder(
+            builder,
+            state.weapon_model_map.as_ref(),
+            state.armor_model_map.as_ref(),
+            state.proficiency_type_by_class_map.as_ref(),
+            state.spellcasting_by_class_map.as_ref(),
+            state.spell_model_map.as_ref(),
+        )
+        .map(CreatureEntity::Character),
+        CombatantBuilder::Monster(builder) => {
+            monster_from_builder(builder, state.monster_model_map.as_ref())
+                .map(CreatureEntity::Monster)
+        }
+    }
 }
 
 fn character_from_builder<'a>(
     builder: CharacterBuilder,
     weapon_model_map: &'a HashMap<WeaponType, WeaponModel>,
-    armor_model_map: &'a HashMap<ArmorType, ArmorModel>,
-) -> CharacterEntity<'a> {
+    armor_model_map: &'a HashMap<(ArmorType, Material), ArmorModel>,
+    proficiency_type_by_class_map: &'a ProficiencyTypeByClassMap,
+    spellcasting_by_class_map: &SpellcastingByClassMap,
+    spell_model_map: &'a SpellModelMap,
+) -> Result<CharacterEntity<'a>, InvalidCharacterBuilder> {
+    let level =
+        Level::try_from(builder.level).map_err(|_| InvalidCharacterBuilder::InvalidLevel {
+            name: builder.name.clone(),
+            level: builder.level,
+        })?;
+
+    if let Some(fighting_style) = builder.fighting_style.as_ref() {
+        if !fighting_style.is_available_to(&builder.class, &level) {
+            return Err(InvalidCharacterBuilder::UnavailableFightingStyle {
+                name: builder.name,
+                fighting_style: fighting_style.clone(),
+            });
+        }
+    }
+
+    if let Some(subclass) = builder.subclass {
+        if !subclass.is_available_to(&builder.class, &level) {
+            return Err(InvalidCharacterBuilder::InvalidSubclass {
+                name: builder.name,
+                subclass,
+            });
+        }
+    }
+
+    if builder.rage && builder.class != ClassType::Barbarian {
+        return Err(InvalidCharacterBuilder::UnavailableClassFeature {
+            name: builder.name,
+            feature: "Rage",
+        });
+    }
+
+    if builder.reckless_attack && (builder.class != ClassType::Barbarian || *level < 2) {
+        return Err(InvalidCharacterBuilder::UnavailableClassFeature {
+            name: builder.name,
+            feature: "Reckless Attack",
+        });
+    }
+
+    if builder.frenzy && (builder.subclass != Some(SubclassType::Berserker) || !builder.rage) {
+        return Err(InvalidCharacterBuilder::UnavailableClassFeature {
+            name: builder.name,
+            feature: "Frenzy",
+        });
+    }
+
+    if builder.smite_policy.is_some() && (builder.class != ClassType::Paladin || *level < 2) {
+        return Err(InvalidCharacterBuilder::UnavailableClassFeature {
+            name: builder.name,
+            feature: "Divine Smite",
+        });
+    }
+
+    let spellcasting = spellcasting_by_class_map
+        .get(&builder.class)
+        .filter(|spellcasting| spellcasting.is_available_at(&level));
+
+    let spell_slots = spellcasting.map(|spellcasting| spellcasting.spell_slots(&level));
+
+    let attack_spell = match builder.attack_spell.as_ref() {
+        Some(index) => {
+            let Some(spell) = spell_model_map.get(index) else {
+                return Err(InvalidCharacterBuilder::UnknownSpell {
+                    name: builder.name,
+                    spell: index.clone(),
+                });
+            };
+
+            if let Err(reason) = validate_attack_spell(
+                spell,
+                &builder.class,
+                spell_slots.as_ref(),
+                builder.attack_spell_slot_level,
+            ) {
+                return Err(InvalidCharacterBuilder::InvalidAttackSpell {
+                    name: builder.name,
+                    spell: spell.name.clone(),
+                    reason,
+                });
+            }
+
+            Some(spell)
+        }
+        None => None,
+    };
+
+    let mut spells = Vec::<&SpellModel>::new();
+    for index in builder.spells.iter().chain(builder.attack_spell.as_ref()) {
+        let Some(spell) = spell_model_map.get(index) else {
+            return Err(InvalidCharacterBuilder::UnknownSpell {
+                name: builder.name,
+                spell: index.clone(),
+            });
+        };
+
+        if !spells.iter().any(|known| known.index == spell.index) {
+            spells.push(spell);
+        }
+    }
+
+    if !spells.is_empty() {
+        let validation = match (spellcasting, spell_slots.as_ref()) {
+            (Some(class_spellcasting), Some(spell_slots)) => validate_spell_selection(
+                &spells,
+                &builder.class,
+                spell_slots,
+                &SpellLimits::from(SpellLimitsContext {
+                    class: &builder.class,
+                    level: &level,
+                    ability_scores: &builder.ability_scores,
+                    class_spellcasting,
+                }),
+            ),
+            _ => Err(InvalidSpellSelection::NoSpellcasting),
+        };
+
+        if let Err(reason) = validation {
+            return Err(InvalidCharacterBuilder::InvalidSpellSelection {
+                name: builder.name,
+                reason,
+            });
+        }
+    }
+
+    let ki = (builder.class == ClassType::Monk).then(|| Ki::new(&level));
+
+    let weapon_model = |weapon_type: &WeaponType| {
+        weapon_model_map
+            .get(weapon_type)
+            .ok_or_else(|| InvalidCharacterBuilder::UnknownWeapon {
+                name: builder.name.clone(),
+                weapon_type: weapon_type.clone(),
+            })
+    };
+
     let weapon = builder
         .weapon_type
         .as_ref()
-        .map(|weapon_type| WeaponEntity {
-            id: Id::new_incremental(),
-            model: weapon_model_map.get(weapon_type).unwrap(),
-        });
+        .map(|weapon_type| {
+            Ok(WeaponEntity {
+                id: Id::new_incremental(),
+                model: weapon_model(weapon_type)?,
+            })
+        })
+        .transpose()?;
 
-    let armor = builder.armor_type.as_ref().map(|armor_type| ArmorEntity {
-        id: Id::new_incremental(),
-        model: armor_model_map.get(armor_type).unwrap(),
-    });
+    let armor = builder
+        .armor_type
+        .as_ref()
+        .map(|armor_type| {
+            let material = builder
+                .armor_material
+                .clone()
+                .unwrap_or_else(|| armor_type.primary_material());
 
-    CharacterEntity {
+            // Only the materials the armor type comes in are loaded
+            let model = armor_model_map
+                .get(&(armor_type.clone(), material.clone()))
+                .ok_or_else(|| InvalidCharacterBuilder::InvalidArmorMaterial {
+                    name: builder.name.clone(),
+                    armor_type: armor_type.clone(),
+                    material,
+                })?;
+
+            Ok(ArmorEntity {
+                id: Id::new_incremental(),
+                model,
+            })
+        })
+        .transpose()?;
+
+    let off_hand_weapon = match builder.off_hand_weapon_type.as_ref() {
+        Some(weapon_type) => {
+            let model = weapon_model(weapon_type)?;
+
+            if let Err(reason) = validate_off_hand_weapon(
+                weapon.as_ref().map(|w| w.model),
+                model,
+                armor
+                    .as_ref()
+                    .is_some_and(|a| a.model.armor_category == ArmorCategory::Shield),
+            ) {
+                return Err(InvalidCharacterBuilder::InvalidOffHandWeapon {
+                    name: builder.name,
+                    reason,
+                });
+            }
+
+            Some(WeaponEntity {
+                id: Id::new_incremental(),
+                model,
+            })
+        }
+        None => None,
+    };
+
+    Ok(CharacterEntity {
         id: Id::new_incremental(),
         name: builder.name.to_string(),
         hp: Hp::new(builder.hp),
         equipped_weapon: weapon,
+        equipped_off_hand_weapon: off_hand_weapon,
         equipped_armor: armor,
         ability_scores: builder.ability_scores,
+        proficiencies: proficiency_type_by_class_map
+            .get(&builder.class)
+            .map_or(&[], Vec::as_slice),
         class: builder.class,
-        level: Level::try_from(builder.level).unwrap(),
+        subclass: builder.subclass,
+        rage: builder.rage.then(|| {
+            let rage = Rage::new(&level);
+            if builder.frenzy {
+                rage.with_frenzy()
+            } else {
+                rage
+            }
+        }),
+        sacred_weapon: (builder.subclass == Some(SubclassType::Devotion)
+            && *level >= SubclassType::Devotion.level())
+        .then(SacredWeapon::new),
+        level,
+        fighting_style: builder.fighting_style,
+        reckless_attack: builder.reckless_attack,
+        attacked_recklessly: false,
+        sneak_attack_used: false,
+        spell_slots,
+        spellcasting_ability: spellcasting.map(|spellcasting| spellcasting.ability.clone()),
+        smite_policy: builder.smite_policy,
+        attack_spell,
+        attack_spell_slot_level: builder.attack_spell_slot_level,
+        spells,
+        conditions: Vec::new(),
+        concentration: None,
+        ki,
+        subclass_damage_used: false,
         prone_state: builder.prone_state,
         cover_state: builder.cover_state,
+        position: builder.position.unwrap_or_default(),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) enum InvalidCharacterBuilder {
+    InvalidLevel {
+        name: String,
+        level: u32,
+    },
+    UnknownWeapon {
+        name: String,
+        weapon_type: WeaponType,
+    },
+    UnavailableFightingStyle {
+        name: String,
+        fighting_style: FightingStyle,
+    },
+    UnavailableClassFeature {
+        name: String,
+        feature: &'static str,
+    },
+    InvalidSubclass {
+        name: String,
+        subclass: SubclassType,
+    },
+    InvalidOffHandWeapon {
+        name: String,
+        reason: InvalidOffHandWeapon,
+    },
+    UnknownSpell {
+        name: String,
+        spell: String,
+    },
+    InvalidAttackSpell {
+        name: String,
+        spell: String,
+        reason: InvalidAttackSpell,
+    },
+    InvalidSpellSelection {
+        name: String,
+        reason: InvalidSpellSelection,
+    },
+    UnknownMonster {
+        monster: String,
+    },
+    InvalidArmorMaterial {
+        name: String,
+        armor_type: ArmorType,
+        material: Material,
+    },
+}
+
+impl Display for InvalidCharacterBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLevel { name, level } => write!(
+                f,
+                "{name} cannot be level {level}, levels run from {MIN_LEVEL} to {MAX_LEVEL}."
+            ),
+            Self::UnknownWeapon { name, weapon_type } => write!(
+                f,
+                "{name} cannot wield a {}, it is missing from the weapon data.",
+                weapon_type.name()
+            ),
+            Self::UnavailableFightingStyle {
+                name,
+                fighting_style,
+            } => write!(
+                f,
+                "{name} cannot take the {} fighting style at their class and level.",
+                fighting_style.name()
+            ),
+            Self::UnavailableClassFeature { name, feature } => {
+                write!(f, "{name} cannot use {feature} at their class and level.")
+            }
+            Self::InvalidSubclass { name, subclass } => write!(
+                f,
+                "{name} cannot follow the {} at their class and level.",
+                subclass.name()
+            ),
+            Self::InvalidOffHandWeapon { name, reason } => write!(
+                f,
+                "{name} cannot wield that off-hand weapon, {}.",
+                reason.reason()
+            ),
+            Self::UnknownSpell { name, spell } => {
+                write!(f, "{name} cannot cast {spell}, no such spell is known.")
+            }
+            Self::InvalidAttackSpell {
+                name,
+                spell,
+                reason,
+            } => write!(f, "{name} cannot attack with {spell}, {}.", reason.reason()),
+            Self::InvalidSpellSelection { name, reason } => match reason {
+                InvalidSpellSelection::NotOnClassSpellList(spell)
+                | InvalidSpellSelection::SpellLevelTooHigh(spell) => {
+                    write!(f, "{name} cannot choose {spell}, {}.", reason.reason())
+                }
+                InvalidSpellSelection::TooManyCantrips(limit) => write!(
+                    f,
+                    "{name} cannot choose those spells, {} ({limit} at most).",
+                    reason.reason()
+                ),
+                InvalidSpellSelection::TooManySpells(preparation) => write!(
+                    f,
+                    "{name} cannot choose those spells, {} ({} at most).",
+                    reason.reason(),
+                    preparation.limit()
+                ),
+                InvalidSpellSelection::NoSpellcasting => {
+                    write!(f, "{name} cannot choose spells, {}.", reason.reason())
+                }
+            },
+            Self::UnknownMonster { monster } => {
+                write!(f, "There is no {monster} monster.")
+            }
+            Self::InvalidArmorMaterial {
+                name,
+                armor_type,
+                material,
+            } => write!(
+                f,
+                "{name} cannot wear {} made of {}, it does not come in that material.",
+                armor_type.name(),
+                material.describe()
+            ),
+        }
     }
 }
 
@@ -113,12 +619,48 @@ fn using_serve_dir_with_assets_fallback() -> Router {
     let serve_dir =
         ServeDir::new(static_dir_path).not_found_service(ServeFile::new("assets/index.html"));
 
+    Router::new()
+        .route("/test", get(|| async { "hi from test" }))
+        .route("/attack", post(post_attack))
+        .route("/encounter", post(post_encounter))
+        .route("/get-weapons", get(get_weapons))
+        .route("/get-armor", get(get_armor))
+        .route("/get-constants", get(get_constants))
+        .route("/get-spells", get(get_spells))
+        .route("/get-monsters", get(get_monsters))
+        .nest_service("/", serve_dir)
+        .with_state(app_state())
+    // .fallback_service(serve_dir)
+}
+
+/// Loads the SRD data every route reads from
+fn app_state() -> AppState {
     let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data/src");
 
-    let equipment_file_string: String = read_equipment_file(data_dir);
+    let equipment_file_string: String = read_equipment_file(&data_dir);
 
     let equipment = deserialize_equipment(&equipment_file_string);
 
+    let classes_file_string: String = read_classes_file(&data_dir);
+
+    let classes = deserialize_classes(&classes_file_string);
+
+    let proficiencies_file_string: String = read_proficiencies_file(&data_dir);
+
+    let proficiencies = deserialize_proficiencies(&proficiencies_file_string);
+
+    let levels_file_string: String = read_levels_file(&data_dir);
+
+    let levels = deserialize_levels(&levels_file_string);
+
+    let spells_file_string: String = read_spells_file(&data_dir);
+
+    let spells = deserialize_spells(&spells_file_string);
+
+    let monsters_file_string: String = read_monsters_file(&data_dir);
+
+    let monsters = deserialize_monsters(&monsters_file_string);
+
     let weapon_model_map = equipment
         .iter()
         .filter_map(|equip| WeaponSchema::try_from(equip).ok())
@@ -131,26 +673,44 @@ fn using_serve_dir_with_assets_fallback() -> Router {
     let armor_model_map = equipment
         .iter()
         .filter_map(|equip| ArmorSchema::try_from(equip).ok())
-        .map(|armor_schema| {
-            let model = armor_model_from_armor_schema(&armor_schema);
-            (model.armor_type.clone(), model)
+        .flat_map(|armor_schema| armor_models_from_armor_schema(&armor_schema))
+        .map(|model| {
+            (
+                (model.armor_type.clone(), model.primary_material.clone()),
+                model,
+            )
         })
         .collect::<HashMap<_, _>>();
 
-    let state = AppState {
+    let spell_model_map = spells
+        .iter()
+        .map(|spell_schema| {
+            let model = spell_model_from_spell_schema(spell_schema);
+            (model.index.clone(), model)
+        })
+        .collect::<HashMap<_, _>>();
+
+    let monster_model_map = monsters
+        .iter()
+        .map(|monster_schema| {
+            let model = monster_model_from_monster_schema(monster_schema);
+            (model.index.clone(), model)
+        })
+        .collect::<HashMap<_, _>>();
+
+    AppState {
         weapon_model_map: Arc::new(weapon_model_map),
         armor_model_map: Arc::new(armor_model_map),
-    };
-
-    Router::new()
-        .route("/test", get(|| async { "hi from test" }))
-        .route("/attack", post(post_attack))
-        .route("/get-weapons", get(get_weapons))
-        .route("/get-armor", get(get_armor))
-        .route("/get-constants", get(get_constants))
-        .nest_service("/", serve_dir)
-        .with_state(state)
-    // .fallback_service(serve_dir)
+        proficiency_type_by_class_map: Arc::new(proficiency_type_by_class_map_from_schemas(
+            &classes,
+            &proficiencies,
+        )),
+        spellcasting_by_class_map: Arc::new(spellcasting_by_class_map_from_schemas(
+            &classes, &levels,
+        )),
+        spell_model_map: Arc::new(spell_model_map),
+        monster_model_map: Arc::new(monster_model_map),
+    }
 }
 
 fn main() {
@@ -182,3 +742,65 @@ fn main() {
             rs5e_server(addr, port).await;
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 3rd-level fighter with the given fields replaced
+    fn character(fields: serde_json::Value) -> CombatantBuilder {
+        let mut character = serde_json::json!({
+            "name": "Vex",
+            "level": 3,
+            "hp": 20,
+            "class": "Fighter",
+            "abilityScores": {"str": 16, "dex": 12, "con": 14, "int": 10, "wis": 10, "cha": 10},
+            "proneState": "Upright",
+            "coverState": "None"
+        });
+        for (field, value) in fields.as_object().unwrap() {
+            character[field] = value.clone();
+        }
+
+        serde_json::from_value(serde_json::json!({"t": "character", "c": character})).unwrap()
+    }
+
+    fn character_with_armor(armor_type: &str, armor_material: &str) -> CombatantBuilder {
+        character(serde_json::json!({"armorType": armor_type, "armorMaterial": armor_material}))
+    }
+
+    #[test]
+    fn armor_only_comes_in_its_material_variants() {
+        let state = app_state();
+
+        let invalid =
+            creature_from_builder(character_with_armor("leatherArmor", "adamantine"), &state);
+        assert!(matches!(
+            invalid,
+            Err(InvalidCharacterBuilder::InvalidArmorMaterial {
+                armor_type: ArmorType::LeatherArmor,
+                material: Material::Adamantine,
+                ..
+            })
+        ));
+
+        assert!(
+            creature_from_builder(character_with_armor("plateArmor", "mithral"), &state).is_ok()
+        );
+    }
+
+    #[test]
+    fn levels_outside_1_to_20_are_rejected() {
+        let state = app_state();
+
+        for level in [0, 21] {
+            assert!(matches!(
+                creature_from_builder(character(serde_json::json!({ "level": level })), &state),
+                Err(InvalidCharacterBuilder::InvalidLevel { level: invalid, .. }) if invalid == level
+            ));
+        }
+        assert!(
+            creature_from_builder(character(serde_json::json!({ "level": 20 })), &state).is_ok()
+        );
+    }
+}
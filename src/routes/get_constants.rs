@@ -1,4 +1,5 @@
-use axum::{http::StatusCode, Json};
+use crate::AppState;
+use axum::{extract::State, http::StatusCode, Json};
 use rs5e_concepts::{
     ability_modifier::ABILITY_MODIFIER_TABLE,
     ability_scores::{DEFAULT_ABILITY_SCORE, MAX_ABILITY_SCORE, MIN_ABILITY_SCORE},
@@ -6,7 +7,7 @@ use rs5e_concepts::{
     cover_state::CoverState,
     hit_dice::{hit_die_by_class_map, HitDieByClassMap},
     level::{DEFAULT_LEVEL, MAX_LEVEL, MIN_LEVEL},
-    proficiency_type::{proficiency_type_by_class_map, ProficiencyTypeByClassMap},
+    proficiency_type::ProficiencyTypeByClassMap,
     prone_state::ProneState,
     weapon_proficiency_bonus::WEAPON_PROFICIENCY_BONUS_TABLE,
 };
@@ -16,7 +17,7 @@ use typeshare::typeshare;
 #[typeshare]
 #[derive(Serialize, Debug)]
 #[allow(non_snake_case)]
-struct Constants {
+struct Constants<'a> {
     MIN_LEVEL: u32,
     MAX_LEVEL: u32,
     MIN_ABILITY_SCORE: u32,
@@ -28,11 +29,11 @@ struct Constants {
     DEFAULT_COVER_STATE: CoverState,
     WEAPON_PROFICIENCY_BONUS_TABLE: [u32; 20],
     ABILITY_MODIFIER_TABLE: [i32; 30],
-    PROFICIENCY_TYPE_BY_CLASS_MAP: &'static ProficiencyTypeByClassMap,
+    PROFICIENCY_TYPE_BY_CLASS_MAP: &'a ProficiencyTypeByClassMap,
     HIT_DIE_BY_CLASS_MAP: &'static HitDieByClassMap,
 }
 
-pub(crate) async fn get_constants() -> (StatusCode, Json<String>) {
+pub(crate) async fn get_constants(State(state): State<AppState>) -> (StatusCode, Json<String>) {
     let constants = Constants {
         MIN_LEVEL,
         MAX_LEVEL,
@@ -45,7 +46,7 @@ pub(crate) async fn get_constants() -> (StatusCode, Json<String>) {
         DEFAULT_COVER_STATE: CoverState::default(),
         WEAPON_PROFICIENCY_BONUS_TABLE,
         ABILITY_MODIFIER_TABLE,
-        PROFICIENCY_TYPE_BY_CLASS_MAP: proficiency_type_by_class_map(),
+        PROFICIENCY_TYPE_BY_CLASS_MAP: &state.proficiency_type_by_class_map,
         HIT_DIE_BY_CLASS_MAP: hit_die_by_class_map(),
     };
 
@@ -0,0 +1,10 @@
+use crate::AppState;
+use axum::{extract::State, http::StatusCode, Json};
+
+pub(crate) async fn get_monsters(State(state): State<AppState>) -> (StatusCode, Json<String>) {
+    let mut monster_list = state.monster_model_map.values().collect::<Vec<_>>();
+    monster_list.sort_by(|a, b| a.name.cmp(&b.name));
+    let monster_list_response = serde_json::to_string(&monster_list).unwrap();
+
+    (StatusCode::OK, axum::Json(monster_list_response))
+}
@@ -0,0 +1,76 @@
+use crate::AppState;
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use rs5e_concepts::{
+    class_type::ClassType, level::Level, spell::SpellModel, spell_selection::is_choosable,
+};
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SpellChoicesQuery {
+    class: ClassType,
+    level: u32,
+}
+
+/// Everything a character of the class and level may know or prepare
+#[typeshare]
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SpellChoices {
+    cantrips_known: u32,
+    /// None for classes that prepare their spells, up to their spellcasting
+    /// ability modifier plus their level, or half their level for paladins
+    spells_known: Option<u32>,
+    /// Cantrips first, then by spell level and name
+    spells: Vec<SpellModel>,
+}
+
+pub(crate) async fn get_spells(
+    State(state): State<AppState>,
+    Query(query): Query<SpellChoicesQuery>,
+) -> (StatusCode, Json<String>) {
+    let Ok(level) = Level::try_from(query.level) else {
+        return (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            axum::Json(format!("There is no level {}.", query.level)),
+        );
+    };
+
+    let spellcasting = state
+        .spellcasting_by_class_map
+        .get(&query.class)
+        .filter(|spellcasting| spellcasting.is_available_at(&level));
+
+    let spell_choices = spellcasting.map_or(
+        SpellChoices {
+            cantrips_known: 0,
+            spells_known: None,
+            spells: Vec::new(),
+        },
+        |spellcasting| {
+            let spell_slots = spellcasting.spell_slots(&level);
+            let mut spells = state
+                .spell_model_map
+                .values()
+                .filter(|spell| is_choosable(spell, &query.class, &spell_slots))
+                .cloned()
+                .collect::<Vec<_>>();
+            spells.sort_by(|a, b| (a.level, &a.name).cmp(&(b.level, &b.name)));
+
+            SpellChoices {
+                cantrips_known: spellcasting.cantrips_known(&level),
+                spells_known: spellcasting.spells_known(&level),
+                spells,
+            }
+        },
+    );
+
+    let spell_choices_response = serde_json::to_string(&spell_choices).unwrap();
+
+    (StatusCode::OK, axum::Json(spell_choices_response))
+}
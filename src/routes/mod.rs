@@ -1,4 +1,7 @@
 pub(crate) mod get_armor;
 pub(crate) mod get_constants;
+pub(crate) mod get_monsters;
+pub(crate) mod get_spells;
 pub(crate) mod get_weapons;
 pub(crate) mod post_attack;
+pub(crate) mod post_encounter;
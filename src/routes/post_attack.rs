@@ -1,30 +1,97 @@
-use crate::{character_from_builder, AppState, CharacterBuilder};
+use crate::{creature_from_builder, AppState, CombatantBuilder};
 use axum::{extract::State, http::StatusCode, Json};
-use rs5e_concepts::cover_state::CoverState;
+use rs5e_components::{
+    attacker::Attacker, combatant::Combatant, destructible::Destructible,
+    has_concentration::HasConcentration, has_conditions::HasConditions, has_ki::HasKi,
+    has_legendary_actions::HasLegendaryActions, has_rage::HasRage,
+    has_sacred_weapon::HasSacredWeapon, identifiable::Identifiable, named::Named,
+};
+use rs5e_concepts::{
+    cover_state::CoverState,
+    critical_state::CriticalState,
+    damage_type::DamageType,
+    id::Id,
+    ki::{Ki, MartialArtsBonusAction, FLURRY_OF_BLOWS_KI_COST},
+    legendary::{LegendaryActionEffect, LegendaryActionPoints, LEGENDARY_ACTIONS_PER_ROUND},
+    monster_action::{MonsterAttack, MonsterSaveAction},
+    position::Position,
+    rage::Rage,
+    sacred_weapon::SacredWeapon,
+    special_ability::RegenerationSuppressed,
+    spell_attack::SpellAttack,
+    spell_save::SpellSave,
+    weapon_range::WeaponRange,
+};
+use rs5e_entities::creature::CreatureEntity;
 use rs5e_log::{
+    describe::Describe,
+    describe_initiative::InitiativeSummary,
     describe_physical_attack_context::{PhysicalAttackAttemptSummary, PhysicalAttackSummary},
     describe_physical_attack_outcome::PhysicalAttackOutcomeSummary,
+    describe_spell_save_outcome::SpellSaveOutcomeSummary,
 };
 use rs5e_systems::{
+    area_of_effect::area_of_effect_targets_system,
+    concentration::{
+        begin_concentration_system, concentration_check_system, end_concentration_system,
+        incapacitated_concentration_system, lapsed_concentration_system,
+    },
+    initiative::initiative_system,
+    legendary::legendary_action_system,
+    monster_attack::monster_attack_system,
+    monster_save_action::{choose_monster_save_action, monster_save_action_system},
     physical_attack::{
         physical_attack_system, PhysicalAttackContext, PhysicalAttackState,
         PhysicalAttackStateAndContext,
     },
     snapshots::{attacker::AttackerSnapshot, defender::DefenderSnapshot},
+    special_ability::{damage_taken_system, nimble_escape_system},
+    spell_attack::{cast_attack_spell_system, spell_attack_system},
+    spell_save::{cast_save_spell_system, spell_save_system},
+    turn::{
+        condition_saving_throws_system, end_turn_system, martial_arts_bonus_action_system,
+        start_turn_system, TurnEndOutcome,
+    },
 };
 use serde::{Deserialize, Serialize};
+use std::ops::Deref;
 use typeshare::typeshare;
 
+/// Combatants start within 5 feet of each other unless told otherwise
+const DEFAULT_DISTANCE: u32 = 5;
+
+/// Allies stand 5 feet to either side of the target unless placed
+const ALLY_OFFSET: i32 = 5;
+
+const fn default_distance() -> u32 {
+    DEFAULT_DISTANCE
+}
+
 #[typeshare]
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct CombatSummary {
+    /// The turn order held for the whole fight
+    initiative: Vec<InitiativeSummary>,
     combatant_1_summary: Option<PhysicalAttackSummary>,
     combatant_2_summary: Option<PhysicalAttackSummary>,
-    attacks: Vec<PhysicalAttackAttemptSummaryWithPhrases>,
+    turns: Vec<TurnSummary>,
     outcome: String,
 }
 
+/// Every attack a combatant makes on their turn
+#[typeshare]
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TurnSummary {
+    before_phrase: String,
+    bonus_action_phrase: Option<String>,
+    attacks: Vec<PhysicalAttackAttemptSummaryWithPhrases>,
+    after_phrase: String,
+    /// Taken by the other combatant once the turn has ended
+    legendary_actions: Vec<PhysicalAttackAttemptSummaryWithPhrases>,
+}
+
 #[typeshare]
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -38,53 +105,129 @@ pub struct PhysicalAttackAttemptSummaryWithPhrases {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct AttackRequest {
-    attacker: CharacterBuilder,
-    target: CharacterBuilder,
+    attacker: CombatantBuilder,
+    target: CombatantBuilder,
+    /// Stands beside the target and may protect them, but does not fight
+    target_ally: Option<CombatantBuilder>,
+    /// Stands beside the target so the attacker can Sneak Attack, but does not fight
+    attacker_ally: Option<CombatantBuilder>,
+    /// Feet between the attacker and the target, checked against spell range.
+    /// Worked out from their positions when both are placed
+    #[serde(default = "default_distance")]
+    distance: u32,
+    /// The fight takes place in sunlight, e.g. for Sunlight Sensitivity
+    #[serde(default)]
+    sunlight: bool,
 }
 
 pub(crate) async fn post_attack(
     State(state): State<AppState>,
-    Json(payload): Json<AttackRequest>,
+    Json(mut payload): Json<AttackRequest>,
 ) -> (StatusCode, Json<CombatSummary>) {
-    let mut attacks = Vec::new();
+    let mut turns = Vec::new();
 
-    let mut combatant_1_entity = character_from_builder(
-        payload.attacker,
-        state.weapon_model_map.as_ref(),
-        state.armor_model_map.as_ref(),
-    );
+    // Unplaced combatants are laid out along a line from the attacker to the
+    // target, with the allies on either side of the target
+    let attacker_position = *payload.attacker.position_mut().get_or_insert_default();
+    let target_position = *payload.target.position_mut().get_or_insert(Position::new(
+        attacker_position
+            .x
+            .saturating_add(i32::try_from(payload.distance).unwrap_or(i32::MAX)),
+        attacker_position.y,
+    ));
+    for (ally, offset) in [
+        (payload.target_ally.as_mut(), ALLY_OFFSET),
+        (payload.attacker_ally.as_mut(), -ALLY_OFFSET),
+    ] {
+        if let Some(ally) = ally {
+            ally.position_mut().get_or_insert(Position::new(
+                target_position.x,
+                target_position.y.saturating_add(offset),
+            ));
+        }
+    }
+    let distance = attacker_position.distance_to(&target_position);
+    let in_sunlight = payload.sunlight;
 
-    let mut combatant_2_entity = character_from_builder(
-        payload.target,
-        state.weapon_model_map.as_ref(),
-        state.armor_model_map.as_ref(),
-    );
+    let combatants = [
+        Some(payload.attacker),
+        Some(payload.target),
+        payload.target_ally,
+        payload.attacker_ally,
+    ]
+    .into_iter()
+    .map(|builder| {
+        builder
+            .map(|builder| creature_from_builder(builder, &state))
+            .transpose()
+    })
+    .collect::<Result<Vec<_>, _>>();
 
-    if combatant_1_entity.cover_state == CoverState::Total
-        && combatant_2_entity.cover_state == CoverState::Total
+    let (
+        mut combatant_1_entity,
+        mut combatant_2_entity,
+        mut target_ally_entity,
+        mut attacker_ally_entity,
+    ) = match combatants.map(Vec::into_iter) {
+        Ok(mut combatants) => (
+            combatants.next().flatten().unwrap(),
+            combatants.next().flatten().unwrap(),
+            combatants.next().flatten(),
+            combatants.next().flatten(),
+        ),
+        Err(invalid_character_builder) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                axum::Json(CombatSummary {
+                    initiative: Vec::new(),
+                    combatant_1_summary: None,
+                    combatant_2_summary: None,
+                    turns: Vec::new(),
+                    outcome: invalid_character_builder.to_string(),
+                }),
+            );
+        }
+    };
+
+    if combatant_1_entity.cover_state() == &CoverState::Total
+        && combatant_2_entity.cover_state() == &CoverState::Total
     {
         return (
             StatusCode::OK,
             axum::Json(CombatSummary {
+                initiative: Vec::new(),
                 combatant_1_summary: None,
                 combatant_2_summary: None,
-                attacks: Vec::new(),
+                turns: Vec::new(),
                 outcome: "They both have full cover what are you trying to do".to_string(),
             }),
         );
     };
 
-    let combatant_1_state = PhysicalAttackState {
-        attacker: AttackerSnapshot::from_attacker(
-            &combatant_1_entity,
-            state.weapon_model_map.as_ref(),
-            state.armor_model_map.as_ref(),
-        ),
-        defender: DefenderSnapshot::from_defender(
-            &combatant_2_entity,
-            state.armor_model_map.as_ref(),
-        ),
-    };
+    let target_id = combatant_2_entity.id();
+
+    // Initiative decides who acts first, turns then alternate in that order
+    // for the rest of the fight
+    let initiative_outcomes = initiative_system(&[&combatant_1_entity, &combatant_2_entity]);
+    if initiative_outcomes
+        .first()
+        .is_some_and(|outcome| outcome.id == target_id)
+    {
+        std::mem::swap(&mut combatant_1_entity, &mut combatant_2_entity);
+    }
+    let initiative = initiative_outcomes
+        .iter()
+        .map(InitiativeSummary::from)
+        .collect::<Vec<_>>();
+
+    let combatant_1_state = physical_attack_state(
+        &combatant_1_entity,
+        &combatant_2_entity,
+        attacker_ally(&attacker_ally_entity, &combatant_1_entity, target_id),
+        defender_ally(&target_ally_entity, &combatant_2_entity, target_id),
+        in_sunlight,
+        &state,
+    );
 
     let combatant_1_context = match PhysicalAttackContext::try_from(&combatant_1_state) {
         Ok(context) => context,
@@ -92,29 +235,27 @@ pub(crate) async fn post_attack(
             return (
                 StatusCode::OK,
                 axum::Json(CombatSummary {
+                    initiative,
                     combatant_1_summary: None,
                     combatant_2_summary: None,
-                    attacks: Vec::new(),
+                    turns: Vec::new(),
                     outcome: format!(
                         "{} is covered, cannot attempt attack.",
-                        combatant_2_entity.name
+                        combatant_2_entity.name()
                     ),
                 }),
             );
         }
     };
 
-    let combatant_2_state = PhysicalAttackState {
-        attacker: AttackerSnapshot::from_attacker(
-            &combatant_2_entity,
-            state.weapon_model_map.as_ref(),
-            state.armor_model_map.as_ref(),
-        ),
-        defender: DefenderSnapshot::from_defender(
-            &combatant_1_entity,
-            state.armor_model_map.as_ref(),
-        ),
-    };
+    let combatant_2_state = physical_attack_state(
+        &combatant_2_entity,
+        &combatant_1_entity,
+        attacker_ally(&attacker_ally_entity, &combatant_2_entity, target_id),
+        defender_ally(&target_ally_entity, &combatant_1_entity, target_id),
+        in_sunlight,
+        &state,
+    );
 
     let combatant_2_context = match PhysicalAttackContext::try_from(&combatant_2_state) {
         Ok(context) => context,
@@ -122,6 +263,7 @@ pub(crate) async fn post_attack(
             return (
                 StatusCode::OK,
                 axum::Json(CombatSummary {
+                    initiative,
                     combatant_1_summary: Some(
                         (&PhysicalAttackStateAndContext {
                             state: combatant_1_state,
@@ -130,20 +272,18 @@ pub(crate) async fn post_attack(
                             .into(),
                     ),
                     combatant_2_summary: None,
-                    attacks: Vec::new(),
+                    turns: Vec::new(),
                     outcome: format!(
                         "{} is covered, cannot attempt attack.",
-                        combatant_1_entity.name
+                        combatant_1_entity.name()
                     ),
                 }),
             );
         }
     };
 
-    let mut combatant_1_state_and_context = PhysicalAttackStateAndContext {
-        state: combatant_1_state,
-        context: combatant_1_context,
-    };
+    // Combatant 1's state and context is rebuilt at the start of every turn
+    let mut combatant_1_state_and_context;
 
     let mut combatant_2_state_and_context = PhysicalAttackStateAndContext {
         state: combatant_2_state,
@@ -154,31 +294,612 @@ pub(crate) async fn post_attack(
     let outcome;
 
     loop {
-        let physical_attack_outcome = physical_attack_system(
+        let turn_start_outcome = start_turn_system(&mut combatant_1_entity, in_sunlight);
+
+        // An incapacitated combatant takes no actions at all
+        let incapacitating_condition = combatant_1_entity
+            .conditions()
+            .iter()
+            .map(|spell_condition| spell_condition.condition)
+            .find(|condition| condition.incapacitates());
+
+        // Nimble Escape hides before attacking, unless rage took the bonus action
+        let hide_outcome = (incapacitating_condition.is_none() && !turn_start_outcome.entered_rage)
+            .then(|| nimble_escape_system(&combatant_1_entity, &combatant_2_entity))
+            .flatten();
+        let bonus_action_used = turn_start_outcome.entered_rage || hide_outcome.is_some();
+
+        // Bonus actions and the defender's last turn can both change the context
+        let mut combatant_1_state = physical_attack_state(
             &combatant_1_entity,
-            &mut combatant_2_entity,
-            &combatant_1_state_and_context,
+            &combatant_2_entity,
+            attacker_ally(&attacker_ally_entity, &combatant_1_entity, target_id),
+            defender_ally(&target_ally_entity, &combatant_2_entity, target_id),
+            in_sunlight,
+            &state,
         );
+        if hide_outcome
+            .as_ref()
+            .is_some_and(|hide_outcome| hide_outcome.hidden.hidden)
+        {
+            combatant_1_state.attacker = combatant_1_state.attacker.with_hidden();
+        }
+        combatant_1_state_and_context = PhysicalAttackStateAndContext {
+            context: PhysicalAttackContext::try_from(&combatant_1_state)
+                .expect("cover does not change during combat"),
+            state: combatant_1_state,
+        };
 
-        let physical_attack_outcome_summary =
-            PhysicalAttackOutcomeSummary::from(&physical_attack_outcome);
+        // Casting the attack spell takes the Attack action, falling back to
+        // weapon attacks when it cannot be cast. Spells without an attack
+        // roll force a saving throw instead
+        let mut cast_failure = None;
+        let mut spell_attack = None;
+        let mut spell_save = None;
+        let slot_level = combatant_1_entity.attack_spell_slot_level();
+        let cast = combatant_1_entity
+            .attack_spell()
+            .filter(|_| incapacitating_condition.is_none())
+            .map(|spell| {
+                let cast = if spell.attack_type.is_some() {
+                    cast_attack_spell_system(&mut combatant_1_entity, spell, slot_level, distance)
+                        .map(|cast| spell_attack = Some(cast))
+                } else {
+                    cast_save_spell_system(
+                        &mut combatant_1_entity,
+                        spell,
+                        slot_level,
+                        distance,
+                        &combatant_2_entity,
+                    )
+                    .map(|cast| spell_save = Some(cast))
+                };
+                (spell, cast)
+            });
+        match cast {
+            Some((spell, Err(reason))) => {
+                cast_failure = Some(PhysicalAttackAttemptSummaryWithPhrases {
+                    before_phrase: format!(
+                        "{} tries to cast {} at {}!",
+                        combatant_1_entity.name(),
+                        spell.name,
+                        combatant_2_entity.name()
+                    ),
+                    summary: PhysicalAttackAttemptSummary::Failure(format!(
+                        "{} cannot cast {}, {}.",
+                        combatant_1_entity.name(),
+                        spell.name,
+                        reason.reason()
+                    )),
+                    after_phrase: format!(
+                        "{} attacks with their weapon instead.",
+                        combatant_1_entity.name()
+                    ),
+                });
+            }
+            Some((_, Ok(()))) | None => (),
+        }
+        let spell_cast = spell_attack.is_some() || spell_save.is_some();
 
-        attacks.push(PhysicalAttackAttemptSummaryWithPhrases {
-            before_phrase: format!(
-                "⚔️ {} attacks {}!",
-                combatant_1_entity.name, combatant_2_entity.name
+        // Casting a spell that requires concentration ends the previous one
+        let mut concentration_phrase = String::new();
+        let cast_spell = spell_attack
+            .as_ref()
+            .map(|spell_attack| spell_attack.spell)
+            .or_else(|| spell_save.as_ref().map(|spell_save| spell_save.spell));
+        if let Some(spell) = cast_spell {
+            if let Some(previous) = begin_concentration_system(&mut combatant_1_entity, spell) {
+                let freed = end_concentration_system(
+                    &previous,
+                    &mut [&mut combatant_1_entity, &mut combatant_2_entity],
+                );
+                concentration_phrase = format!(
+                    " {} stops concentrating on {} to cast {}.{}",
+                    combatant_1_entity.name(),
+                    previous.spell_name,
+                    spell.name,
+                    spell_ended_phrase(&previous.spell_name, &freed)
+                );
+            }
+        }
+
+        // A monster makes the attacks of its stat block, following
+        // Multiattack when it has one, unless it has a use left of a limited
+        // action such as a breath weapon
+        let save_action = (incapacitating_condition.is_none() && !spell_cast)
+            .then(|| {
+                choose_monster_save_action(
+                    &combatant_1_entity,
+                    &combatant_1_entity.monster_save_actions(),
+                )
+            })
+            .flatten();
+        let monster_attacks = match save_action {
+            Some(_) => Vec::new(),
+            None => combatant_1_entity.monster_attacks(),
+        };
+
+        // A spell with a saving throw is cast once for the whole action
+        let attacks_per_turn = match (&spell_attack, &spell_save) {
+            _ if incapacitating_condition.is_some() => 0,
+            (Some(spell_attack), _) => spell_attack.attacks,
+            (None, Some(_)) => 1,
+            (None, None) if save_action.is_some() => 1,
+            (None, None) if !monster_attacks.is_empty() => monster_attacks.len() as u32,
+            (None, None) => *combatant_1_state_and_context
+                .context
+                .attacker
+                .attacks_per_turn
+                .deref(),
+        };
+        let mut attacks = Vec::new();
+
+        let attack_label = match (&spell_attack, &spell_save, save_action) {
+            (Some(spell_attack), _, _) => spell_attack.spell.name.as_str(),
+            (None, Some(spell_save), _) => spell_save.spell.name.as_str(),
+            (None, None, Some(save_action)) => save_action.name.as_str(),
+            (None, None, None) if combatant_1_entity.equipped_off_hand_weapon().is_some() => {
+                "Main-hand attack"
+            }
+            (None, None, None) => "Attack",
+        };
+
+        for attack_number in 1..=attacks_per_turn {
+            let monster_attack = monster_attacks.get(attack_number as usize - 1).copied();
+            let label = format!(
+                "{} {attack_number} of {attacks_per_turn}",
+                monster_attack.map_or(attack_label, |monster_attack| monster_attack.name.as_str())
+            );
+
+            attacks.push(match (&spell_attack, &spell_save) {
+                (Some(spell_attack), _) => spell_attack_with_phrases(
+                    &mut combatant_1_entity,
+                    &mut combatant_2_entity,
+                    &combatant_1_state_and_context,
+                    spell_attack,
+                    label,
+                ),
+                (None, Some(spell_save)) => spell_save_with_phrases(
+                    &mut combatant_1_entity,
+                    &mut combatant_2_entity,
+                    [target_ally_entity.as_mut(), attacker_ally_entity.as_mut()]
+                        .into_iter()
+                        .flatten()
+                        .collect(),
+                    spell_save,
+                    label,
+                ),
+                (None, None) => match (save_action, monster_attack) {
+                    (Some(save_action), _) => monster_save_action_with_phrases(
+                        &mut combatant_1_entity,
+                        &mut combatant_2_entity,
+                        save_action,
+                        label,
+                    ),
+                    (None, Some(monster_attack)) => monster_attack_with_phrases(
+                        &mut combatant_1_entity,
+                        &mut combatant_2_entity,
+                        &combatant_1_state_and_context,
+                        monster_attack,
+                        label,
+                    ),
+                    (None, None) => attack_with_phrases(
+                        &mut combatant_1_entity,
+                        &mut combatant_2_entity,
+                        &combatant_1_state_and_context,
+                        label,
+                    ),
+                },
+            });
+
+            // The rest of the attacks are not made once the target drops
+            if combatant_2_entity.hp() == 0 {
+                break;
+            }
+
+            // Attacking gives away a hidden attacker's position
+            if combatant_1_state_and_context.state.attacker.hidden {
+                let combatant_1_state = physical_attack_state(
+                    &combatant_1_entity,
+                    &combatant_2_entity,
+                    attacker_ally(&attacker_ally_entity, &combatant_1_entity, target_id),
+                    defender_ally(&target_ally_entity, &combatant_2_entity, target_id),
+                    in_sunlight,
+                    &state,
+                );
+                combatant_1_state_and_context = PhysicalAttackStateAndContext {
+                    context: PhysicalAttackContext::try_from(&combatant_1_state)
+                        .expect("cover does not change during combat"),
+                    state: combatant_1_state,
+                };
+            }
+        }
+
+        let main_attacks_made = attacks.len();
+
+        // A frenzy grants a melee weapon attack as a bonus action on every
+        // turn after the one the rage started
+        let frenzy_attack = combatant_2_entity.hp() > 0
+            && !bonus_action_used
+            && !spell_cast
+            && incapacitating_condition.is_none()
+            && combatant_1_entity.rage().is_some_and(Rage::is_frenzied)
+            && combatant_1_state_and_context
+                .state
+                .attacker
+                .weapon
+                .is_some_and(|weapon| weapon.weapon_range == WeaponRange::Melee);
+
+        if frenzy_attack {
+            attacks.push(attack_with_phrases(
+                &mut combatant_1_entity,
+                &mut combatant_2_entity,
+                &combatant_1_state_and_context,
+                "Frenzy attack".to_string(),
+            ));
+        }
+
+        // Entering a rage or hiding already took the bonus action
+        let martial_arts_bonus_action = (combatant_2_entity.hp() > 0
+            && !bonus_action_used
+            && !spell_cast
+            && incapacitating_condition.is_none()
+            && combatant_1_state_and_context
+                .context
+                .attacker
+                .martial_arts
+                .is_some())
+        .then(|| martial_arts_bonus_action_system(&mut combatant_1_entity));
+
+        if let Some(martial_arts_bonus_action) = martial_arts_bonus_action {
+            let mut unarmed_strike_state = physical_attack_state(
+                &combatant_1_entity,
+                &combatant_2_entity,
+                attacker_ally(&attacker_ally_entity, &combatant_1_entity, target_id),
+                defender_ally(&target_ally_entity, &combatant_2_entity, target_id),
+                in_sunlight,
+                &state,
+            );
+            unarmed_strike_state.attacker = unarmed_strike_state.attacker.with_unarmed_strike();
+            let unarmed_strike_state_and_context = PhysicalAttackStateAndContext {
+                context: PhysicalAttackContext::try_from(&unarmed_strike_state)
+                    .expect("cover does not change during combat"),
+                state: unarmed_strike_state,
+            };
+
+            let strikes = martial_arts_bonus_action.strikes();
+            for strike_number in 1..=strikes {
+                attacks.push(attack_with_phrases(
+                    &mut combatant_1_entity,
+                    &mut combatant_2_entity,
+                    &unarmed_strike_state_and_context,
+                    match martial_arts_bonus_action {
+                        MartialArtsBonusAction::FlurryOfBlows => {
+                            format!("Flurry of Blows strike {strike_number} of {strikes}")
+                        }
+                        MartialArtsBonusAction::UnarmedStrike => {
+                            "Martial Arts bonus strike".to_string()
+                        }
+                    },
+                ));
+
+                if combatant_2_entity.hp() == 0 {
+                    break;
+                }
+            }
+        }
+
+        // The off-hand weapon only attacks if no other bonus action was taken,
+        // after attacking with a weapon in the other hand
+        let off_hand_attack = combatant_2_entity.hp() > 0
+            && !bonus_action_used
+            && !spell_cast
+            && incapacitating_condition.is_none()
+            && !frenzy_attack
+            && martial_arts_bonus_action.is_none()
+            && combatant_1_entity.equipped_off_hand_weapon().is_some();
+
+        if off_hand_attack {
+            let mut off_hand_state = physical_attack_state(
+                &combatant_1_entity,
+                &combatant_2_entity,
+                attacker_ally(&attacker_ally_entity, &combatant_1_entity, target_id),
+                defender_ally(&target_ally_entity, &combatant_2_entity, target_id),
+                in_sunlight,
+                &state,
+            );
+            off_hand_state.attacker = off_hand_state.attacker.with_off_hand_attack();
+            let off_hand_state_and_context = PhysicalAttackStateAndContext {
+                context: PhysicalAttackContext::try_from(&off_hand_state)
+                    .expect("cover does not change during combat"),
+                state: off_hand_state,
+            };
+
+            attacks.push(attack_with_phrases(
+                &mut combatant_1_entity,
+                &mut combatant_2_entity,
+                &off_hand_state_and_context,
+                "Off-hand attack".to_string(),
+            ));
+        }
+
+        let target_dropped = combatant_2_entity.hp() == 0;
+
+        let turn_end_outcome = end_turn_system(&mut combatant_1_entity, attacks.len() as u32);
+        let condition_saving_throws = condition_saving_throws_system(&mut combatant_1_entity);
+
+        if let Some(cast_failure) = cast_failure {
+            attacks.insert(0, cast_failure);
+        }
+
+        let rage_phrase = match turn_end_outcome {
+            TurnEndOutcome {
+                exhaustion_gained: true,
+                ..
+            } => format!(
+                " {0}'s frenzy ends and {0} suffers a level of exhaustion.",
+                combatant_1_entity.name()
+            ),
+            TurnEndOutcome {
+                rage_ended: true, ..
+            } => format!(" {}'s rage ends.", combatant_1_entity.name()),
+            TurnEndOutcome { .. } => String::new(),
+        };
+        let sacred_weapon_ended_phrase = if turn_end_outcome.sacred_weapon_ended {
+            format!(" {}'s Sacred Weapon ends.", combatant_1_entity.name())
+        } else {
+            String::new()
+        };
+        let rage_phrase = format!("{rage_phrase}{sacred_weapon_ended_phrase}");
+
+        let condition_phrase = condition_saving_throws
+            .iter()
+            .map(|condition_saving_throw| {
+                let saving_throw = &condition_saving_throw.saving_throw;
+                let spell_condition = &condition_saving_throw.spell_condition;
+                let roll_phrase = if saving_throw.automatic_failure {
+                    format!(
+                        "automatically fails a {:?} saving throw",
+                        saving_throw.ability
+                    )
+                } else {
+                    format!(
+                        "rolls {} on a {:?} saving throw against DC {}",
+                        saving_throw.total_value(),
+                        saving_throw.ability,
+                        saving_throw.dc
+                    )
+                };
+                let result_phrase = if saving_throw.legendary_resistance {
+                    "uses Legendary Resistance so is no longer"
+                } else if saving_throw.succeeded() {
+                    "is no longer"
+                } else {
+                    "remains"
+                };
+
+                format!(
+                    " {} {roll_phrase} and {result_phrase} {} by {}.",
+                    combatant_1_entity.name(),
+                    spell_condition.condition.describe(),
+                    spell_condition.spell_name
+                )
+            })
+            .collect::<String>();
+
+        let concentrating_phrase =
+            combatant_1_entity
+                .concentration()
+                .map_or(String::new(), |concentration| {
+                    format!(
+                        " {} is concentrating on {}.",
+                        combatant_1_entity.name(),
+                        concentration.spell_name
+                    )
+                });
+        let concentration_phrase = format!("{concentration_phrase}{concentrating_phrase}");
+
+        let regeneration_phrase = match turn_start_outcome.regeneration {
+            Some(Ok(hp_change)) => format!(
+                " {} regains {} HP with Regeneration ({}/{} HP).",
+                combatant_1_entity.name(),
+                hp_change.after - hp_change.before,
+                hp_change.after,
+                hp_change.max
+            ),
+            Some(Err(RegenerationSuppressed::Damage(damage_type))) => format!(
+                " {}'s Regeneration does not work this turn after taking {} damage.",
+                combatant_1_entity.name(),
+                damage_type.describe()
             ),
-            after_phrase: format!(
-                "{} has {} HP remaining!",
-                combatant_2_entity.name, combatant_2_entity.hp.current
+            Some(Err(RegenerationSuppressed::Sunlight)) => format!(
+                " {}'s Regeneration does not work in sunlight.",
+                combatant_1_entity.name()
+            ),
+            None => String::new(),
+        };
+
+        let sacred_weapon_phrase = if turn_start_outcome.sacred_weapon_activated {
+            format!(
+                " {} channels divinity into {} with Sacred Weapon for 1 minute ({} Channel Divinity remaining).",
+                combatant_1_entity.name(),
+                combatant_1_entity
+                    .equipped_weapon()
+                    .map_or("a weapon", |weapon| weapon.model.weapon_type.name()),
+                combatant_1_entity
+                    .sacred_weapon()
+                    .map_or(0, SacredWeapon::uses_remaining)
+            )
+        } else {
+            String::new()
+        };
+
+        let recharge_phrase = turn_start_outcome
+            .recharges
+            .iter()
+            .map(|recharge_roll| {
+                format!(
+                    " {} rolls {} on {} to recharge {}, needing {}, and {}.",
+                    combatant_1_entity.name(),
+                    recharge_roll.roll,
+                    recharge_roll.dice,
+                    recharge_roll.name,
+                    recharge_roll.min_value,
+                    if recharge_roll.recharged() {
+                        "it recharges"
+                    } else {
+                        "it does not recharge"
+                    }
+                )
+            })
+            .collect::<String>();
+
+        // The other combatant can take one legendary action as the turn ends
+        let mut legendary_actions = Vec::new();
+        if !target_dropped && combatant_1_entity.hp() > 0 {
+            let combatant_2_state = physical_attack_state(
+                &combatant_2_entity,
+                &combatant_1_entity,
+                attacker_ally(&attacker_ally_entity, &combatant_2_entity, target_id),
+                defender_ally(&target_ally_entity, &combatant_1_entity, target_id),
+                in_sunlight,
+                &state,
+            );
+            let legendary_state_and_context = PhysicalAttackStateAndContext {
+                context: PhysicalAttackContext::try_from(&combatant_2_state)
+                    .expect("cover does not change during combat"),
+                state: combatant_2_state,
+            };
+
+            let legendary_action_options = combatant_2_entity.legendary_actions();
+            if let Some(legendary_action) =
+                legendary_action_system(&mut combatant_2_entity, legendary_action_options)
+            {
+                let label = format!(
+                    "Legendary action costing {} ({} of {LEGENDARY_ACTIONS_PER_ROUND} left)",
+                    legendary_action.cost,
+                    combatant_2_entity
+                        .legendary_action_points()
+                        .map_or(0, LegendaryActionPoints::points_remaining)
+                );
+
+                legendary_actions.extend(match &legendary_action.effect {
+                    LegendaryActionEffect::Attack(monster_attack) => {
+                        Some(monster_attack_with_phrases(
+                            &mut combatant_2_entity,
+                            &mut combatant_1_entity,
+                            &legendary_state_and_context,
+                            monster_attack,
+                            label,
+                        ))
+                    }
+                    LegendaryActionEffect::SaveAction(save_action) => {
+                        Some(monster_save_action_with_phrases(
+                            &mut combatant_2_entity,
+                            &mut combatant_1_entity,
+                            save_action,
+                            label,
+                        ))
+                    }
+                    // Never chosen, as only implemented actions are picked
+                    LegendaryActionEffect::NotImplemented => None,
+                });
+            }
+        }
+        let attacker_dropped = combatant_1_entity.hp() == 0;
+
+        // Concentration lost this turn may have held creatures out of reach
+        // of the attacks, e.g. a bystander caught in the spell
+        let mut creatures = Vec::from([&mut combatant_1_entity, &mut combatant_2_entity]);
+        creatures.extend(target_ally_entity.as_mut());
+        creatures.extend(attacker_ally_entity.as_mut());
+        let lapsed_phrase = lapsed_concentration_system(&mut creatures)
+            .iter()
+            .map(|(spell_name, freed)| spell_ended_phrase(spell_name, freed))
+            .collect::<String>();
+
+        turns.push(TurnSummary {
+            before_phrase: format!(
+                "⚔️ {}'s turn against {}!{regeneration_phrase}{sacred_weapon_phrase}{recharge_phrase}",
+                combatant_1_entity.name(), combatant_2_entity.name()
             ),
-            summary: PhysicalAttackAttemptSummary::Success {
-                after: Box::new(physical_attack_outcome_summary),
+            bonus_action_phrase: match martial_arts_bonus_action {
+                _ if turn_start_outcome.entered_rage => Some(format!(
+                    "{} enters a {}rage as a bonus action ({} remaining).",
+                    combatant_1_entity.name(),
+                    if combatant_1_entity.rage().is_some_and(Rage::frenzy) {
+                        "frenzied "
+                    } else {
+                        ""
+                    },
+                    combatant_1_entity
+                        .rage()
+                        .and_then(Rage::uses_remaining)
+                        .map_or_else(|| "unlimited".to_string(), |uses| uses.to_string())
+                )),
+                _ if frenzy_attack => Some(format!(
+                    "{} makes a frenzied melee weapon attack as a bonus action.",
+                    combatant_1_entity.name()
+                )),
+                Some(MartialArtsBonusAction::FlurryOfBlows) => Some(format!(
+                    "{} spends {FLURRY_OF_BLOWS_KI_COST} ki point on Flurry of Blows as a bonus action ({} remaining).",
+                    combatant_1_entity.name(),
+                    combatant_1_entity.ki().map_or(0, Ki::points_remaining)
+                )),
+                Some(MartialArtsBonusAction::UnarmedStrike) => Some(format!(
+                    "{} makes an unarmed strike with Martial Arts as a bonus action.",
+                    combatant_1_entity.name()
+                )),
+                None if hide_outcome.is_some() => hide_outcome.as_ref().map(|hide_outcome| {
+                    format!(
+                        "{} hides with Nimble Escape as a bonus action, rolling {} on a Dexterity (Stealth) check against a passive Perception of {}, and {} {}.",
+                        combatant_1_entity.name(),
+                        hide_outcome.stealth_check,
+                        hide_outcome.hidden.observer_passive_perception,
+                        if hide_outcome.hidden.hidden {
+                            "is hidden from"
+                        } else {
+                            "is spotted by"
+                        },
+                        combatant_2_entity.name()
+                    )
+                }),
+                None if off_hand_attack => Some(format!(
+                    "{} makes an off-hand attack with {} as a bonus action.",
+                    combatant_1_entity.name(),
+                    combatant_1_entity
+                        .equipped_off_hand_weapon()
+                        .map_or("a weapon", |weapon| weapon.model.weapon_type.name())
+                )),
+                None => None,
+            },
+            after_phrase: if target_dropped && main_attacks_made < attacks_per_turn as usize {
+                format!(
+                    "{} drops after {main_attacks_made} of {attacks_per_turn} attacks, ending the turn early.{rage_phrase}{condition_phrase}{concentration_phrase}{lapsed_phrase}",
+                    combatant_2_entity.name(),
+                )
+            } else if let Some(condition) = incapacitating_condition {
+                format!(
+                    "{0} is {1} and cannot act, {0}'s turn ends.{rage_phrase}{condition_phrase}{concentration_phrase}{lapsed_phrase}",
+                    combatant_1_entity.name(),
+                    condition.describe()
+                )
+            } else {
+                format!(
+                    "{}'s turn ends.{rage_phrase}{condition_phrase}{concentration_phrase}{lapsed_phrase}",
+                    combatant_1_entity.name()
+                )
             },
+            attacks,
+            legendary_actions,
         });
 
-        if combatant_2_entity.hp.current == 0 {
-            outcome = format!("{} wins!", combatant_1_entity.name);
+        if target_dropped {
+            outcome = format!("{} wins!", combatant_1_entity.name());
+            break;
+        }
+        if attacker_dropped {
+            outcome = format!("{} wins!", combatant_2_entity.name());
             break;
         }
 
@@ -200,10 +921,429 @@ pub(crate) async fn post_attack(
     (
         StatusCode::OK,
         axum::Json(CombatSummary {
+            initiative,
             combatant_1_summary: Some((&combatant_1_state_and_context).into()),
             combatant_2_summary: Some((&combatant_2_state_and_context).into()),
-            attacks,
+            turns,
             outcome,
         }),
     )
 }
+
+/// Makes one attack and describes it, `label` leads the phrase e.g. "Attack 1 of 2"
+fn attack_with_phrases<'a>(
+    attacker: &mut CreatureEntity<'a>,
+    defender: &mut CreatureEntity<'a>,
+    state_and_context: &PhysicalAttackStateAndContext,
+    label: String,
+) -> PhysicalAttackAttemptSummaryWithPhrases {
+    let physical_attack_outcome = physical_attack_system(attacker, defender, state_and_context);
+    let damage_taken_phrase = damage_taken_phrase(
+        defender,
+        physical_attack_outcome.hit.damage_dealt(),
+        &physical_attack_outcome.hit.damage_types(),
+        physical_attack_outcome.critical_state,
+    );
+    let concentration_phrase = concentration_check_phrase(
+        attacker,
+        defender,
+        physical_attack_outcome.hit.damage_dealt(),
+    );
+
+    PhysicalAttackAttemptSummaryWithPhrases {
+        before_phrase: format!("{label}: {} attacks {}!", attacker.name(), defender.name()),
+        after_phrase: format!(
+            "{} has {} HP remaining!{damage_taken_phrase}{concentration_phrase}",
+            defender.name(),
+            defender.hp()
+        ),
+        summary: PhysicalAttackAttemptSummary::Success {
+            after: Box::new(PhysicalAttackOutcomeSummary::from(&physical_attack_outcome)),
+        },
+    }
+}
+
+/// Makes one attack roll of a spell and describes it
+fn spell_attack_with_phrases<'a>(
+    attacker: &mut CreatureEntity<'a>,
+    defender: &mut CreatureEntity<'a>,
+    state_and_context: &PhysicalAttackStateAndContext,
+    spell_attack: &SpellAttack,
+    label: String,
+) -> PhysicalAttackAttemptSummaryWithPhrases {
+    let spell_attack_outcome =
+        spell_attack_system(attacker, defender, state_and_context, spell_attack);
+    let damage_taken_phrase = damage_taken_phrase(
+        defender,
+        spell_attack_outcome.hit.damage_dealt(),
+        &spell_attack_outcome.hit.damage_types(),
+        spell_attack_outcome.critical_state,
+    );
+    let concentration_phrase =
+        concentration_check_phrase(attacker, defender, spell_attack_outcome.hit.damage_dealt());
+
+    PhysicalAttackAttemptSummaryWithPhrases {
+        before_phrase: format!(
+            "{label}: {} casts {} at {}!",
+            attacker.name(),
+            spell_attack.spell.name,
+            defender.name()
+        ),
+        after_phrase: format!(
+            "{} has {} HP remaining!{damage_taken_phrase}{concentration_phrase}",
+            defender.name(),
+            defender.hp()
+        ),
+        summary: PhysicalAttackAttemptSummary::Success {
+            after: Box::new(PhysicalAttackOutcomeSummary::from(&spell_attack_outcome)),
+        },
+    }
+}
+
+/// Makes one attack from a monster's stat block and describes it
+fn monster_attack_with_phrases<'a>(
+    attacker: &mut CreatureEntity<'a>,
+    defender: &mut CreatureEntity<'a>,
+    state_and_context: &PhysicalAttackStateAndContext,
+    monster_attack: &MonsterAttack,
+    label: String,
+) -> PhysicalAttackAttemptSummaryWithPhrases {
+    let monster_attack_outcome =
+        monster_attack_system(attacker, defender, state_and_context, monster_attack);
+    let damage_taken_phrase = damage_taken_phrase(
+        defender,
+        monster_attack_outcome.hit.damage_dealt(),
+        &monster_attack_outcome.hit.damage_types(),
+        monster_attack_outcome.critical_state,
+    );
+    let concentration_phrase = concentration_check_phrase(
+        attacker,
+        defender,
+        monster_attack_outcome.hit.damage_dealt(),
+    );
+
+    PhysicalAttackAttemptSummaryWithPhrases {
+        before_phrase: format!(
+            "{label}: {} attacks {} with {}!",
+            attacker.name(),
+            defender.name(),
+            monster_attack.name
+        ),
+        after_phrase: format!(
+            "{} has {} HP remaining!{damage_taken_phrase}{concentration_phrase}",
+            defender.name(),
+            defender.hp()
+        ),
+        summary: PhysicalAttackAttemptSummary::Success {
+            after: Box::new(PhysicalAttackOutcomeSummary::from(&monster_attack_outcome)),
+        },
+    }
+}
+
+/// Takes a monster action that forces the defender to make a saving throw
+/// and describes it
+fn monster_save_action_with_phrases<'a>(
+    attacker: &mut CreatureEntity<'a>,
+    defender: &mut CreatureEntity<'a>,
+    save_action: &MonsterSaveAction,
+    label: String,
+) -> PhysicalAttackAttemptSummaryWithPhrases {
+    let monster_save_action_outcome = monster_save_action_system(attacker, defender, save_action);
+    let summary = SpellSaveOutcomeSummary::from(&monster_save_action_outcome);
+
+    let damage_dealt = monster_save_action_outcome.target.damage_dealt;
+    let damage_taken_phrase = damage_taken_phrase(
+        defender,
+        damage_dealt,
+        &save_action
+            .damage
+            .iter()
+            .map(|damage| damage.damage_type)
+            .collect::<Vec<_>>(),
+        CriticalState::Normal,
+    );
+    let concentration_phrase = concentration_check_phrase(attacker, defender, damage_dealt);
+
+    PhysicalAttackAttemptSummaryWithPhrases {
+        before_phrase: format!(
+            "{label}: {} uses {} on {}!",
+            attacker.name(),
+            save_action.name,
+            defender.name()
+        ),
+        after_phrase: format!(
+            "{} has {} HP remaining!{damage_taken_phrase}{concentration_phrase}",
+            defender.name(),
+            defender.hp()
+        ),
+        summary: PhysicalAttackAttemptSummary::SavingThrow {
+            after: Box::new(summary),
+        },
+    }
+}
+
+/// Casts a spell that forces a saving throw at the defender and describes it,
+/// catching the bystanders standing in its area
+fn spell_save_with_phrases<'a>(
+    attacker: &mut CreatureEntity<'a>,
+    defender: &mut CreatureEntity<'a>,
+    bystanders: Vec<&mut CreatureEntity<'a>>,
+    spell_save: &SpellSave,
+    label: String,
+) -> PhysicalAttackAttemptSummaryWithPhrases {
+    let mut targets =
+        area_of_effect_targets_system(attacker, spell_save.spell, defender, bystanders).targets;
+    let spell_save_outcome = spell_save_system(attacker, &mut targets, spell_save);
+    let summary = SpellSaveOutcomeSummary::from(&spell_save_outcome);
+    let damage_types = spell_save.damage_type.into_iter().collect::<Vec<_>>();
+
+    // Everyone caught in the area reacts to their own damage, the defender first
+    let mut target_phrases = targets.iter_mut().map(|target| {
+        let damage_dealt = spell_save_outcome
+            .targets
+            .iter()
+            .find(|target_outcome| target_outcome.id == target.id())
+            .map_or(0, |target_outcome| target_outcome.damage_dealt);
+        let mut phrase =
+            damage_taken_phrase(target, damage_dealt, &damage_types, CriticalState::Normal);
+        phrase += &concentration_check_phrase(attacker, target, damage_dealt);
+
+        if let Some(concentration) = incapacitated_concentration_system(*target) {
+            let freed =
+                end_concentration_system(&concentration, &mut [&mut *attacker, &mut **target]);
+            phrase += &format!(
+                " {} is incapacitated and loses concentration on {}.{}",
+                target.name(),
+                concentration.spell_name,
+                spell_ended_phrase(&concentration.spell_name, &freed)
+            );
+        }
+
+        (target.name().to_string(), target.hp(), phrase)
+    });
+    let defender_phrase = target_phrases
+        .next()
+        .map(|(_, _, phrase)| phrase)
+        .unwrap_or_default();
+    let bystander_phrases = target_phrases
+        .map(|(name, hp, phrase)| format!(" {name} has {hp} HP remaining!{phrase}"))
+        .collect::<String>();
+
+    PhysicalAttackAttemptSummaryWithPhrases {
+        before_phrase: format!(
+            "{label}: {} casts {} at {}!",
+            attacker.name(),
+            spell_save.spell.name,
+            defender.name()
+        ),
+        after_phrase: format!(
+            "{} has {} HP remaining!{defender_phrase}{bystander_phrases}",
+            defender.name(),
+            defender.hp()
+        ),
+        summary: PhysicalAttackAttemptSummary::SavingThrow {
+            after: Box::new(summary),
+        },
+    }
+}
+
+/// The defender's Constitution saving throw to keep concentrating after
+/// taking damage, ending the spell's effects when it fails
+fn concentration_check_phrase<'a>(
+    attacker: &mut CreatureEntity<'a>,
+    defender: &mut CreatureEntity<'a>,
+    damage: u32,
+) -> String {
+    let Some(check) = concentration_check_system(defender, damage) else {
+        return String::new();
+    };
+
+    let (result_phrase, spell_ended_phrase) = if check.saving_throw.legendary_resistance {
+        (
+            "fails, but keeps concentrating with Legendary Resistance",
+            String::new(),
+        )
+    } else if check.maintained() {
+        ("keeps concentrating", String::new())
+    } else {
+        let freed =
+            end_concentration_system(&check.concentration, &mut [&mut *attacker, &mut *defender]);
+        (
+            "loses concentration",
+            spell_ended_phrase(&check.concentration.spell_name, &freed),
+        )
+    };
+
+    format!(
+        " {} rolls {} on a Constitution saving throw against DC {} for taking {} damage and {result_phrase} on {}.{spell_ended_phrase}",
+        defender.name(),
+        check.saving_throw.total_value(),
+        check.saving_throw.dc,
+        check.damage,
+        check.concentration.spell_name
+    )
+}
+
+/// Special traits that react to the defender taking damage, e.g. Undead
+/// Fortitude keeping them at 1 hit point
+fn damage_taken_phrase(
+    defender: &mut CreatureEntity,
+    damage: u32,
+    damage_types: &[DamageType],
+    critical_state: CriticalState,
+) -> String {
+    let Some(check) = damage_taken_system(defender, damage, damage_types, critical_state) else {
+        return String::new();
+    };
+
+    let result_phrase = if check.hp_change.is_some() {
+        "drops to 1 HP instead"
+    } else {
+        "falls"
+    };
+
+    format!(
+        " {} rolls {} on a Constitution saving throw against DC {} for Undead Fortitude and {result_phrase}.",
+        defender.name(),
+        check.saving_throw.total_value(),
+        check.saving_throw.dc
+    )
+}
+
+/// Names the creatures freed from a spell's conditions, if any
+fn spell_ended_phrase(spell_name: &str, freed: &[String]) -> String {
+    if freed.is_empty() {
+        String::new()
+    } else {
+        format!(" {spell_name} ends for {}.", freed.join(" and "))
+    }
+}
+
+/// The target's ally only protects the target, and only while standing
+fn defender_ally<'e, 'a>(
+    target_ally: &'e Option<CreatureEntity<'a>>,
+    defender: &CreatureEntity,
+    target_id: Id,
+) -> Option<&'e CreatureEntity<'a>> {
+    target_ally
+        .as_ref()
+        .filter(|ally| defender.id() == target_id && ally.hp() > 0)
+}
+
+/// The attacker's ally only helps against the target, and only while standing
+/// and able to act
+fn attacker_ally<'e, 'a>(
+    attacker_ally: &'e Option<CreatureEntity<'a>>,
+    attacker: &CreatureEntity,
+    target_id: Id,
+) -> Option<&'e CreatureEntity<'a>> {
+    attacker_ally
+        .as_ref()
+        .filter(|ally| attacker.id() != target_id && ally.hp() > 0 && !ally.is_incapacitated())
+}
+
+/// Snapshots both combatants as they are at the start of the attacker's turn
+fn physical_attack_state<'a>(
+    attacker: &CreatureEntity,
+    defender: &CreatureEntity,
+    attacker_ally: Option<&CreatureEntity>,
+    defender_ally: Option<&CreatureEntity>,
+    in_sunlight: bool,
+    state: &'a AppState,
+) -> PhysicalAttackState<'a> {
+    let attacker_snapshot = AttackerSnapshot::from_attacker(
+        attacker,
+        state.weapon_model_map.as_ref(),
+        state.armor_model_map.as_ref(),
+    );
+    let attacker_snapshot = if in_sunlight {
+        attacker_snapshot.with_sunlight()
+    } else {
+        attacker_snapshot
+    };
+    let defender_snapshot =
+        DefenderSnapshot::from_defender(defender, state.armor_model_map.as_ref());
+
+    PhysicalAttackState {
+        attacker: match attacker_ally {
+            Some(_) => attacker_snapshot.with_ally_adjacent_to_target(),
+            None => attacker_snapshot,
+        },
+        defender: match defender_ally {
+            Some(ally) => defender_snapshot.with_protecting_ally(ally),
+            None => defender_snapshot,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_state;
+    use rs5e_concepts::{bonus_damage::BonusDamageSource, hit::Hit};
+
+    /// Attacks until one hits, returning its bonus damage sources
+    fn bonus_damage_of_next_hit(
+        attacker: &mut CreatureEntity,
+        defender: &mut CreatureEntity,
+        state_and_context: &PhysicalAttackStateAndContext,
+    ) -> Vec<BonusDamageSource> {
+        loop {
+            if let Hit::Success {
+                bonus_damage_rolls, ..
+            } = physical_attack_system(attacker, defender, state_and_context).hit
+            {
+                return bonus_damage_rolls
+                    .into_iter()
+                    .map(|bonus_damage_roll| bonus_damage_roll.source)
+                    .collect();
+            }
+        }
+    }
+
+    #[test]
+    fn colossus_slayer_applies_once_an_earlier_hit_wounds_the_target() {
+        let state = app_state();
+        let builder = |value| serde_json::from_value::<CombatantBuilder>(value).unwrap();
+        let mut hunter = creature_from_builder(
+            builder(serde_json::json!({
+                "t": "character",
+                "c": {
+                    "name": "Vex",
+                    "level": 5,
+                    "hp": 40,
+                    "class": "Ranger",
+                    "subclass": "Hunter",
+                    "abilityScores": {"str": 10, "dex": 18, "con": 14, "int": 10, "wis": 14, "cha": 10},
+                    "weaponType": "longbow",
+                    "proneState": "Upright",
+                    "coverState": "None"
+                }
+            })),
+            &state,
+        )
+        .unwrap();
+        let mut hill_giant = creature_from_builder(
+            builder(serde_json::json!({
+                "t": "monster",
+                "c": {"monster": "hill-giant", "proneState": "Upright", "coverState": "None"}
+            })),
+            &state,
+        )
+        .unwrap();
+
+        // The state is taken once at the start of the turn, at full hit points
+        let hunter_state = physical_attack_state(&hunter, &hill_giant, None, None, false, &state);
+        let state_and_context = PhysicalAttackStateAndContext {
+            context: PhysicalAttackContext::try_from(&hunter_state).unwrap(),
+            state: hunter_state,
+        };
+
+        let first_hit = bonus_damage_of_next_hit(&mut hunter, &mut hill_giant, &state_and_context);
+        assert!(!first_hit.contains(&BonusDamageSource::ColossusSlayer));
+        assert!(hill_giant.hp() < hill_giant.max_hp());
+
+        let second_hit = bonus_damage_of_next_hit(&mut hunter, &mut hill_giant, &state_and_context);
+        assert!(second_hit.contains(&BonusDamageSource::ColossusSlayer));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{character_from_builder, AppState, CharacterBuilder, InvalidCharacterBuilder};
+use axum::{extract::State, http::StatusCode, Json};
+use rs5e_concepts::encounter::Encounter;
+use rs5e_log::describe::Describe;
+use rs5e_systems::encounter::encounter_system;
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+#[typeshare]
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EncounterSummary {
+    encounter: Option<Encounter>,
+    outcome: String,
+}
+
+#[typeshare]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EncounterRequest {
+    party: Vec<CharacterBuilder>,
+    /// SRD indexes of the monsters, once per monster, e.g. "goblin"
+    monsters: Vec<String>,
+}
+
+/// Budgets an encounter before simulating it
+pub(crate) async fn post_encounter(
+    State(state): State<AppState>,
+    Json(payload): Json<EncounterRequest>,
+) -> (StatusCode, Json<EncounterSummary>) {
+    let unprocessable = |outcome: String| {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            axum::Json(EncounterSummary {
+                encounter: None,
+                outcome,
+            }),
+        )
+    };
+
+    if payload.party.is_empty() || payload.monsters.is_empty() {
+        return unprocessable(
+            "An encounter needs at least one character and one monster.".to_string(),
+        );
+    }
+
+    let party = payload
+        .party
+        .into_iter()
+        .map(|builder| {
+            character_from_builder(
+                builder,
+                state.weapon_model_map.as_ref(),
+                state.armor_model_map.as_ref(),
+                state.proficiency_type_by_class_map.as_ref(),
+                state.spellcasting_by_class_map.as_ref(),
+                state.spell_model_map.as_ref(),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>();
+    let monsters = payload
+        .monsters
+        .into_iter()
+        .map(|monster| {
+            state
+                .monster_model_map
+                .get(&monster)
+                .ok_or(InvalidCharacterBuilder::UnknownMonster { monster })
+        })
+        .collect::<Result<Vec<_>, _>>();
+
+    let (party, monsters) = match (party, monsters) {
+        (Ok(party), Ok(monsters)) => (party, monsters),
+        (Err(invalid_builder), _) | (_, Err(invalid_builder)) => {
+            return unprocessable(invalid_builder.to_string());
+        }
+    };
+
+    let encounter = encounter_system(&party.iter().collect::<Vec<_>>(), &monsters);
+
+    let monsters_phrase = monsters
+        .iter()
+        .map(|monster| {
+            format!(
+                "{} (CR {}, {} XP)",
+                monster.name,
+                monster.challenge_rating.describe(),
+                monster.xp
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let worth_phrase = if monsters.len() == 1 {
+        "is worth"
+    } else {
+        "are worth"
+    };
+    let thresholds = encounter.party_thresholds;
+
+    (
+        StatusCode::OK,
+        axum::Json(EncounterSummary {
+            outcome: format!(
+                "{monsters_phrase} {worth_phrase} {} XP, adjusted to {} XP with a ×{} multiplier for a party of {}. Against the party's thresholds of {} easy, {} medium, {} hard and {} deadly, the encounter is {}.",
+                encounter.total_xp,
+                encounter.adjusted_xp,
+                encounter.multiplier,
+                party.len(),
+                thresholds.easy,
+                thresholds.medium,
+                thresholds.hard,
+                thresholds.deadly,
+                encounter.difficulty.describe()
+            ),
+            encounter: Some(encounter),
+        }),
+    )
+}